@@ -12,34 +12,34 @@ fn main() {
     use tabled::{Alignment, Column, Format, Head, Modify, Object, Row, Style, Table, Tabled};
 
     #[derive(Tabled)]
-    struct BSD {
+    struct Bsd {
         distribution: &'static str,
         year_of_first_release: usize,
         is_active: bool,
     }
 
     let data = vec![
-        BSD {
+        Bsd {
             distribution: "SunOS",
             year_of_first_release: 1982,
             is_active: false,
         },
-        BSD {
+        Bsd {
             distribution: "NetBSD",
             year_of_first_release: 1993,
             is_active: true,
         },
-        BSD {
+        Bsd {
             distribution: "FreeBSD",
             year_of_first_release: 1993,
             is_active: true,
         },
-        BSD {
+        Bsd {
             distribution: "BSD",
             year_of_first_release: 1978,
             is_active: false,
         },
-        BSD {
+        Bsd {
             distribution: "OpenBSD",
             year_of_first_release: 1995,
             is_active: true,
@@ -10,7 +10,7 @@ fn main() {
         ["Hello World", "[[[[[[[[[[[[[[[[["],
     ];
 
-    let table = Table::new(&data)
+    let table = Table::new(data)
         .with(Style::github_markdown())
         .with(Modify::new(Full).with(MaxWidth(10, "...")));
 
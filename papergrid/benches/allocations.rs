@@ -0,0 +1,56 @@
+//! Compares rendering of big grids into a new string for each render
+//! with rendering line by line into a reused buffer.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+
+fn grid(size: usize) -> Grid<'static> {
+    let mut grid = Grid::from_fn(size, 8, |row, column| format!("{}-{}", row, column * 1000));
+    grid.set(
+        Entity::Global,
+        Settings::new()
+            .indent(1, 1, 0, 0)
+            .alignment(AlignmentHorizontal::Center),
+    );
+    // a setting of a particular cell makes both ways be drawn through a layout
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().alignment(AlignmentHorizontal::Center),
+    );
+    grid
+}
+
+fn allocations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("allocations");
+    group.sample_size(20);
+    for size in [10_000, 50_000] {
+        let grid = grid(size);
+
+        // each line is an owned string, like lines were collected before `render_lines`
+        group.bench_with_input(BenchmarkId::new("owned_lines", size), &grid, |b, grid| {
+            b.iter(|| {
+                grid.to_string()
+                    .lines()
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<String>>()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("to_string", size), &grid, |b, grid| {
+            b.iter(|| grid.to_string())
+        });
+
+        let mut buffer = String::new();
+        group.bench_with_input(BenchmarkId::new("render_lines", size), &grid, |b, grid| {
+            b.iter(|| {
+                let mut length = 0;
+                grid.render_lines(&mut buffer, |line| length += line.len());
+                length
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, allocations);
+criterion_main!(benches);
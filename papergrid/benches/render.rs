@@ -0,0 +1,40 @@
+//! Compares rendering of a grid which takes the fast path for single-line grids
+//! with the same grid which is drawn through a layout because of a setting of one cell.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+
+fn grid(size: usize) -> Grid<'static> {
+    let mut grid = Grid::from_fn(size, 8, |row, column| format!("{}-{}", row, column * 1000));
+    grid.set(
+        Entity::Global,
+        Settings::new()
+            .indent(1, 1, 0, 0)
+            .alignment(AlignmentHorizontal::Center),
+    );
+    grid
+}
+
+fn render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    for size in [10, 100, 1000] {
+        let simple = grid(size);
+        group.bench_with_input(BenchmarkId::new("simple", size), &simple, |b, grid| {
+            b.iter(|| grid.to_string())
+        });
+
+        // a setting of a particular cell makes a grid be drawn through a layout
+        let mut layout = grid(size);
+        layout.set(
+            Entity::Cell(0, 0),
+            Settings::new().alignment(AlignmentHorizontal::Center),
+        );
+        group.bench_with_input(BenchmarkId::new("layout", size), &layout, |b, grid| {
+            b.iter(|| grid.to_string())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
@@ -0,0 +1,310 @@
+use core::fmt::{self, Display, Write};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{AlignmentHorizontal, Justified};
+
+/// CompactGrid is a grid of a fixed size which is rendered without allocations.
+///
+/// It's meant for small tables which are rendered over and over again, like on each frame of a UI.
+/// Unlike [crate::Grid] it has only one style for all cells and no spans,
+/// and widths of columns are kept on a stack.
+///
+/// The module uses only `core`, contents are measured as plain text so they must not have ANSI sequences.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{AlignmentHorizontal, CompactGrid, CompactStyle};
+///
+///     const GRID: CompactGrid<'static, 2, 2> = CompactGrid::new([["id", "name"], ["1", "Grodno"]])
+///         .style(CompactStyle::ascii())
+///         .padding(1, 1)
+///         .alignment(AlignmentHorizontal::Right);
+///
+///     assert_eq!(
+///          GRID.to_string(),
+///          "+----+--------+\n\
+///           | id |   name |\n\
+///           +----+--------+\n\
+///           |  1 | Grodno |\n\
+///           +----+--------+\n"
+///     )
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactGrid<'a, const ROWS: usize, const COLUMNS: usize> {
+    cells: [[&'a str; COLUMNS]; ROWS],
+    style: CompactStyle,
+    padding: (usize, usize),
+    alignment: AlignmentHorizontal,
+}
+
+impl<'a, const ROWS: usize, const COLUMNS: usize> CompactGrid<'a, ROWS, COLUMNS> {
+    /// New creates a grid with an ascii style, no padding and left alignment.
+    pub const fn new(cells: [[&'a str; COLUMNS]; ROWS]) -> Self {
+        Self {
+            cells,
+            style: CompactStyle::ascii(),
+            padding: (0, 0),
+            alignment: AlignmentHorizontal::Left,
+        }
+    }
+
+    /// Style sets borders of the grid.
+    pub const fn style(mut self, style: CompactStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Padding sets a number of spaces on the left and right of each cell.
+    pub const fn padding(mut self, left: usize, right: usize) -> Self {
+        self.padding = (left, right);
+        self
+    }
+
+    /// Alignment sets a horizontal alignment of all cells.
+    pub const fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set_cell changes a content of a cell.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the cell is out of the grid.
+    pub fn set_cell(&mut self, row: usize, column: usize, text: &'a str) {
+        self.cells[row][column] = text;
+    }
+
+    /// Get_cell returns a content of a cell.
+    pub fn get_cell(&self, row: usize, column: usize) -> Option<&'a str> {
+        self.cells.get(row).and_then(|row| row.get(column)).copied()
+    }
+
+    /// Column_widths returns a width of each column, not counting padding.
+    pub fn column_widths(&self) -> [usize; COLUMNS] {
+        let mut widths = [0; COLUMNS];
+        for row in &self.cells {
+            for (width, text) in widths.iter_mut().zip(row) {
+                *width = core::cmp::max(*width, text_width(text));
+            }
+        }
+
+        widths
+    }
+
+    fn write_line(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        widths: &[usize; COLUMNS],
+        line: &CompactLine,
+    ) -> fmt::Result {
+        let style = &self.style;
+        write_char(f, style.left.map(|_| line.left))?;
+        for (column, width) in widths.iter().enumerate() {
+            if column > 0 {
+                write_char(f, style.vertical.map(|_| line.intersection))?;
+            }
+
+            repeat(f, line.main, self.padding.0 + width + self.padding.1)?;
+        }
+        write_char(f, style.right.map(|_| line.right))?;
+
+        f.write_char('\n')
+    }
+
+    fn write_row(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        widths: &[usize; COLUMNS],
+        row: &[&str; COLUMNS],
+    ) -> fmt::Result {
+        let height = row
+            .iter()
+            .map(|text| text.lines().count())
+            .max()
+            .unwrap_or(0);
+        for index in 0..core::cmp::max(height, 1) {
+            write_char(f, self.style.left)?;
+            for (column, (text, width)) in row.iter().zip(widths).enumerate() {
+                if column > 0 {
+                    write_char(f, self.style.vertical)?;
+                }
+
+                let text = text.lines().nth(index).unwrap_or("");
+                if self.alignment == AlignmentHorizontal::Justify {
+                    repeat(f, ' ', self.padding.0)?;
+                    Justified(text, *width, text_width).fmt(f)?;
+                    repeat(f, ' ', self.padding.1)?;
+                    continue;
+                }
+
+                let (left, right) = match self.alignment {
+                    AlignmentHorizontal::Left | AlignmentHorizontal::Justify => {
+                        (0, width - text_width(text))
+                    }
+                    AlignmentHorizontal::Right => (width - text_width(text), 0),
+                    AlignmentHorizontal::Center => {
+                        let diff = width - text_width(text);
+                        (diff / 2, diff - diff / 2)
+                    }
+                };
+
+                repeat(f, ' ', self.padding.0 + left)?;
+                f.write_str(text)?;
+                repeat(f, ' ', right + self.padding.1)?;
+            }
+            write_char(f, self.style.right)?;
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const ROWS: usize, const COLUMNS: usize> Display for CompactGrid<'_, ROWS, COLUMNS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if ROWS == 0 || COLUMNS == 0 {
+            return Ok(());
+        }
+
+        let widths = self.column_widths();
+        if let Some(line) = &self.style.top {
+            self.write_line(f, &widths, line)?;
+        }
+
+        for (i, row) in self.cells.iter().enumerate() {
+            if i > 0 {
+                if let Some(line) = &self.style.horizontal {
+                    self.write_line(f, &widths, line)?;
+                }
+            }
+
+            self.write_row(f, &widths, row)?;
+        }
+
+        if let Some(line) = &self.style.bottom {
+            self.write_line(f, &widths, line)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// CompactStyle holds borders of a [CompactGrid].
+///
+/// Corners and intersections of a horizontal line are drawn only where a vertical line is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactStyle {
+    top: Option<CompactLine>,
+    bottom: Option<CompactLine>,
+    horizontal: Option<CompactLine>,
+    left: Option<char>,
+    right: Option<char>,
+    vertical: Option<char>,
+}
+
+impl CompactStyle {
+    /// Blank style has no borders at all.
+    pub const fn blank() -> Self {
+        Self {
+            top: None,
+            bottom: None,
+            horizontal: None,
+            left: None,
+            right: None,
+            vertical: None,
+        }
+    }
+
+    /// Ascii style looks like the default style of [crate::Grid].
+    pub const fn ascii() -> Self {
+        let line = CompactLine::new('-', '+', '+', '+');
+        Self {
+            top: Some(line),
+            bottom: Some(line),
+            horizontal: Some(line),
+            left: Some('|'),
+            right: Some('|'),
+            vertical: Some('|'),
+        }
+    }
+
+    /// Top sets a line above the first row.
+    pub const fn top(mut self, line: Option<CompactLine>) -> Self {
+        self.top = line;
+        self
+    }
+
+    /// Bottom sets a line below the last row.
+    pub const fn bottom(mut self, line: Option<CompactLine>) -> Self {
+        self.bottom = line;
+        self
+    }
+
+    /// Horizontal sets a line between rows.
+    pub const fn horizontal(mut self, line: Option<CompactLine>) -> Self {
+        self.horizontal = line;
+        self
+    }
+
+    /// Left sets a left border.
+    pub const fn left(mut self, c: Option<char>) -> Self {
+        self.left = c;
+        self
+    }
+
+    /// Right sets a right border.
+    pub const fn right(mut self, c: Option<char>) -> Self {
+        self.right = c;
+        self
+    }
+
+    /// Vertical sets a line between columns.
+    pub const fn vertical(mut self, c: Option<char>) -> Self {
+        self.vertical = c;
+        self
+    }
+}
+
+/// CompactLine is a horizontal line of a [CompactStyle].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactLine {
+    main: char,
+    intersection: char,
+    left: char,
+    right: char,
+}
+
+impl CompactLine {
+    /// New creates a line.
+    ///
+    /// * `main` - a character which is used for building the line.
+    /// * `intersection` - a character where the line meets a vertical line.
+    /// * `left` - a left corner.
+    /// * `right` - a right corner.
+    pub const fn new(main: char, intersection: char, left: char, right: char) -> Self {
+        Self {
+            main,
+            intersection,
+            left,
+            right,
+        }
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    text.lines().map(UnicodeWidthStr::width).max().unwrap_or(0)
+}
+
+fn write_char(f: &mut fmt::Formatter<'_>, c: Option<char>) -> fmt::Result {
+    match c {
+        Some(c) => f.write_char(c),
+        None => Ok(()),
+    }
+}
+
+fn repeat(f: &mut fmt::Formatter<'_>, c: char, count: usize) -> fmt::Result {
+    (0..count).try_for_each(|_| f.write_char(c))
+}
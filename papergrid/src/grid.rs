@@ -0,0 +1,6363 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::{max, min},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    fmt::{self, Display},
+    fs::File,
+    hash::{Hash, Hasher},
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use crate::{AlignmentHorizontal, Justified, Repeat};
+
+/// Grid provides a set of methods for building a text-based table
+///
+/// A content of cells may be borrowed for a lifetime `'a`, see [Grid::set_cell_content].
+///
+/// Contents of cells are kept in [Records] and everything else in a [GridConfig],
+/// so the same contents can be rendered with different settings, see [Grid::set_config].
+#[derive(Debug, Clone)]
+pub struct Grid<'a> {
+    records: Records<'a>,
+    config: GridConfig,
+    sizes: SizeCache,
+    widths: WidthCache,
+}
+
+// SizeCache keeps sizes of cells between renders, so only changed cells are measured again
+#[derive(Debug, Default)]
+struct SizeCache {
+    enabled: bool,
+    sizes: Mutex<Option<CachedSizes>>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedSizes {
+    cells: Vec<Vec<(usize, usize)>>,
+    changed: Vec<(usize, usize)>,
+}
+
+impl SizeCache {
+    fn invalidate(&mut self) {
+        *self.sizes.get_mut().unwrap() = None;
+    }
+
+    fn mark(&mut self, row: usize, column: usize) {
+        if let Some(sizes) = self.sizes.get_mut().unwrap() {
+            sizes.changed.push((row, column));
+        }
+    }
+}
+
+impl Clone for SizeCache {
+    fn clone(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            sizes: Mutex::new(self.sizes.lock().unwrap().clone()),
+        }
+    }
+}
+
+// WidthCache keeps widths of contents by their hashes,
+// so contents which were already seen aren't measured again even if cells were changed.
+//
+// A content is keyed only by its 64-bit hash, the content itself isn't kept,
+// so contents with colliding hashes would share a width.
+// Widths which weren't used by the last measurement of all cells are dropped,
+// so the cache doesn't outgrow contents of the grid.
+#[derive(Debug, Default)]
+struct WidthCache {
+    enabled: bool,
+    widths: Mutex<CachedWidths>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CachedWidths {
+    // a width of a content and a measurement it was last used by
+    widths: HashMap<u64, (usize, usize)>,
+    measurement: usize,
+    hits: usize,
+    misses: usize,
+}
+
+impl WidthCache {
+    fn clear(&mut self) {
+        *self.widths.get_mut().unwrap() = CachedWidths::default();
+    }
+
+    fn stats(&self) -> CacheStats {
+        let cache = self.widths.lock().unwrap();
+        CacheStats {
+            hits: cache.hits,
+            misses: cache.misses,
+            entries: cache.widths.len(),
+        }
+    }
+
+    fn cells_size(&self, cells: &[Vec<StyledCell<'_>>]) -> Vec<Vec<(usize, usize)>> {
+        if !self.enabled {
+            return cells_size(cells);
+        }
+
+        let mut cache = self.widths.lock().unwrap();
+        cache.measurement += 1;
+        let sizes = cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|(cell, style)| cache.cell_size(cell, style))
+                    .collect()
+            })
+            .collect();
+
+        let measurement = cache.measurement;
+        cache.widths.retain(|_, (_, used)| *used == measurement);
+
+        sizes
+    }
+
+    fn cell_size(&self, cell: &[Cow<'_, str>], style: &Style) -> (usize, usize) {
+        if !self.enabled {
+            return (cell_width(cell, style), cell_height(cell, style));
+        }
+
+        self.widths.lock().unwrap().cell_size(cell, style)
+    }
+}
+
+impl CachedWidths {
+    fn cell_size(&mut self, cell: &[Cow<'_, str>], style: &Style) -> (usize, usize) {
+        if style.nested.is_some() {
+            return (cell_width(cell, style), cell_height(cell, style));
+        }
+
+        let mut hasher = DefaultHasher::new();
+        cell.hash(&mut hasher);
+        let key = hasher.finish();
+
+        let content_width = match self.widths.get_mut(&key) {
+            Some((width, used)) => {
+                self.hits += 1;
+                *used = self.measurement;
+                *width
+            }
+            None => {
+                self.misses += 1;
+                let width = cell.iter().map(|l| string_width(l)).max().unwrap_or(0);
+                self.widths.insert(key, (width, self.measurement));
+                width
+            }
+        };
+
+        let width = content_width + style.indent.left + style.indent.right;
+        (width, cell_height(cell, style))
+    }
+}
+
+impl Clone for WidthCache {
+    fn clone(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            widths: Mutex::new(self.widths.lock().unwrap().clone()),
+        }
+    }
+}
+
+/// CacheStats is a statistics of a width cache of a [Grid], see [Grid::set_width_cache].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// An amount of contents which widths were taken from the cache.
+    pub hits: usize,
+    /// An amount of contents which were measured.
+    pub misses: usize,
+    /// An amount of widths kept in the cache.
+    pub entries: usize,
+}
+
+/// Records holds contents of cells of a [Grid] row by row.
+#[derive(Debug, Clone, Default)]
+pub struct Records<'a> {
+    size: (usize, usize),
+    cells: Vec<Vec<Cow<'a, str>>>,
+}
+
+impl<'a> Records<'a> {
+    /// New creates records of a given size where every cell is empty.
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            size: (rows, columns),
+            cells: vec![vec![Cow::Borrowed(""); columns]; rows],
+        }
+    }
+
+    /// Count_rows returns an amount of rows.
+    pub fn count_rows(&self) -> usize {
+        self.size.0
+    }
+
+    /// Count_columns returns an amount of columns.
+    pub fn count_columns(&self) -> usize {
+        self.size.1
+    }
+
+    /// Get returns a content of a cell.
+    pub fn get(&self, row: usize, column: usize) -> &str {
+        &self.cells[row][column]
+    }
+
+    /// Set sets a content of a cell, which may be borrowed.
+    pub fn set<C: Into<Cow<'a, str>>>(&mut self, row: usize, column: usize, content: C) {
+        self.cells[row][column] = content.into();
+    }
+}
+
+/// GridConfig holds settings of a [Grid]: borders, styles, colors and size limits.
+///
+/// It doesn't hold contents of cells, so it can be moved between grids of the same amount of rows.
+#[derive(Debug, Clone)]
+pub struct GridConfig {
+    border_styles: Vec<Border>,
+    styles: HashMap<Entity, Style>,
+    colors: HashMap<Entity, Color>,
+    backgrounds: HashMap<Entity, Color>,
+    classes: HashMap<Entity, String>,
+    // entities out of the grid which a text was set for, the text itself is dropped
+    texts_out_of_grid: HashSet<Entity>,
+    overrides: BorderOverrides,
+    last_row_length: Option<usize>,
+    min_widths: HashMap<usize, usize>,
+    min_heights: HashMap<usize, usize>,
+    layout: LayoutOptions,
+    trim_trailing_whitespace: bool,
+    tab_width: usize,
+}
+
+impl GridConfig {
+    fn new(rows: usize) -> Self {
+        let mut styles = HashMap::new();
+        styles.insert(Entity::Global, Style::default());
+
+        Self {
+            border_styles: vec![Grid::default_border(); rows],
+            styles,
+            colors: HashMap::new(),
+            backgrounds: HashMap::new(),
+            classes: HashMap::new(),
+            texts_out_of_grid: HashSet::new(),
+            overrides: BorderOverrides::default(),
+            last_row_length: None,
+            min_widths: HashMap::new(),
+            min_heights: HashMap::new(),
+            layout: LayoutOptions::default(),
+            trim_trailing_whitespace: false,
+            tab_width: 4,
+        }
+    }
+
+    /// Count_rows returns an amount of rows the config is made for.
+    pub fn count_rows(&self) -> usize {
+        self.border_styles.len()
+    }
+
+    /// Resize_rows adapts the config to a given amount of rows.
+    ///
+    /// Borders of the first and the last rows are kept in place,
+    /// added rows take a border of the last inner row and settings of the last row.
+    pub fn resize_rows(&mut self, count_rows: usize) {
+        let borders = std::mem::take(&mut self.border_styles);
+        let (first, last) = match (borders.first(), borders.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                self.border_styles = vec![Grid::default_border(); count_rows];
+                return;
+            }
+        };
+
+        // a header and a last row have their own borders
+        let inner = if borders.len() > 2 {
+            &borders[borders.len() - 2]
+        } else {
+            last
+        };
+
+        self.border_styles = (0..count_rows)
+            .map(|row| {
+                if row + 1 == count_rows {
+                    let mut border = last.clone();
+                    if row == 0 {
+                        border.top_line = first.top_line.clone();
+                    }
+                    border
+                } else if row + 1 < borders.len() {
+                    borders[row].clone()
+                } else {
+                    inner.clone()
+                }
+            })
+            .collect();
+
+        let last_row = borders.len() - 1;
+        let added = borders.len()..count_rows;
+        copy_row_entities(&mut self.styles, last_row, added.clone());
+        copy_row_entities(&mut self.colors, last_row, added.clone());
+        copy_row_entities(&mut self.backgrounds, last_row, added.clone());
+        copy_row_entities(&mut self.classes, last_row, added);
+    }
+}
+
+impl<'a> Grid<'a> {
+    /// The new method creates a grid instance with default styles.
+    ///
+    /// The size of the grid can not be changed after the instance is created.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     let str = grid.to_string();
+    ///     assert_eq!(
+    ///          str,
+    ///          "+++\n\
+    ///           |||\n\
+    ///           +++\n\
+    ///           |||\n\
+    ///           +++\n"
+    ///     )
+    /// ```
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Grid {
+            records: Records::new(rows, columns),
+            config: GridConfig::new(rows),
+            sizes: SizeCache::default(),
+            widths: WidthCache::default(),
+        }
+    }
+
+    /// From_fn creates a grid with default styles
+    /// where a content of each cell is produced by a function of a `(row, column)` position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let grid = Grid::from_fn(2, 3, |row, column| row * 3 + column);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+-+\n\
+    ///           |0|1|2|\n\
+    ///           +-+-+-+\n\
+    ///           |3|4|5|\n\
+    ///           +-+-+-+\n"
+    ///     )
+    /// ```
+    pub fn from_fn<F, T>(rows: usize, columns: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+        T: Display,
+    {
+        let mut grid = Self::new(rows, columns);
+        for (row, cells) in grid.records.cells.iter_mut().enumerate() {
+            for (column, cell) in cells.iter_mut().enumerate() {
+                *cell = Cow::Owned(f(row, column).to_string());
+            }
+        }
+
+        grid
+    }
+
+    /// From_fixed_width creates a grid from column-aligned text, like an output of `ps` or `df`.
+    ///
+    /// Each not blank line becomes a row and each range of chars becomes a column,
+    /// a content of a cell is trimmed and borrowed from the text.
+    /// A range may go beyond a line, so `start..usize::MAX` takes the rest of a line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let text = concat!(
+    ///         "PID TTY    CMD\n",
+    ///         " 42 pts/0  bash\n",
+    ///         "137 pts/0  ps aux\n",
+    ///     );
+    ///
+    ///     let grid = Grid::from_fixed_width(text, &[0..3, 4..10, 11..usize::MAX]);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+-----+------+\n\
+    ///           |PID|TTY  |CMD   |\n\
+    ///           +---+-----+------+\n\
+    ///           |42 |pts/0|bash  |\n\
+    ///           +---+-----+------+\n\
+    ///           |137|pts/0|ps aux|\n\
+    ///           +---+-----+------+\n"
+    ///     )
+    /// ```
+    pub fn from_fixed_width(text: &'a str, columns: &[std::ops::Range<usize>]) -> Self {
+        let lines = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>();
+
+        let mut grid = Self::new(lines.len(), columns.len());
+        for (row, line) in lines.into_iter().enumerate() {
+            // byte offsets of chars and of the end of a line
+            let offsets = line
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(line.len()))
+                .collect::<Vec<_>>();
+            let offset = |i: usize| offsets[min(i, offsets.len() - 1)];
+
+            for (column, range) in columns.iter().enumerate() {
+                let start = offset(range.start);
+                let end = max(start, offset(range.end));
+                grid.set_cell_content(row, column, line[start..end].trim());
+            }
+        }
+
+        grid
+    }
+
+    /// From_parts creates a grid from contents and settings which may be taken from another grid.
+    ///
+    /// # Panics
+    ///
+    /// A config must be made for the same amount of rows as records have.
+    pub fn from_parts(records: Records<'a>, config: GridConfig) -> Self {
+        assert_eq!(
+            records.count_rows(),
+            config.count_rows(),
+            "a config is made for a different amount of rows"
+        );
+
+        Self {
+            records,
+            config,
+            sizes: SizeCache::default(),
+            widths: WidthCache::default(),
+        }
+    }
+
+    /// Into_parts splits a grid into its contents and settings.
+    pub fn into_parts(self) -> (Records<'a>, GridConfig) {
+        (self.records, self.config)
+    }
+
+    /// Records returns contents of cells.
+    pub fn records(&self) -> &Records<'a> {
+        &self.records
+    }
+
+    /// Config returns settings of the grid.
+    pub fn config(&self) -> &GridConfig {
+        &self.config
+    }
+
+    /// Set_config replaces settings of the grid and returns the old ones.
+    ///
+    /// Contents aren't touched, so the same data can be rendered in different styles cheaply.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::from_fn(1, 2, |_, column| column);
+    ///     let plain = grid.config().clone();
+    ///     grid.set(Entity::Global, Settings::new().indent(1, 1, 0, 0));
+    ///     assert_eq!(grid.to_string(), "+---+---+\n| 0 | 1 |\n+---+---+\n");
+    ///
+    ///     let padded = grid.set_config(plain);
+    ///     assert_eq!(grid.to_string(), "+-+-+\n|0|1|\n+-+-+\n");
+    ///
+    ///     grid.set_config(padded);
+    ///     assert_eq!(grid.to_string(), "+---+---+\n| 0 | 1 |\n+---+---+\n");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// A config must be made for the same amount of rows as the grid has.
+    pub fn set_config(&mut self, config: GridConfig) -> GridConfig {
+        assert_eq!(
+            self.count_rows(),
+            config.count_rows(),
+            "a config is made for a different amount of rows"
+        );
+
+        self.sizes.invalidate();
+        std::mem::replace(&mut self.config, config)
+    }
+
+    /// Set_records replaces contents of the grid and returns the old ones.
+    ///
+    /// Settings are kept, so a grid can be refreshed with new data without setting it up again,
+    /// see [GridConfig::resize_rows].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Records, Settings};
+    ///     let mut grid = Grid::from_fn(1, 1, |_, _| "a");
+    ///     grid.set(Entity::Global, Settings::new().indent(1, 1, 0, 0));
+    ///
+    ///     let mut records = Records::new(2, 1);
+    ///     records.set(0, 0, "b");
+    ///     records.set(1, 0, "c");
+    ///     grid.set_records(records);
+    ///
+    ///     assert_eq!(grid.to_string(), "+---+\n| b |\n+---+\n| c |\n+---+\n");
+    /// ```
+    pub fn set_records(&mut self, records: Records<'a>) -> Records<'a> {
+        self.config.resize_rows(records.count_rows());
+        self.sizes.invalidate();
+        std::mem::replace(&mut self.records, records)
+    }
+
+    /// Set method is responsible for modification of cell/row/column.
+    ///
+    /// The method panics if incorrect cell/row/column index is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Row(0), Settings::new().text("row 1"));
+    ///     grid.set(Entity::Row(1), Settings::new().text("row 2"));
+    ///     let str = grid.to_string();
+    ///     assert_eq!(
+    ///          str,
+    ///          "+-----+-----+\n\
+    ///           |row 1|row 1|\n\
+    ///           +-----+-----+\n\
+    ///           |row 2|row 2|\n\
+    ///           +-----+-----+\n"
+    ///     )
+    /// ```
+    pub fn set(&mut self, entity: Entity, settings: Settings) {
+        if let Some(text) = settings.text {
+            self.set_text(&entity, text);
+        }
+
+        // a color is kept apart from a style so setting it doesn't reset other settings
+        if let Some(color) = settings.color {
+            self.config.colors.insert(entity, color);
+        }
+
+        if let Some(background) = settings.background {
+            self.config.backgrounds.insert(entity, background);
+        }
+
+        if let Some(class) = settings.class {
+            self.config.classes.insert(entity, class);
+        }
+
+        if settings.indent.is_none()
+            && settings.alignment_h.is_none()
+            && settings.alignment_v.is_none()
+            && settings.span.is_none()
+            && settings.row_span.is_none()
+            && settings.indent_position.is_none()
+            && settings.raw.is_none()
+            && settings.nested.is_none()
+            && settings.fill.is_none()
+        {
+            return;
+        }
+
+        self.sizes.invalidate();
+
+        // Check for existed style and don't rewrite it totally in case it exists,
+        // only change parts which are set in settings
+        let mut s = self
+            .config
+            .styles
+            .get(&entity)
+            .map_or_else(Style::default, |s| s.clone());
+
+        if let Some(indent) = settings.indent {
+            s.indent = indent;
+        }
+        if let Some(alignment) = settings.alignment_h {
+            s.alignment_h = alignment;
+        }
+        if let Some(alignment) = settings.alignment_v {
+            s.alignment_v = alignment;
+        }
+        if let Some(span) = settings.span {
+            s.span = span;
+        }
+        if let Some(span) = settings.row_span {
+            s.row_span = span;
+        }
+        if let Some(position) = settings.indent_position {
+            s.indent_position = position;
+        }
+        if let Some(raw) = settings.raw {
+            s.raw = raw;
+        }
+        if let Some(nested) = settings.nested {
+            s.nested = Some(nested);
+        }
+        if let Some(fill) = settings.fill {
+            s.fill = fill;
+        }
+
+        self.config.styles.insert(entity, s);
+    }
+
+    /// get_cell_content returns content without any style changes
+    pub fn get_cell_content(&self, row: usize, column: usize) -> &str {
+        self.records.get(row, column)
+    }
+
+    /// set_cell_content sets a content of a cell without changing its style.
+    ///
+    /// Unlike [Settings::text] it accepts a borrowed string,
+    /// in which case the content is not copied neither here nor while rendering.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let data = vec![String::from("a"), String::from("b")];
+    ///     let mut grid = Grid::new(1, 2);
+    ///     for (column, text) in data.iter().enumerate() {
+    ///         grid.set_cell_content(0, column, text.as_str());
+    ///     }
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+\n\
+    ///           |a|b|\n\
+    ///           +-+-+\n"
+    ///     )
+    /// ```
+    pub fn set_cell_content<C: Into<Cow<'a, str>>>(
+        &mut self,
+        row: usize,
+        column: usize,
+        content: C,
+    ) {
+        self.records.set(row, column, content);
+        self.sizes.mark(row, column);
+    }
+
+    /// Row returns an iterator over contents of cells of a row.
+    pub fn row(&self, row: usize) -> impl ExactSizeIterator<Item = &str> + '_ {
+        self.records.cells[row].iter().map(|cell| cell.as_ref())
+    }
+
+    /// Row_mut returns an iterator over contents of cells of a row which can be rewritten.
+    pub fn row_mut(&mut self, row: usize) -> impl ExactSizeIterator<Item = &mut Cow<'a, str>> + '_ {
+        for column in 0..self.count_columns() {
+            self.sizes.mark(row, column);
+        }
+
+        self.records.cells[row].iter_mut()
+    }
+
+    /// Column returns an iterator over contents of cells of a column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let mut grid = Grid::from_fn(3, 2, |row, column| row * 10 + column);
+    ///     let total = grid.column(1).map(|n| n.parse::<usize>().unwrap()).sum::<usize>();
+    ///     assert_eq!(total, 33);
+    ///
+    ///     for cell in grid.column_mut(0) {
+    ///         *cell = format!("#{}", cell).into();
+    ///     }
+    ///     assert_eq!(grid.column(0).collect::<Vec<_>>(), ["#0", "#10", "#20"]);
+    /// ```
+    pub fn column(&self, column: usize) -> impl ExactSizeIterator<Item = &str> + '_ {
+        self.records
+            .cells
+            .iter()
+            .map(move |row| row[column].as_ref())
+    }
+
+    /// Column_mut returns an iterator over contents of cells of a column which can be rewritten.
+    pub fn column_mut(
+        &mut self,
+        column: usize,
+    ) -> impl ExactSizeIterator<Item = &mut Cow<'a, str>> + '_ {
+        for row in 0..self.count_rows() {
+            self.sizes.mark(row, column);
+        }
+
+        self.records
+            .cells
+            .iter_mut()
+            .map(move |row| &mut row[column])
+    }
+
+    /// get_cell_settings returns a settings of a cell
+    pub fn get_cell_settings(&self, row: usize, column: usize) -> Settings {
+        let style = self.style(row, column);
+        let content = &self.records.cells[row][column];
+        let mut settings = Settings::default()
+            .text(content.as_ref())
+            .alignment(style.alignment_h)
+            .vertical_alignment(style.alignment_v)
+            .set_span(style.span)
+            .set_row_span(style.row_span)
+            .indent(
+                style.indent.left,
+                style.indent.right,
+                style.indent.top,
+                style.indent.bottom,
+            )
+            .indent_position(style.indent_position)
+            .raw(style.raw)
+            .fill(style.fill);
+        settings.nested = style.nested.clone();
+        settings.color = style.color.clone();
+        settings.background = style.background.clone();
+        settings.class = self.class(row, column).map(ToOwned::to_owned);
+
+        settings
+    }
+
+    /// get_cell_span returns an amount of columns a cell spans including its own column,
+    /// a span which goes beyond the last column is cut, see [Settings::set_span].
+    pub fn get_cell_span(&self, row: usize, column: usize) -> usize {
+        cell_span(&self.style(row, column), column, self.count_columns())
+    }
+
+    /// is_cell_raw returns true if a cell is rendered as is, see [Settings::raw].
+    pub fn is_cell_raw(&self, row: usize, column: usize) -> bool {
+        self.style(row, column).raw
+    }
+
+    /// Entities returns an iterator over all cells of the grid
+    /// together with their resolved settings.
+    ///
+    /// The settings are the same which are used while rendering,
+    /// so it can be used to write custom analysis or transformation passes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set(Entity::Row(1), Settings::new().set_span(2));
+    ///
+    ///     let spans: Vec<_> = grid
+    ///         .entities()
+    ///         .map(|(entity, settings)| (entity, settings.get_span()))
+    ///         .collect();
+    ///
+    ///     assert_eq!(
+    ///         spans,
+    ///         vec![
+    ///             (Entity::Cell(0, 0), Some(1)),
+    ///             (Entity::Cell(0, 1), Some(1)),
+    ///             (Entity::Cell(1, 0), Some(2)),
+    ///             (Entity::Cell(1, 1), Some(2)),
+    ///         ]
+    ///     );
+    /// ```
+    pub fn entities(&self) -> impl Iterator<Item = (Entity, Settings)> + '_ {
+        (0..self.count_rows()).flat_map(move |row| {
+            (0..self.count_columns()).map(move |column| {
+                (
+                    Entity::Cell(row, column),
+                    self.get_cell_settings(row, column),
+                )
+            })
+        })
+    }
+
+    /// Render_fit_height renders the grid so it fits into `height` lines.
+    ///
+    /// The first row is considered a header and is always kept,
+    /// then rows from a side set by `anchor` are added while they fit.
+    /// The last emitted row is closed by the bottom line of the grid.
+    /// If even the header doesn't fit it's cut to `height` lines.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Anchor, Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(4, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("h"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("1"));
+    ///     grid.set(Entity::Cell(2, 0), Settings::new().text("2"));
+    ///     grid.set(Entity::Cell(3, 0), Settings::new().text("3"));
+    ///
+    ///     assert_eq!(
+    ///          grid.render_fit_height(5, Anchor::Bottom),
+    ///          "+-+\n\
+    ///           |h|\n\
+    ///           +-+\n\
+    ///           |3|\n\
+    ///           +-+\n"
+    ///     )
+    /// ```
+    pub fn render_fit_height(&self, height: usize, anchor: Anchor) -> String {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return String::new();
+        }
+
+        let measurement = self.measure();
+        let row_heights = measurement.row_heights();
+        let has_line = |line: &LineStyle| if line.is_empty() { 0 } else { 1 };
+        let bottom_lines = (0..count_rows)
+            .map(|row| has_line(&self.config.border_styles[row].bottom_line))
+            .collect::<Vec<_>>();
+        let rows_lines = (0..count_rows)
+            .map(|row| {
+                let top = if row == 0 {
+                    has_line(&self.config.border_styles[row].top_line)
+                } else {
+                    0
+                };
+                top + row_heights[row] + bottom_lines[row]
+            })
+            .collect::<Vec<_>>();
+
+        let rendered = self.draw(&measurement);
+        let mut rows = Vec::with_capacity(count_rows);
+        let mut lines = rendered.lines();
+        for &count_lines in &rows_lines {
+            rows.push(lines.by_ref().take(count_lines).collect::<Vec<_>>());
+        }
+
+        // the last emitted row is closed by the bottom frame line instead of its own split line
+        let last_row = count_rows - 1;
+        let frame = &rows[last_row][rows[last_row].len() - bottom_lines[last_row]..];
+        let fits = |used: usize, last: usize| used - bottom_lines[last] + frame.len() <= height;
+
+        let mut used = rows[0].len();
+        if !fits(used, 0) {
+            return rows[0]
+                .iter()
+                .take(height)
+                .map(|l| format!("{}\n", l))
+                .collect();
+        }
+
+        let mut body = Vec::new();
+        let mut last = 0;
+        let order: Box<dyn Iterator<Item = usize>> = match anchor {
+            Anchor::Top => Box::new(1..count_rows),
+            Anchor::Bottom => Box::new((1..count_rows).rev()),
+        };
+        for row in order {
+            if !fits(used + rows[row].len(), max(last, row)) {
+                break;
+            }
+
+            used += rows[row].len();
+            last = max(last, row);
+            body.push(row);
+        }
+
+        body.sort_unstable();
+        let mut output = Vec::new();
+        for row in std::iter::once(0).chain(body) {
+            if row == last {
+                let content = rows[row].len() - bottom_lines[row];
+                output.extend(rows[row][..content].iter().copied());
+                output.extend(frame.iter().copied());
+            } else {
+                output.extend(rows[row].iter().copied());
+            }
+        }
+
+        output.into_iter().map(|l| format!("{}\n", l)).collect()
+    }
+
+    /// Count_rows returns an amount of rows on the grid
+    pub fn count_rows(&self) -> usize {
+        self.records.size.0
+    }
+    /// Count_rows returns an amount of columns on the grid
+    pub fn count_columns(&self) -> usize {
+        self.records.size.1
+    }
+
+    /// Get_border returns a border for a given row.
+    pub fn get_border(&self, row: usize) -> &Border {
+        &self.config.border_styles[row]
+    }
+
+    /// Get_border_mut returns a border for a given row.
+    /// The border can be modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///    use papergrid::{Grid, Entity, Settings};
+    ///    let mut grid = Grid::new(2, 2);
+    ///    grid.set(Entity::Global, Settings::new().text("asd"));
+    ///    grid.get_border_mut(0).empty()
+    ///         .top('─', '┬', Some('┌'), Some('┐'))
+    ///         .bottom('─', '┼', Some('├'), Some('┤'))
+    ///         .inner(Some('│'), Some('│'), Some('│'));
+    ///    grid.get_border_mut(1).empty()
+    ///         .top('─', '┬', Some('┌'), Some('┐'))
+    ///         .bottom('─', '┴', Some('└'), Some('┘'))
+    ///         .inner(Some('│'), Some('│'), Some('│'));
+    ///
+    ///    let str = grid.to_string();
+    ///    assert_eq!(
+    ///        str,
+    ///        "┌───┬───┐\n\
+    ///         │asd│asd│\n\
+    ///         ├───┼───┤\n\
+    ///         │asd│asd│\n\
+    ///         └───┴───┘\n"
+    ///    )
+    /// ```
+    pub fn get_border_mut(&mut self, row: usize) -> &mut Border {
+        debug_assert!(row < self.count_rows());
+        &mut self.config.border_styles[row]
+    }
+
+    /// Set_horizontal_char overrides a character of a horizontal line above/below a cell.
+    ///
+    /// A `line` index 0 is a top line of the grid and a `line` index N is a line below N-1 row.
+    ///
+    /// The character is used only if the line has a character on this place.
+    pub fn set_horizontal_char(&mut self, line: usize, column: usize, c: char) {
+        self.config.overrides.horizontal.insert((line, column), c);
+    }
+
+    /// Set_vertical_char overrides a character of a vertical line on a given row.
+    ///
+    /// A `separator` index 0 is a left border and a `separator` index N is a line after N-1 column.
+    ///
+    /// The character is used only if the line has a character on this place.
+    pub fn set_vertical_char(&mut self, row: usize, separator: usize, c: char) {
+        self.config.overrides.vertical.insert((row, separator), c);
+    }
+
+    /// Set_intersection_char overrides a character of an intersection of a horizontal `line`
+    /// and a vertical `separator`.
+    ///
+    /// See [Grid::set_horizontal_char] and [Grid::set_vertical_char] for indexes.
+    ///
+    /// The character is used only if the line has a character on this place.
+    pub fn set_intersection_char(&mut self, line: usize, separator: usize, c: char) {
+        self.config
+            .overrides
+            .intersection
+            .insert((line, separator), c);
+    }
+
+    /// Set_last_row_length limits an amount of cells which are rendered in the last row.
+    ///
+    /// The frame is drawn only around the first `length` cells of the row
+    /// and a border of the previous row is closed above the rest of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 3);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///     grid.set_last_row_length(1);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+-+\n\
+    ///           |a|a|a|\n\
+    ///           +-+-+-+\n\
+    ///           |a|\n\
+    ///           +-+\n"
+    ///     )
+    /// ```
+    pub fn set_last_row_length(&mut self, length: usize) {
+        self.config.last_row_length = Some(length);
+    }
+
+    /// Set_min_column_width sets a minimal width of a column including an indent.
+    ///
+    /// A wider content still makes the column wider.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///     grid.set_min_column_width(1, 3);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+---+\n\
+    ///           |a|a  |\n\
+    ///           +-+---+\n"
+    ///     )
+    /// ```
+    pub fn set_min_column_width(&mut self, column: usize, width: usize) {
+        self.config.min_widths.insert(column, width);
+    }
+
+    /// Set_min_row_height sets a minimal height of a row including an indent.
+    ///
+    /// A higher content still makes the row higher.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///     grid.set_min_row_height(0, 2);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+\n\
+    ///           |a|a|\n\
+    ///           | | |\n\
+    ///           +-+-+\n"
+    ///     )
+    /// ```
+    pub fn set_min_row_height(&mut self, row: usize, height: usize) {
+        self.config.min_heights.insert(row, height);
+    }
+
+    /// Set_layout_options sets [LayoutOptions] which are used to measure and draw the grid.
+    pub fn set_layout_options(&mut self, options: LayoutOptions) {
+        self.config.layout = options;
+    }
+
+    /// Get_layout_options returns [LayoutOptions] of the grid.
+    pub fn get_layout_options(&self) -> LayoutOptions {
+        self.config.layout
+    }
+
+    /// Set_trim_trailing_whitespace sets whether whitespace at the end of lines is removed when they are written.
+    ///
+    /// Lines of cells are padded to a width of a column,
+    /// so without a right border they end with spaces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("abc"));
+    ///     for row in 0..2 {
+    ///         grid.get_border_mut(row).empty();
+    ///     }
+    ///
+    ///     grid.set_trim_trailing_whitespace(true);
+    ///     assert_eq!(grid.to_string(), "a\nabc\n");
+    /// ```
+    pub fn set_trim_trailing_whitespace(&mut self, trim: bool) {
+        self.config.trim_trailing_whitespace = trim;
+    }
+
+    /// Get_trim_trailing_whitespace returns whether whitespace at the end of lines is removed.
+    pub fn get_trim_trailing_whitespace(&self) -> bool {
+        self.config.trim_trailing_whitespace
+    }
+
+    /// Set_tab_width sets a distance between tab stops which `\t` characters are expanded to.
+    ///
+    /// Tabs are replaced by spaces up to a next tab stop of a line before a content is measured,
+    /// so a content with tabs doesn't break an alignment. A default width is 4.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a\tb"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("abc\td"));
+    ///     grid.set_tab_width(2);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-----+\n\
+    ///           |a b  |\n\
+    ///           +-----+\n\
+    ///           |abc d|\n\
+    ///           +-----+\n"
+    ///     )
+    /// ```
+    pub fn set_tab_width(&mut self, width: usize) {
+        self.config.tab_width = width;
+        self.sizes.invalidate();
+    }
+
+    /// Get_tab_width returns a distance between tab stops.
+    pub fn get_tab_width(&self) -> usize {
+        self.config.tab_width
+    }
+
+    /// Set_incremental makes the grid keep sizes of cells between renders.
+    ///
+    /// When a content of a cell is changed only this cell is measured again on a next render,
+    /// which saves most of a render time of a big grid which is refreshed cell by cell.
+    /// Any other change of the grid makes all cells to be measured again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::from_fn(1, 2, |_, column| column);
+    ///     grid.set_incremental(true);
+    ///     assert_eq!(grid.to_string(), "+-+-+\n|0|1|\n+-+-+\n");
+    ///
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("100"));
+    ///     assert_eq!(grid.to_string(), "+-+---+\n|0|100|\n+-+---+\n");
+    /// ```
+    pub fn set_incremental(&mut self, incremental: bool) {
+        self.sizes.enabled = incremental;
+        self.sizes.invalidate();
+    }
+
+    /// Get_incremental returns whether sizes of cells are kept between renders.
+    pub fn get_incremental(&self) -> bool {
+        self.sizes.enabled
+    }
+
+    /// Set_width_cache makes a grid keep widths of contents of cells by hashes of the contents,
+    /// so contents which were already measured aren't scanned again on next renders.
+    ///
+    /// Unlike [Grid::set_incremental] the cache survives any changes of a grid,
+    /// which is handy when a grid is refreshed by mostly the same data.
+    /// Widths of contents which are not met by a measurement of all cells are dropped,
+    /// so the cache holds about as many widths as there are distinct contents in the grid.
+    ///
+    /// Contents are looked up only by their 64-bit hashes,
+    /// so in an unlikely case of a collision a content gets a width of another one.
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::from_fn(1, 2, |_, _| "a");
+    ///     grid.set_width_cache(true);
+    ///     assert_eq!(grid.to_string(), "+-+-+\n|a|a|\n+-+-+\n");
+    ///
+    ///     let stats = grid.width_cache_stats();
+    ///     assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    /// ```
+    pub fn set_width_cache(&mut self, enabled: bool) {
+        self.widths.enabled = enabled;
+        self.widths.clear();
+    }
+
+    /// Get_width_cache returns whether widths of contents are cached.
+    pub fn get_width_cache(&self) -> bool {
+        self.widths.enabled
+    }
+
+    /// Width_cache_stats returns a statistics of the width cache, see [Grid::set_width_cache].
+    pub fn width_cache_stats(&self) -> CacheStats {
+        self.widths.stats()
+    }
+
+    /// Clear_width_cache removes all widths from the width cache and resets its statistics.
+    pub fn clear_width_cache(&mut self) {
+        self.widths.clear();
+    }
+
+    /// Push_row adds an empty row to the end of a grid.
+    pub fn push_row(&mut self) {
+        self.insert_row(self.count_rows());
+    }
+
+    /// Insert row in a grid.
+    ///
+    /// Settings of rows and cells which are below the index are moved down together with them.
+    pub fn insert_row(&mut self, index: usize) {
+        self.sizes.invalidate();
+        self.records
+            .cells
+            .insert(index, vec![Cow::Borrowed(""); self.count_columns()]);
+        self.config
+            .border_styles
+            .insert(index, Self::default_border());
+        self.records.size.0 += 1;
+
+        let shift = |row: usize| if row >= index { row + 1 } else { row };
+        self.shift_min_heights(|row| Some(shift(row)));
+        self.move_overrides(|row| Some(shift(row)), true, self.count_rows() - 1);
+        self.move_entities(|entity| match entity {
+            Entity::Row(row) => Some(Entity::Row(shift(row))),
+            Entity::Cell(row, column) => Some(Entity::Cell(shift(row), column)),
+            entity => Some(entity),
+        });
+    }
+
+    /// Removes a `row` from a grid.
+    ///
+    /// The row index must be started from 0.
+    /// Settings of the row are dropped and settings of rows below are moved up.
+    pub fn remove_row(&mut self, row: usize) {
+        self.sizes.invalidate();
+        self.records.cells.remove(row);
+        self.config.border_styles.remove(row);
+        self.records.size.0 -= 1;
+
+        self.shrink_spans(row, true);
+
+        let removed = row;
+        let shift = |row: usize| match row.cmp(&removed) {
+            std::cmp::Ordering::Less => Some(row),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(row - 1),
+        };
+        self.shift_min_heights(shift);
+        self.move_overrides(shift, true, self.count_rows() + 1);
+        self.move_entities(|entity| match entity {
+            Entity::Row(row) => shift(row).map(Entity::Row),
+            Entity::Cell(row, column) => shift(row).map(|row| Entity::Cell(row, column)),
+            entity => Some(entity),
+        });
+    }
+
+    /// Push_column adds an empty column to the end of a grid.
+    pub fn push_column(&mut self) {
+        self.insert_column(self.count_columns());
+    }
+
+    /// Insert_column adds an empty column to a grid.
+    ///
+    /// Settings of columns and cells which are to the right of the index are moved together with them.
+    pub fn insert_column(&mut self, index: usize) {
+        self.sizes.invalidate();
+        for row in &mut self.records.cells {
+            row.insert(index, Cow::Borrowed(""));
+        }
+        self.records.size.1 += 1;
+
+        let shift = |column: usize| {
+            if column >= index {
+                column + 1
+            } else {
+                column
+            }
+        };
+        self.shift_min_widths(|column| Some(shift(column)));
+        self.move_overrides(
+            |column| Some(shift(column)),
+            false,
+            self.count_columns() - 1,
+        );
+        self.move_entities(|entity| match entity {
+            Entity::Column(column) => Some(Entity::Column(shift(column))),
+            Entity::Cell(row, column) => Some(Entity::Cell(row, shift(column))),
+            entity => Some(entity),
+        });
+    }
+
+    /// Removes a `column` from a grid.
+    ///
+    /// The column index must be started from 0.
+    /// Settings of the column are dropped and settings of columns to the right are moved left.
+    pub fn remove_column(&mut self, column: usize) {
+        self.sizes.invalidate();
+        self.records.size.1 -= 1;
+        for row in 0..self.count_rows() {
+            self.records.cells[row].remove(column);
+        }
+
+        self.shrink_spans(column, false);
+
+        let removed = column;
+        let shift = |column: usize| match column.cmp(&removed) {
+            std::cmp::Ordering::Less => Some(column),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(column - 1),
+        };
+        self.shift_min_widths(shift);
+        self.move_overrides(shift, false, self.count_columns() + 1);
+        self.move_entities(|entity| match entity {
+            Entity::Column(column) => shift(column).map(Entity::Column),
+            Entity::Cell(row, column) => shift(column).map(|column| Entity::Cell(row, column)),
+            entity => Some(entity),
+        });
+    }
+
+    /// Set_column_order reorders columns according to a given permutation.
+    ///
+    /// The column which was at `order[i]` position is moved to `i` position.
+    /// Settings of the columns and cells are moved together with them,
+    /// so a spanned cell keeps its span when its columns stay next to each other in the same order.
+    ///
+    /// An order which is not a permutation of all columns of the grid or which splits columns of a spanned cell
+    /// is returned as an [OrderError] and the grid is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, OrderError, Settings};
+    ///     let mut grid = Grid::new(1, 3);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("0"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("1"));
+    ///     grid.set(Entity::Cell(0, 2), Settings::new().text("2"));
+    ///     assert_eq!(grid.set_column_order(&[2, 0, 1]), Ok(()));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+-+\n\
+    ///           |2|0|1|\n\
+    ///           +-+-+-+\n"
+    ///     );
+    ///
+    ///     assert_eq!(grid.set_column_order(&[0, 0, 1]), Err(OrderError::NotPermutation));
+    /// ```
+    pub fn set_column_order(&mut self, order: &[usize]) -> Result<(), OrderError> {
+        let count_columns = self.count_columns();
+        let positions =
+            permutation_positions(order, count_columns).ok_or(OrderError::NotPermutation)?;
+
+        for row in 0..self.count_rows() {
+            let mut column = 0;
+            while column < count_columns {
+                let span = self.get_cell_span(row, column);
+                let is_split = (column + 1..column + span)
+                    .any(|covered| positions[covered] != positions[column] + covered - column);
+                if is_split {
+                    return Err(OrderError::SplitSpan { row, column });
+                }
+
+                column += span;
+            }
+        }
+
+        self.sizes.invalidate();
+
+        for row in &mut self.records.cells {
+            let mut old = std::mem::take(row);
+            *row = order
+                .iter()
+                .map(|&column| std::mem::take(&mut old[column]))
+                .collect();
+        }
+
+        self.config.min_widths = self
+            .config
+            .min_widths
+            .drain()
+            .map(|(column, width)| match positions.get(column) {
+                Some(&position) => (position, width),
+                None => (column, width),
+            })
+            .collect();
+
+        self.move_entities(|entity| {
+            Some(match entity {
+                Entity::Column(column) if column < count_columns => {
+                    Entity::Column(positions[column])
+                }
+                Entity::Cell(row, column) if column < count_columns => {
+                    Entity::Cell(row, positions[column])
+                }
+                entity => entity,
+            })
+        });
+
+        Ok(())
+    }
+
+    /// Set_row_order reorders rows according to a given permutation.
+    ///
+    /// The row which was at `order[i]` position is moved to `i` position.
+    /// Settings of the rows and cells are moved together with them,
+    /// so a cell spanned over rows keeps its span when its rows stay next to each other in the same order.
+    /// Borders are kept in place.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `order` is not a permutation of all rows of the grid
+    /// or if it splits rows of a spanned cell.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(3, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("0"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("1"));
+    ///     grid.set(Entity::Cell(2, 0), Settings::new().text("2"));
+    ///     grid.set_row_order(&[2, 0, 1]);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+\n\
+    ///           |2|\n\
+    ///           +-+\n\
+    ///           |0|\n\
+    ///           +-+\n\
+    ///           |1|\n\
+    ///           +-+\n"
+    ///     )
+    /// ```
+    pub fn set_row_order(&mut self, order: &[usize]) {
+        self.sizes.invalidate();
+        let count_rows = self.count_rows();
+        let positions = permutation_positions(order, count_rows).unwrap_or_else(|| {
+            panic!(
+                "a row order {:?} is not a permutation of {} rows",
+                order, count_rows
+            )
+        });
+
+        for row in 0..count_rows {
+            let mut column = 0;
+            while column < self.count_columns() {
+                let row_span = self
+                    .get_cell_settings(row, column)
+                    .get_row_span()
+                    .unwrap_or(1)
+                    .min(count_rows - row);
+                let is_split = (row + 1..row + row_span)
+                    .any(|covered| positions[covered] != positions[row] + covered - row);
+                assert!(
+                    !is_split,
+                    "a row order {:?} splits a span of a cell ({}, {})",
+                    order, row, column
+                );
+
+                column += self.get_cell_span(row, column);
+            }
+        }
+
+        let mut old = std::mem::take(&mut self.records.cells);
+        self.records.cells = order
+            .iter()
+            .map(|&row| std::mem::take(&mut old[row]))
+            .collect();
+
+        self.shift_min_heights(|row| Some(positions.get(row).copied().unwrap_or(row)));
+
+        self.move_entities(|entity| {
+            Some(match entity {
+                Entity::Row(row) if row < count_rows => Entity::Row(positions[row]),
+                Entity::Cell(row, column) if row < count_rows => {
+                    Entity::Cell(positions[row], column)
+                }
+                entity => entity,
+            })
+        });
+    }
+
+    /// Select_rows creates a grid of given rows, a row may be selected more than once.
+    ///
+    /// Settings of rows and cells are taken together with them,
+    /// while borders are kept in place like in [Grid::set_row_order].
+    ///
+    /// # Panics
+    ///
+    /// The method panics if a row is out of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let grid = Grid::from_fn(4, 1, |row, _| row);
+    ///     assert_eq!(
+    ///          grid.select_rows(&[0, 3]).to_string(),
+    ///          "+-+\n\
+    ///           |0|\n\
+    ///           +-+\n\
+    ///           |3|\n\
+    ///           +-+\n"
+    ///     )
+    /// ```
+    pub fn select_rows(&self, rows: &[usize]) -> Grid<'a> {
+        let count_rows = rows.len();
+        let mut positions: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (position, &row) in rows.iter().enumerate() {
+            assert!(row < self.count_rows(), "row {} is out of the grid", row);
+            positions.entry(row).or_default().push(position);
+        }
+
+        let move_entity = |entity: Entity| -> Vec<Entity> {
+            match entity {
+                Entity::Row(row) => positions.get(&row).map_or_else(Vec::new, |p| {
+                    p.iter().map(|&row| Entity::Row(row)).collect()
+                }),
+                Entity::Cell(row, column) => positions.get(&row).map_or_else(Vec::new, |p| {
+                    p.iter().map(|&row| Entity::Cell(row, column)).collect()
+                }),
+                entity => vec![entity],
+            }
+        };
+
+        fn select<T: Clone>(
+            map: &HashMap<Entity, T>,
+            move_entity: impl Fn(Entity) -> Vec<Entity>,
+        ) -> HashMap<Entity, T> {
+            map.iter()
+                .flat_map(|(&entity, value)| {
+                    move_entity(entity)
+                        .into_iter()
+                        .map(move |entity| (entity, value.clone()))
+                })
+                .collect()
+        }
+
+        let mut border_styles = self.config.border_styles.clone();
+        border_styles.resize(count_rows, Self::default_border());
+
+        let overrides = BorderOverrides {
+            horizontal: (self.config.overrides.horizontal.iter())
+                .filter(|((line, _), _)| *line <= count_rows)
+                .map(|(&key, &c)| (key, c))
+                .collect(),
+            vertical: (self.config.overrides.vertical.iter())
+                .filter(|((row, _), _)| *row < count_rows)
+                .map(|(&key, &c)| (key, c))
+                .collect(),
+            intersection: (self.config.overrides.intersection.iter())
+                .filter(|((line, _), _)| *line <= count_rows)
+                .map(|(&key, &c)| (key, c))
+                .collect(),
+        };
+
+        let last_row = self.count_rows().checked_sub(1);
+        let config = GridConfig {
+            border_styles,
+            styles: select(&self.config.styles, move_entity),
+            colors: select(&self.config.colors, move_entity),
+            backgrounds: select(&self.config.backgrounds, move_entity),
+            classes: select(&self.config.classes, move_entity),
+            texts_out_of_grid: (self.config.texts_out_of_grid.iter())
+                .flat_map(|&entity| move_entity(entity))
+                .collect(),
+            overrides,
+            last_row_length: self
+                .config
+                .last_row_length
+                .filter(|_| rows.last().copied() == last_row),
+            min_widths: self.config.min_widths.clone(),
+            min_heights: rows
+                .iter()
+                .enumerate()
+                .filter_map(|(position, row)| {
+                    self.config.min_heights.get(row).map(|&h| (position, h))
+                })
+                .collect(),
+            layout: self.config.layout,
+            trim_trailing_whitespace: self.config.trim_trailing_whitespace,
+            tab_width: self.config.tab_width,
+        };
+
+        let records = Records {
+            size: (count_rows, self.count_columns()),
+            cells: rows
+                .iter()
+                .map(|&row| self.records.cells[row].clone())
+                .collect(),
+        };
+
+        Self {
+            records,
+            config,
+            sizes: SizeCache::default(),
+            widths: self.widths.clone(),
+        }
+    }
+
+    fn shift_min_heights<F: Fn(usize) -> Option<usize>>(&mut self, move_row: F) {
+        self.config.min_heights = self
+            .config
+            .min_heights
+            .drain()
+            .filter_map(|(row, height)| move_row(row).map(|row| (row, height)))
+            .collect();
+    }
+
+    fn shift_min_widths<F: Fn(usize) -> Option<usize>>(&mut self, move_column: F) {
+        self.config.min_widths = self
+            .config
+            .min_widths
+            .drain()
+            .filter_map(|(column, width)| move_column(column).map(|column| (column, width)))
+            .collect();
+    }
+
+    // overrides are moved together with a row below and a column to the right of them,
+    // a line below the last row and a separator after the last column stay at the end
+    fn move_overrides<F: Fn(usize) -> Option<usize>>(
+        &mut self,
+        shift: F,
+        is_row: bool,
+        old_count: usize,
+    ) {
+        let count = if is_row {
+            self.count_rows()
+        } else {
+            self.count_columns()
+        };
+        let shift_line = |line: usize| {
+            if line >= old_count {
+                Some(count)
+            } else {
+                shift(line)
+            }
+        };
+
+        // keys are (line, column), (row, separator) and (line, separator)
+        let overrides = &mut self.config.overrides;
+        if is_row {
+            move_keys(&mut overrides.horizontal, &shift_line, true);
+            move_keys(&mut overrides.vertical, &shift, true);
+            move_keys(&mut overrides.intersection, &shift_line, true);
+        } else {
+            move_keys(&mut overrides.horizontal, &shift, false);
+            move_keys(&mut overrides.vertical, &shift_line, false);
+            move_keys(&mut overrides.intersection, &shift_line, false);
+        }
+    }
+
+    // spans which cover a removed row or column lose it,
+    // a span which starts on it is dropped together with its cell
+    fn shrink_spans(&mut self, removed: usize, is_row: bool) {
+        for (entity, style) in self.config.styles.iter_mut() {
+            let (start, span) = match (*entity, is_row) {
+                (Entity::Cell(row, _), true) | (Entity::Row(row), true) => {
+                    (row, &mut style.row_span)
+                }
+                (Entity::Cell(_, column), false) | (Entity::Column(column), false) => {
+                    (column, &mut style.span)
+                }
+                _ => continue,
+            };
+
+            if start < removed && removed < start + *span {
+                *span -= 1;
+            }
+        }
+    }
+
+    // an entity which is moved to `None` is dropped together with its settings,
+    // spans of moved entities are cut at the end of the grid
+    fn move_entities<F: Fn(Entity) -> Option<Entity>>(&mut self, move_entity: F) {
+        let (count_rows, count_columns) = (self.count_rows(), self.count_columns());
+        self.config.styles = self
+            .config
+            .styles
+            .drain()
+            .filter_map(|(entity, style)| move_entity(entity).map(|entity| (entity, style)))
+            .map(|(entity, mut style)| {
+                if let Entity::Cell(row, _) | Entity::Row(row) = entity {
+                    style.row_span = style.row_span.min(count_rows.saturating_sub(row)).max(1);
+                }
+                if let Entity::Cell(_, column) | Entity::Column(column) = entity {
+                    style.span = style.span.min(count_columns.saturating_sub(column)).max(1);
+                }
+
+                (entity, style)
+            })
+            .collect();
+        self.config.colors = self
+            .config
+            .colors
+            .drain()
+            .filter_map(|(entity, color)| move_entity(entity).map(|entity| (entity, color)))
+            .collect();
+        self.config.backgrounds = self
+            .config
+            .backgrounds
+            .drain()
+            .filter_map(|(entity, color)| move_entity(entity).map(|entity| (entity, color)))
+            .collect();
+        self.config.classes = self
+            .config
+            .classes
+            .drain()
+            .filter_map(|(entity, class)| move_entity(entity).map(|entity| (entity, class)))
+            .collect();
+        self.config.texts_out_of_grid = self
+            .config
+            .texts_out_of_grid
+            .drain()
+            .filter_map(&move_entity)
+            .collect();
+    }
+
+    fn set_text<S: Into<String>>(&mut self, entity: &Entity, text: S) {
+        let is_in_grid = match *entity {
+            Entity::Global => true,
+            Entity::Column(column) => column < self.count_columns(),
+            Entity::Row(row) => row < self.count_rows(),
+            Entity::Cell(row, column) => row < self.count_rows() && column < self.count_columns(),
+        };
+        if !is_in_grid {
+            self.config.texts_out_of_grid.insert(*entity);
+            return;
+        }
+
+        let text = Cow::Owned(text.into());
+        match *entity {
+            Entity::Cell(row, column) => {
+                self.records.cells[row][column] = text;
+                self.sizes.mark(row, column);
+            }
+            Entity::Column(column) => {
+                for row in 0..self.count_rows() {
+                    self.records.cells[row][column] = text.clone();
+                    self.sizes.mark(row, column);
+                }
+            }
+            Entity::Row(row) => {
+                for column in 0..self.count_columns() {
+                    self.records.cells[row][column] = text.clone();
+                    self.sizes.mark(row, column);
+                }
+            }
+            Entity::Global => {
+                for row in 0..self.count_rows() {
+                    for column in 0..self.count_columns() {
+                        self.records.cells[row][column] = text.clone();
+                    }
+                }
+                self.sizes.invalidate();
+            }
+        }
+    }
+
+    fn style(&self, row: usize, column: usize) -> Style {
+        let v = [
+            self.config.styles.get(&Entity::Cell(row, column)),
+            self.config.styles.get(&Entity::Column(column)),
+            self.config.styles.get(&Entity::Row(row)),
+            self.config.styles.get(&Entity::Global),
+        ];
+
+        #[allow(clippy::manual_flatten)]
+        for styles in &v {
+            if let Some(style) = styles {
+                let mut style = (*style).clone();
+                style.color = self.color(row, column);
+                style.background = self.background(row, column);
+                style.remainder = self.config.layout.center_remainder;
+                return style;
+            }
+        }
+
+        unreachable!("there's a global settings guaranted in the map")
+    }
+
+    fn color(&self, row: usize, column: usize) -> Option<Color> {
+        [
+            Entity::Cell(row, column),
+            Entity::Column(column),
+            Entity::Row(row),
+            Entity::Global,
+        ]
+        .iter()
+        .find_map(|entity| self.config.colors.get(entity))
+        .cloned()
+    }
+
+    fn background(&self, row: usize, column: usize) -> Option<Color> {
+        [
+            Entity::Cell(row, column),
+            Entity::Column(column),
+            Entity::Row(row),
+            Entity::Global,
+        ]
+        .iter()
+        .find_map(|entity| self.config.backgrounds.get(entity))
+        .cloned()
+    }
+
+    fn class(&self, row: usize, column: usize) -> Option<&str> {
+        [
+            Entity::Cell(row, column),
+            Entity::Column(column),
+            Entity::Row(row),
+            Entity::Global,
+        ]
+        .iter()
+        .find_map(|entity| self.config.classes.get(entity))
+        .map(String::as_str)
+    }
+
+    // only changed cells are measured if sizes are cached
+    fn cells_size(&self, cells: &[Vec<StyledCell<'_>>]) -> Vec<Vec<(usize, usize)>> {
+        if !self.sizes.enabled {
+            return self.widths.cells_size(cells);
+        }
+
+        let mut cache = self.sizes.sizes.lock().unwrap();
+        let is_valid = |sizes: &CachedSizes| {
+            sizes.cells.len() == cells.len()
+                && (sizes.cells.iter().zip(cells)).all(|(sizes, row)| sizes.len() == row.len())
+        };
+
+        let sizes = match cache.take() {
+            Some(mut sizes) if is_valid(&sizes) => {
+                for (row, column) in sizes.changed.drain(..) {
+                    let (cell, style) = &cells[row][column];
+                    sizes.cells[row][column] = self.widths.cell_size(cell, style);
+                }
+
+                sizes.cells
+            }
+            _ => self.widths.cells_size(cells),
+        };
+
+        *cache = Some(CachedSizes {
+            cells: sizes.clone(),
+            changed: Vec::new(),
+        });
+
+        sizes
+    }
+
+    fn styled_cells(&self, count_rows: usize, count_columns: usize) -> Vec<Vec<StyledCell<'_>>> {
+        let mut rows = Vec::with_capacity(count_rows);
+        (0..count_rows).for_each(|row_index| {
+            let mut row = Vec::with_capacity(count_columns);
+
+            (0..count_columns).for_each(|column_index| {
+                let content = &self.records.cells[row_index][column_index];
+                let cell: Vec<_> = content
+                    .lines()
+                    .map(|line| expand_tabs(line, self.config.tab_width))
+                    .collect();
+                let mut style = self.style(row_index, column_index);
+                if style.nested.is_some() {
+                    // a nested grid fills a cell, so only a span is kept
+                    style = Style {
+                        span: style.span,
+                        row_span: style.row_span,
+                        nested: style.nested,
+                        ..Style::default()
+                    };
+                } else if style.raw {
+                    // only a span is kept as it's a part of the layout
+                    style = Style {
+                        span: style.span,
+                        row_span: style.row_span,
+                        raw: true,
+                        ..Style::default()
+                    };
+                }
+
+                row.push((cell, style));
+            });
+
+            rows.push(row);
+        });
+
+        // cells under a row span are replaced by a single empty cell which spans over its columns
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                if !is_cell_visible(&rows[row], column) || rows[row][column].1.row_span < 2 {
+                    continue;
+                }
+
+                let span = cell_span(&rows[row][column].1, column, count_columns);
+                let row_span = min(rows[row][column].1.row_span, count_rows - row);
+                rows[row][column].1.row_span = row_span;
+                for covered in &mut rows[row + 1..row + row_span] {
+                    for cell in &mut covered[column..column + span] {
+                        *cell = (Vec::new(), Style::default());
+                    }
+                    covered[column].1.span = span;
+                }
+            }
+        }
+
+        rows
+    }
+
+    // returns an origin and an amount of rows of each cell which spans over several rows
+    fn row_spans(&self, cells: &[Vec<StyledCell<'_>>]) -> Vec<(usize, usize, usize)> {
+        let count_columns = self.count_columns();
+        (0..self.count_rows())
+            .flat_map(|row| {
+                (0..count_columns)
+                    .filter(move |&column| is_cell_visible(&cells[row], column))
+                    .filter(move |&column| cells[row][column].1.row_span > 1)
+                    .map(move |column| (row, column, cells[row][column].1.row_span))
+            })
+            .collect()
+    }
+
+    // returns lines which junctions of a split line around a row are taken from
+    fn junctions(&self, row: usize) -> Junctions<'_> {
+        let last_row = self.count_rows() - 1;
+        Junctions {
+            top: &self.config.border_styles[0].top_line,
+            bottom: &self.config.border_styles[last_row].bottom_line,
+            vertical: &self.config.border_styles[row].inner,
+        }
+    }
+
+    // returns whether there's a split line below each row except the last one
+    fn split_lines(&self) -> Vec<bool> {
+        let count_rows = self.count_rows();
+        self.config.border_styles[..count_rows.saturating_sub(1)]
+            .iter()
+            .map(|border| !border.bottom_line.is_empty())
+            .collect()
+    }
+
+    fn layout<'b>(
+        &self,
+        mut cells: Vec<Vec<StyledCell<'b>>>,
+        column_widths: &[usize],
+    ) -> Layout<'b> {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+
+        let mut columns = (0..count_rows)
+            .map(|row| {
+                (0..count_columns)
+                    .filter(|&column| is_cell_visible(&cells[row], column))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut widths = cells_width(&mut cells, column_widths, count_rows, count_columns);
+
+        // cells of a ragged last row which are out of its length are not rendered
+        let last_row = count_rows - 1;
+        let ragged_length = self
+            .config
+            .last_row_length
+            .filter(|&length| length < count_columns);
+        if let Some(length) = ragged_length {
+            let visible = columns[last_row]
+                .iter()
+                .take_while(|&&column| column < length)
+                .count();
+            columns[last_row].truncate(visible);
+            widths[last_row].truncate(visible);
+            cells[last_row].truncate(visible);
+        }
+
+        Layout {
+            cells,
+            columns,
+            widths,
+            count_columns,
+            ragged_length,
+        }
+    }
+
+    fn default_border() -> Border {
+        Border {
+            inner: LineStyle {
+                main: Some('-'),
+                intersection: Some('|'),
+                left_intersection: Some('|'),
+                right_intersection: Some('|'),
+            },
+            bottom_line: LineStyle {
+                main: Some('-'),
+                intersection: Some('+'),
+                left_intersection: Some('+'),
+                right_intersection: Some('+'),
+            },
+            top_line: LineStyle {
+                main: Some('-'),
+                intersection: Some('+'),
+                left_intersection: Some('+'),
+                right_intersection: Some('+'),
+            },
+            color: None,
+        }
+    }
+}
+
+/// Settings represent setting of a particular cell
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    text: Option<String>,
+    indent: Option<Indent>,
+    alignment_h: Option<AlignmentHorizontal>,
+    alignment_v: Option<AlignmentVertical>,
+    span: Option<usize>,
+    row_span: Option<usize>,
+    indent_position: Option<IndentPosition>,
+    raw: Option<bool>,
+    nested: Option<Arc<Grid<'static>>>,
+    color: Option<Color>,
+    background: Option<Color>,
+    class: Option<String>,
+    fill: Option<char>,
+}
+
+impl Settings {
+    /// New method constructs an instance of settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Text method sets content for a cell
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Text_fmt sets content for a cell from formatting arguments,
+    /// so any [Display] value can be used without formatting it into a string beforehand.
+    ///
+    /// A static string is not formatted at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text_fmt(format_args!("{:.2}", 1.0 / 3.0)));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text_fmt(format_args!("static")));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+----+------+\n\
+    ///           |0.33|static|\n\
+    ///           +----+------+\n"
+    ///     )
+    /// ```
+    pub fn text_fmt(mut self, args: fmt::Arguments<'_>) -> Self {
+        let text = match args.as_str() {
+            Some(text) => text.to_owned(),
+            None => fmt::format(args),
+        };
+
+        self.text = Some(text);
+        self
+    }
+
+    /// Indent method sets indent for a cell
+    pub fn indent(mut self, left: usize, right: usize, top: usize, bottom: usize) -> Self {
+        self.indent = Some(Indent {
+            top,
+            bottom,
+            left,
+            right,
+        });
+        self
+    }
+
+    /// Alignment method sets horizontal alignment for a cell
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment_h = Some(alignment);
+        self
+    }
+
+    /// Alignment method sets horizontal alignment for a cell
+    pub fn vertical_alignment(mut self, alignment: AlignmentVertical) -> Self {
+        self.alignment_v = Some(alignment);
+        self
+    }
+
+    /// Set_span sets an amount of columns a cell covers including its own column,
+    /// like a `colspan` in HTML, so 2 makes a cell take its column and the next one.
+    ///
+    /// 0 and 1 mean a cell is not spanned.
+    /// A span which goes beyond the last column is cut at the end of a row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::from_fn(2, 3, |row, column| row * 3 + column);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("x").set_span(2));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("y").set_span(5));
+    ///     assert_eq!(grid.get_cell_span(1, 1), 2);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--+-+\n\
+    ///           |x |2|\n\
+    ///           +-++-+\n\
+    ///           |3|y |\n\
+    ///           +-+--+\n"
+    ///     )
+    /// ```
+    pub fn set_span(mut self, span: usize) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Set_row_span sets an amount of rows a cell spans.
+    ///
+    /// Together with [Settings::set_span] it makes a cell cover a rectangular region,
+    /// cells under the region are not rendered and borders are drawn only around it.
+    /// A span can't go beyond the last row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::from_fn(3, 3, |row, column| row * 3 + column);
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("x").set_span(2).set_row_span(2));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+---+\n\
+    ///           |0|x  |\n\
+    ///           +-+   |\n\
+    ///           |3|   |\n\
+    ///           +-+-+-+\n\
+    ///           |6|7|8|\n\
+    ///           +-+-+-+\n"
+    ///     )
+    /// ```
+    pub fn set_row_span(mut self, span: usize) -> Self {
+        self.row_span = Some(span);
+        self
+    }
+
+    /// Get_row_span returns an amount of rows a cell spans if it's set.
+    pub fn get_row_span(&self) -> Option<usize> {
+        self.row_span
+    }
+
+    /// Get_text returns a content if it's set.
+    pub fn get_text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Get_indent returns an indent if it's set in a `(left, right, top, bottom)` order.
+    pub fn get_indent(&self) -> Option<(usize, usize, usize, usize)> {
+        self.indent
+            .as_ref()
+            .map(|i| (i.left, i.right, i.top, i.bottom))
+    }
+
+    /// Get_alignment returns a horizontal alignment if it's set.
+    pub fn get_alignment(&self) -> Option<AlignmentHorizontal> {
+        self.alignment_h
+    }
+
+    /// Get_vertical_alignment returns a vertical alignment if it's set.
+    pub fn get_vertical_alignment(&self) -> Option<AlignmentVertical> {
+        self.alignment_v
+    }
+
+    /// Get_span returns a span if it's set.
+    pub fn get_span(&self) -> Option<usize> {
+        self.span
+    }
+
+    /// Get_indent_position returns an indent position if it's set.
+    pub fn get_indent_position(&self) -> Option<IndentPosition> {
+        self.indent_position
+    }
+
+    /// Indent_position method sets whether an indent is applied inside or outside of the alignment width.
+    pub fn indent_position(mut self, position: IndentPosition) -> Self {
+        self.indent_position = Some(position);
+        self
+    }
+
+    /// Fill method sets a character which fills a space left by an alignment between a content and an indent.
+    ///
+    /// An indent itself is always filled with spaces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Column(0), Settings::new().indent(1, 1, 0, 0).fill('.'));
+    ///     grid.set(
+    ///         Entity::Column(1),
+    ///         Settings::new().indent(1, 1, 0, 0).fill('0').alignment(AlignmentHorizontal::Right),
+    ///     );
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("name"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("42"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("address"));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("1024"));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---------+------+\n\
+    ///           | name... | 0042 |\n\
+    ///           +---------+------+\n\
+    ///           | address | 1024 |\n\
+    ///           +---------+------+\n"
+    ///     )
+    /// ```
+    pub fn fill(mut self, fill: char) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Get_fill returns a fill character if it's set.
+    pub fn get_fill(&self) -> Option<char> {
+        self.fill
+    }
+
+    /// Raw method sets whether a cell content is rendered as is.
+    ///
+    /// A raw content is not indented, aligned nor colored,
+    /// so it's an escape hatch for pre-rendered content like braille graphics or pre-colored text.
+    /// A caller is responsible for its lines to have a width of a column,
+    /// shorter lines are only filled up with spaces to keep borders in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, AlignmentHorizontal};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Global, Settings::new().indent(1, 1, 0, 0).alignment(AlignmentHorizontal::Right));
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("abc"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("⣿⣿⣿⣿⣿⣿⣿").raw(true));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-------+\n\
+    ///           |   abc |\n\
+    ///           +-------+\n\
+    ///           |⣿⣿⣿⣿⣿⣿⣿|\n\
+    ///           +-------+\n"
+    ///     )
+    /// ```
+    pub fn raw(mut self, raw: bool) -> Self {
+        self.raw = Some(raw);
+        self
+    }
+
+    /// Get_raw returns whether a content is rendered as is if it's set.
+    pub fn get_raw(&self) -> Option<bool> {
+        self.raw
+    }
+
+    /// Nested method puts a grid inside of a cell instead of its text.
+    ///
+    /// A width and a height of the nested grid take part in a layout like a content does,
+    /// and the nested grid is stretched to fill the whole cell,
+    /// so its lines meet borders of the cell.
+    /// A cell indent and alignment are not applied to it.
+    ///
+    /// A text of the cell is kept, so it may be used as a fallback by exporters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let nested = Grid::from_fn(1, 2, |_, column| column);
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("abcdefgh"));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().nested(nested));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--------+--------+\n\
+    ///           |abcdefgh|abcdefgh|\n\
+    ///           +--------+--------+\n\
+    ///           |abcdefgh|+-+----+|\n\
+    ///           |        ||0|1   ||\n\
+    ///           |        |+-+----+|\n\
+    ///           +--------+--------+\n"
+    ///     )
+    /// ```
+    pub fn nested(mut self, grid: Grid<'static>) -> Self {
+        self.nested = Some(Arc::new(grid));
+        self
+    }
+
+    /// Get_nested returns a nested grid if it's set.
+    pub fn get_nested(&self) -> Option<&Grid<'static>> {
+        self.nested.as_deref()
+    }
+
+    /// Color method sets a color of a cell content.
+    ///
+    /// The color is applied to each line of a content separately,
+    /// so it doesn't leak to borders or other cells.
+    #[cfg(feature = "color")]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Get_color returns a color if it's set.
+    #[cfg(feature = "color")]
+    pub fn get_color(&self) -> Option<&Color> {
+        self.color.as_ref()
+    }
+
+    /// Background method sets a color of a whole cell, including its indent and an alignment space.
+    ///
+    /// It's kept apart from [Settings::color], so a content color is drawn over the background.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, Color};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("a").indent(1, 1, 0, 0));
+    ///     grid.set(Entity::Row(0), Settings::new().background(Color::on_black()));
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().color(Color::red()));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+\n\
+    ///           |\u{1b}[40m \u{1b}[31ma\u{1b}[0m\u{1b}[40m \u{1b}[0m|\n\
+    ///           +---+\n"
+    ///     )
+    /// ```
+    #[cfg(feature = "color")]
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Get_background returns a background color if it's set.
+    #[cfg(feature = "color")]
+    pub fn get_background(&self) -> Option<&Color> {
+        self.background.as_ref()
+    }
+
+    /// Class method sets a name of a class of a cell.
+    ///
+    /// It's not rendered, but it's kept to tell what a cell means
+    /// to code which exports a grid into other formats.
+    /// Like a color it doesn't reset other settings of a cell.
+    pub fn class<S: Into<String>>(mut self, class: S) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Get_class returns a class if it's set.
+    pub fn get_class(&self) -> Option<&str> {
+        self.class.as_deref()
+    }
+}
+
+/// Border structure represent all borders of a row
+#[derive(Debug, Clone)]
+pub struct Border {
+    top_line: LineStyle,
+    bottom_line: LineStyle,
+    inner: LineStyle,
+    color: Option<Color>,
+}
+
+impl Border {
+    /// empty border returns a border for a row with no frame and no internal separation
+    ///
+    /// A color of the border is kept.
+    pub fn empty(&mut self) -> &mut Self {
+        *self = Self {
+            top_line: LineStyle::default(),
+            bottom_line: LineStyle::default(),
+            inner: LineStyle::default(),
+            color: self.color.take(),
+        };
+
+        self
+    }
+
+    /// The method sets a color which is used for all characters of the border.
+    #[cfg(feature = "color")]
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// The method sets a top border line.
+    ///
+    /// * `main` - is a character which is used for building line.
+    /// * `intersection` - a character which is used for internal separation on the line.
+    /// * `left_intersection` - a left border character.
+    /// * `right_intersection` - a right border character.
+    pub fn top(
+        &mut self,
+        main: char,
+        intersection: char,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.top_line = LineStyle {
+            main: Some(main),
+            intersection: Some(intersection),
+            left_intersection,
+            right_intersection,
+        };
+
+        self
+    }
+
+    /// The method sets a bottom border line.
+    ///
+    /// * `main` - is a character which is used for building line.
+    /// * `intersection` - a character which is used for internal separation on the line.
+    /// * `left_intersection` - a left border character.
+    /// * `right_intersection` - a right border character.
+    pub fn bottom(
+        &mut self,
+        main: char,
+        intersection: char,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.bottom_line = LineStyle {
+            main: Some(main),
+            intersection: Some(intersection),
+            left_intersection,
+            right_intersection,
+        };
+
+        self
+    }
+
+    /// The method sets an inner row symbols.
+    ///
+    /// * `intersection` - a character which is used for internal separation on the line.
+    /// * `left_intersection` - a left border character.
+    /// * `right_intersection` - a right border character.
+    pub fn inner(
+        &mut self,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.inner = LineStyle {
+            main: None,
+            intersection,
+            left_intersection,
+            right_intersection,
+        };
+
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct LineStyle {
+    main: Option<char>,
+    intersection: Option<char>,
+    left_intersection: Option<char>,
+    right_intersection: Option<char>,
+}
+
+/// BorderOverrides holds characters which replace a row border on particular places.
+#[derive(Debug, Clone, Default)]
+struct BorderOverrides {
+    // (line, column)
+    horizontal: HashMap<(usize, usize), char>,
+    // (row, separator)
+    vertical: HashMap<(usize, usize), char>,
+    // (line, separator)
+    intersection: HashMap<(usize, usize), char>,
+}
+
+impl LineStyle {
+    fn is_empty(&self) -> bool {
+        self.left_intersection.is_none()
+            && self.right_intersection.is_none()
+            && self.intersection.is_none()
+            && self.main.is_none()
+    }
+}
+
+/// ValidationError describes a setting which can't be applied as it is, see [Grid::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Settings are set for an entity outside of the grid, so they're never used.
+    EntityOutOfGrid(Entity),
+    /// A cell spans over more columns than there are left in its row,
+    /// the span is cut at the end of the row.
+    SpanOutOfGrid {
+        /// A row of the cell.
+        row: usize,
+        /// A column of the cell.
+        column: usize,
+        /// A span of the cell.
+        span: usize,
+    },
+    /// A cell spans over more rows than there are left below it,
+    /// the span is cut at the last row.
+    RowSpanOutOfGrid {
+        /// A row of the cell.
+        row: usize,
+        /// A column of the cell.
+        column: usize,
+        /// A row span of the cell.
+        span: usize,
+    },
+    /// A spanned cell is covered by a span of another cell, so it's not rendered.
+    OverlappingSpans {
+        /// A row of the covered cell.
+        row: usize,
+        /// A column of the covered cell.
+        column: usize,
+    },
+    /// A minimal width is set for a column which doesn't exist.
+    MinWidthOutOfGrid(usize),
+    /// A minimal height is set for a row which doesn't exist.
+    MinHeightOutOfGrid(usize),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EntityOutOfGrid(entity) => {
+                write!(
+                    f,
+                    "settings are set for {:?} which is out of the grid",
+                    entity
+                )
+            }
+            Self::SpanOutOfGrid { row, column, span } => write!(
+                f,
+                "a cell ({}, {}) spans over {} columns which is beyond the row",
+                row, column, span
+            ),
+            Self::RowSpanOutOfGrid { row, column, span } => write!(
+                f,
+                "a cell ({}, {}) spans over {} rows which is beyond the grid",
+                row, column, span
+            ),
+            Self::OverlappingSpans { row, column } => write!(
+                f,
+                "a spanned cell ({}, {}) is covered by another span",
+                row, column
+            ),
+            Self::MinWidthOutOfGrid(column) => write!(
+                f,
+                "a minimal width is set for a column {} which is out of the grid",
+                column
+            ),
+            Self::MinHeightOutOfGrid(row) => write!(
+                f,
+                "a minimal height is set for a row {} which is out of the grid",
+                row
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// OrderError describes an order which can't be applied by [Grid::set_column_order].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError {
+    /// An order is not a permutation of all columns of the grid.
+    NotPermutation,
+    /// An order puts columns of a spanned cell apart or changes their order.
+    SplitSpan {
+        /// A row of the spanned cell.
+        row: usize,
+        /// A column of the spanned cell.
+        column: usize,
+    },
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotPermutation => write!(f, "an order is not a permutation of all columns"),
+            Self::SplitSpan { row, column } => write!(
+                f,
+                "an order splits columns of a spanned cell ({}, {})",
+                row, column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+/// Entity a structure which represent a set of cells.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub enum Entity {
+    /// All cells on the grid.
+    Global,
+    /// All cells in a column on the grid.
+    Column(usize),
+    /// All cells in a row on the grid.
+    Row(usize),
+    /// A particular cell (row, column) on the grid.
+    Cell(usize, usize),
+}
+
+#[derive(Debug, Clone)]
+struct Style {
+    indent: Indent,
+    alignment_h: AlignmentHorizontal,
+    alignment_v: AlignmentVertical,
+    span: usize,
+    row_span: usize,
+    indent_position: IndentPosition,
+    raw: bool,
+    nested: Option<Arc<Grid<'static>>>,
+    color: Option<Color>,
+    background: Option<Color>,
+    remainder: Remainder,
+    fill: char,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            alignment_h: AlignmentHorizontal::Left,
+            alignment_v: AlignmentVertical::Top,
+            indent: Indent {
+                bottom: 0,
+                left: 0,
+                right: 0,
+                top: 0,
+            },
+            span: 1,
+            row_span: 1,
+            indent_position: IndentPosition::Outside,
+            raw: false,
+            nested: None,
+            color: None,
+            background: None,
+            remainder: Remainder::End,
+            fill: ' ',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Indent {
+    top: usize,
+    bottom: usize,
+    left: usize,
+    right: usize,
+}
+
+/// IndentPosition represents whether an indent is applied inside or outside of an alignment width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndentPosition {
+    /// The content is aligned in a width without an indent and the indent is put around it (align-then-pad).
+    ///
+    /// It's a default behaiviour.
+    Outside,
+    /// The content is aligned in a whole width of a cell (pad-then-align).
+    ///
+    /// An indent only guarantees a minimal distance between a content and a border.
+    Inside,
+}
+
+/// LayoutOptions pins decisions which have no single right answer when a grid is laid out.
+///
+/// All of them are made in integer arithmetic in a fixed order,
+/// so a grid is rendered the same way on any platform.
+/// A default value keeps the behaviour the grid had before the options were introduced.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Grid, Entity, LayoutOptions, Settings, Spread};
+///     let mut grid = Grid::new(2, 2);
+///     grid.set(Entity::Cell(0, 0), Settings::new().text("0123456").set_span(2));
+///     grid.set(Entity::Cell(1, 0), Settings::new().text("a"));
+///     grid.set(Entity::Cell(1, 1), Settings::new().text("b"));
+///     grid.set_layout_options(LayoutOptions::new().span_spread(Spread::Last));
+///     assert_eq!(
+///          grid.to_string(),
+///          "+-------+\n\
+///           |0123456|\n\
+///           +-+-----+\n\
+///           |a|b    |\n\
+///           +-+-----+\n"
+///     )
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LayoutOptions {
+    span_spread: Spread,
+    center_remainder: Remainder,
+    span_separators: SeparatorOwnership,
+}
+
+impl LayoutOptions {
+    /// New creates default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Span_spread sets which columns get an extra width a spanned cell needs.
+    pub fn span_spread(mut self, spread: Spread) -> Self {
+        self.span_spread = spread;
+        self
+    }
+
+    /// Center_remainder sets a side which gets an odd space around a centered content.
+    pub fn center_remainder(mut self, remainder: Remainder) -> Self {
+        self.center_remainder = remainder;
+        self
+    }
+
+    /// Span_separators sets whether separators inside of a span count towards its width.
+    pub fn span_separators(mut self, ownership: SeparatorOwnership) -> Self {
+        self.span_separators = ownership;
+        self
+    }
+
+    /// Get_span_spread returns a [Spread] of spanned cells.
+    pub fn get_span_spread(&self) -> Spread {
+        self.span_spread
+    }
+
+    /// Get_center_remainder returns a [Remainder] of centered contents.
+    pub fn get_center_remainder(&self) -> Remainder {
+        self.center_remainder
+    }
+
+    /// Get_span_separators returns a [SeparatorOwnership] of spanned cells.
+    pub fn get_span_separators(&self) -> SeparatorOwnership {
+        self.span_separators
+    }
+}
+
+/// Spread represents how an extra width of a spanned cell is split among its columns.
+///
+/// Spans are handled from narrow to wide ones, a wide span takes into account
+/// an expansion made by narrower ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Spread {
+    /// Columns get a char in turn starting from the first one,
+    /// so leading columns get a remainder of an uneven split.
+    ///
+    /// It's a default behaiviour.
+    #[default]
+    Leading,
+    /// Columns get a char in turn starting from the last one,
+    /// so trailing columns get a remainder of an uneven split.
+    Trailing,
+    /// The first column gets the whole extra width.
+    First,
+    /// The last column gets the whole extra width.
+    Last,
+    /// Columns get an extra width in proportion to their widths,
+    /// what's left after an even split goes to columns with the largest remainders,
+    /// leading columns go first on a tie.
+    ///
+    /// Columns of zero width are handled like by [Spread::Leading].
+    Proportional,
+}
+
+/// Remainder represents a side which gets an odd space when a content can't be centered exactly.
+///
+/// It's used by both horizontal and vertical centering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Remainder {
+    /// The odd space is put after a content, on the right or at the bottom.
+    ///
+    /// It's a default behaiviour.
+    #[default]
+    End,
+    /// The odd space is put before a content, on the left or at the top.
+    Start,
+}
+
+impl Remainder {
+    // returns a space which is put before a content
+    fn half(&self, space: usize) -> usize {
+        match self {
+            Self::End => space / 2,
+            Self::Start => space - space / 2,
+        }
+    }
+}
+
+/// SeparatorOwnership represents who owns vertical separators inside of a span.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SeparatorOwnership {
+    /// Separators belong to a spanned cell, so a content may take their place.
+    ///
+    /// It's a default behaiviour.
+    #[default]
+    Span,
+    /// Separators belong to columns, so a content must fit into widths of the columns alone.
+    ///
+    /// Places of separators are left blank.
+    Columns,
+}
+
+/// Measurement is a size of a [Grid] calculated by [Grid::measure].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Measurement {
+    column_widths: Vec<usize>,
+    row_heights: Vec<usize>,
+    column_offsets: Vec<usize>,
+    row_offsets: Vec<usize>,
+    width: usize,
+    height: usize,
+}
+
+impl Measurement {
+    /// Width returns a width of the longest line of a rendered grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height returns an amount of lines of a rendered grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Column_widths returns widths of columns, not including borders.
+    pub fn column_widths(&self) -> &[usize] {
+        &self.column_widths
+    }
+
+    /// Row_heights returns heights of rows, not including borders.
+    pub fn row_heights(&self) -> &[usize] {
+        &self.row_heights
+    }
+
+    /// Column_offsets returns a display column of a first character of each column
+    /// in a rendered line.
+    ///
+    /// Positions are calculated by borders of the first row.
+    /// They are counted in terminal columns rather than bytes,
+    /// so they differ from byte offsets when borders or contents are not ASCII.
+    pub fn column_offsets(&self) -> &[usize] {
+        &self.column_offsets
+    }
+
+    /// Row_offsets returns an index of a first line of each row in a rendered grid.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    /// Cell_position returns a `(x, y)` position of a top left corner of a cell content area,
+    /// which can be used to put a cursor or an overlay over a rendered grid.
+    ///
+    /// `x` is a display column, the same as in [Self::column_offsets], and `y` is a line index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("0-0"));
+    ///     grid.set_vertical_char(1, 1, '│');
+    ///
+    ///     let measurement = grid.measure();
+    ///     let (x, y) = measurement.cell_position(1, 1).unwrap();
+    ///
+    ///     let rendered = grid.to_string();
+    ///     let line = rendered.lines().nth(y).unwrap();
+    ///     let content = line.chars().skip(x).take(3).collect::<String>();
+    ///     assert_eq!((x, y), (5, 3));
+    ///     assert_eq!(content, "0-0");
+    /// ```
+    pub fn cell_position(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        let x = self.column_offsets.get(column)?;
+        let y = self.row_offsets.get(row)?;
+        Some((*x, *y))
+    }
+}
+
+/// Anchor represents a side of a grid which is kept when not all rows fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    /// Rows at the top are kept.
+    Top,
+    /// Rows at the bottom are kept.
+    Bottom,
+}
+
+/// Color represents an ANSI style which wraps a text.
+///
+/// A `prefix` is put before a text and a `suffix` after it.
+/// They are not counted in a width of a cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    prefix: String,
+    suffix: String,
+}
+
+impl Color {
+    const RESET: &'static str = "\u{1b}[0m";
+
+    /// New creates a color from a custom prefix and suffix.
+    pub fn new<S: Into<String>>(prefix: S, suffix: S) -> Self {
+        Self {
+            prefix: prefix.into(),
+            suffix: suffix.into(),
+        }
+    }
+
+    fn sgr(code: u8) -> Self {
+        Self::new(format!("\u{1b}[{}m", code), Self::RESET.to_owned())
+    }
+
+    /// Black foreground color.
+    pub fn black() -> Self {
+        Self::sgr(30)
+    }
+
+    /// Red foreground color.
+    pub fn red() -> Self {
+        Self::sgr(31)
+    }
+
+    /// Green foreground color.
+    pub fn green() -> Self {
+        Self::sgr(32)
+    }
+
+    /// Yellow foreground color.
+    pub fn yellow() -> Self {
+        Self::sgr(33)
+    }
+
+    /// Blue foreground color.
+    pub fn blue() -> Self {
+        Self::sgr(34)
+    }
+
+    /// Magenta foreground color.
+    pub fn magenta() -> Self {
+        Self::sgr(35)
+    }
+
+    /// Cyan foreground color.
+    pub fn cyan() -> Self {
+        Self::sgr(36)
+    }
+
+    /// White foreground color.
+    pub fn white() -> Self {
+        Self::sgr(37)
+    }
+
+    /// Black background color.
+    pub fn on_black() -> Self {
+        Self::sgr(40)
+    }
+
+    /// Red background color.
+    pub fn on_red() -> Self {
+        Self::sgr(41)
+    }
+
+    /// Green background color.
+    pub fn on_green() -> Self {
+        Self::sgr(42)
+    }
+
+    /// Yellow background color.
+    pub fn on_yellow() -> Self {
+        Self::sgr(43)
+    }
+
+    /// Blue background color.
+    pub fn on_blue() -> Self {
+        Self::sgr(44)
+    }
+
+    /// Magenta background color.
+    pub fn on_magenta() -> Self {
+        Self::sgr(45)
+    }
+
+    /// Cyan background color.
+    pub fn on_cyan() -> Self {
+        Self::sgr(46)
+    }
+
+    /// White background color.
+    pub fn on_white() -> Self {
+        Self::sgr(47)
+    }
+
+    /// Bold text.
+    pub fn bold() -> Self {
+        Self::sgr(1)
+    }
+
+    /// Dim text.
+    pub fn dim() -> Self {
+        Self::sgr(2)
+    }
+
+    /// And combines 2 colors, the prefixes and suffixes are joined.
+    pub fn and(self, other: Color) -> Self {
+        Self {
+            prefix: self.prefix + &other.prefix,
+            suffix: other.suffix + &self.suffix,
+        }
+    }
+}
+
+// Colored wraps a text in a color if it's present
+// Render measures and draws a grid calling a progress callback after each row
+struct Render<'a, 'b, F>(&'a Grid<'b>, RefCell<F>);
+
+impl<F: FnMut(usize, usize)> Display for Render<'_, '_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.count_rows() == 0 || self.0.count_columns() == 0 {
+            return Ok(());
+        }
+
+        let mut progress = self.1.borrow_mut();
+        let (measurement, layout, row_spans) = self.0.measure_layout();
+        self.0
+            .fmt_layout(f, &measurement, layout, &row_spans, &mut *progress)
+    }
+}
+
+struct Colored<'a, D>(D, Option<&'a Color>);
+
+// Once displays a value by calling a function which can be called only once
+struct Once<F>(RefCell<Option<F>>);
+
+impl<F> Once<F> {
+    fn new(f: F) -> Self {
+        Self(RefCell::new(Some(f)))
+    }
+}
+
+impl<F: FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result> Display for Once<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let draw = self
+            .0
+            .borrow_mut()
+            .take()
+            .expect("it's displayed only once");
+        draw(f)
+    }
+}
+
+// TrimLines holds whitespace back until a next char,
+// so whitespace which is followed by a `\n` is never written
+struct TrimLines<W> {
+    inner: W,
+    pending: String,
+}
+
+impl<W: fmt::Write> fmt::Write for TrimLines<W> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        for c in text.chars() {
+            if c == '\n' {
+                self.pending.clear();
+                self.inner.write_char(c)?;
+            } else if c.is_whitespace() {
+                self.pending.push(c);
+            } else {
+                self.inner.write_str(&self.pending)?;
+                self.pending.clear();
+                self.inner.write_char(c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn has_gaps(text: &str) -> bool {
+    text.split_whitespace().nth(1).is_some()
+}
+
+impl<D: Display> Display for Colored<'_, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self.1 {
+            Some(color) => write!(f, "{}{}{}", color.prefix, self.0, color.suffix),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl AlignmentHorizontal {
+    fn align(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        text: &str,
+        width: usize,
+        color: Option<&Color>,
+        remainder: Remainder,
+        fill: char,
+    ) -> fmt::Result {
+        // it's important step
+        // we are ignoring trailing spaces which allows us to do alignment with more space
+        // example: tests::grid_2x2_alignment_test
+        let text = text.trim();
+        if *self == AlignmentHorizontal::Justify && has_gaps(text) {
+            return Colored(Justified(text, width, string_width), color).fmt(f);
+        }
+
+        let text_width = string_width(text);
+        let diff = width - text_width;
+        let left = match self {
+            AlignmentHorizontal::Left | AlignmentHorizontal::Justify => 0,
+            AlignmentHorizontal::Right => diff,
+            AlignmentHorizontal::Center => remainder.half(diff),
+        };
+
+        repeat_char(f, fill, left)?;
+        write!(f, "{}", Colored(text, color))?;
+        repeat_char(f, fill, diff - left)
+    }
+
+    fn align_with_indent(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        text: &str,
+        width: usize,
+        style: &Style,
+    ) -> fmt::Result {
+        // the content is aligned against the whole width,
+        // the indent only guarantees a minimal distance to a border
+        let (left_indent, right_indent) = (style.indent.left, style.indent.right);
+        if *self == AlignmentHorizontal::Justify && has_gaps(text) {
+            let width = width - left_indent - right_indent;
+            repeat_char(f, ' ', left_indent)?;
+            Colored(Justified(text, width, string_width), style.color.as_ref()).fmt(f)?;
+            return repeat_char(f, ' ', right_indent);
+        }
+
+        let diff = width - string_width(text);
+        let left = match self {
+            AlignmentHorizontal::Left | AlignmentHorizontal::Justify => left_indent,
+            AlignmentHorizontal::Right => diff - right_indent,
+            AlignmentHorizontal::Center => min(
+                max(style.remainder.half(diff), left_indent),
+                diff - right_indent,
+            ),
+        };
+        let right = diff - left;
+
+        // an indent is kept blank, only a space between it and the content is filled
+        let left_fill = left.saturating_sub(left_indent);
+        let right_fill = right.saturating_sub(right_indent);
+        repeat_char(f, ' ', left - left_fill)?;
+        repeat_char(f, style.fill, left_fill)?;
+        write!(f, "{}", Colored(text, style.color.as_ref()))?;
+        repeat_char(f, style.fill, right_fill)?;
+        repeat_char(f, ' ', right - right_fill)
+    }
+}
+
+/// AlignmentVertical represents an vertical aligment of a cell content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlignmentVertical {
+    Center,
+    Top,
+    Bottom,
+}
+
+impl AlignmentVertical {
+    fn top_ident(&self, height: usize, real_height: usize, remainder: Remainder) -> usize {
+        match self {
+            AlignmentVertical::Top => 0,
+            AlignmentVertical::Bottom => height - real_height,
+            AlignmentVertical::Center => remainder.half(height - real_height),
+        }
+    }
+}
+
+impl std::fmt::Display for Grid<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+
+        // It may happen when all cells removed via `remove_row`, `remove_column` methods
+        if count_rows == 0 || count_columns == 0 {
+            return Ok(());
+        }
+
+        if let Some(style) = self.simple_style() {
+            return self.fmt_simple(f, &style);
+        }
+
+        let (measurement, layout, row_spans) = self.measure_layout();
+        self.fmt_layout(f, &measurement, layout, &row_spans, &mut |_, _| {})
+    }
+}
+
+impl Grid<'_> {
+    /// Measure calculates a size of the grid without rendering it.
+    ///
+    /// The result can be passed to [Grid::draw] to render the grid later
+    /// without calculating widths and heights again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("0-0"));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("1-1\n1-1"));
+    ///
+    ///     let measurement = grid.measure();
+    ///     assert_eq!(measurement.width(), 9);
+    ///     assert_eq!(measurement.height(), 6);
+    ///     assert_eq!(measurement.column_widths(), &[3, 3]);
+    ///     assert_eq!(measurement.row_heights(), &[1, 2]);
+    ///
+    ///     assert_eq!(grid.draw(&measurement), grid.to_string());
+    /// ```
+    pub fn measure(&self) -> Measurement {
+        if self.count_rows() == 0 || self.count_columns() == 0 {
+            return Measurement::default();
+        }
+
+        self.measure_layout().0
+    }
+
+    // cells are built once and kept in a layout, so they can be drawn right after a measurement
+    fn measure_layout(&self) -> (Measurement, Layout<'_>, Vec<(usize, usize, usize)>) {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+
+        let cells = self.styled_cells(count_rows, count_columns);
+        let row_spans = self.row_spans(&cells);
+        let sizes = self.cells_size(&cells);
+        let row_heights = rows_height(
+            &cells,
+            &sizes,
+            &row_spans,
+            &self.split_lines(),
+            &self.config.min_heights,
+            &self.config.layout,
+            count_rows,
+        );
+        let column_widths = columns_width(
+            &cells,
+            &sizes,
+            &self.config.min_widths,
+            &self.config.layout,
+            count_rows,
+            count_columns,
+        );
+        let layout = self.layout(cells, &column_widths);
+
+        let width = (0..count_rows)
+            .map(|row| {
+                let border = &self.config.border_styles[row];
+                let count_separators = separators(
+                    &border.inner,
+                    &self.config.overrides.vertical,
+                    row,
+                    &layout.columns[row],
+                    layout.row_length(row),
+                )
+                .iter()
+                .filter(|c| c.is_some())
+                .count();
+
+                layout.widths[row].iter().sum::<usize>() + count_separators
+            })
+            .max()
+            .unwrap_or(0);
+
+        let has_line = |line: &LineStyle| if line.is_empty() { 0 } else { 1 };
+        let mut row_offsets = Vec::with_capacity(count_rows);
+        let mut height = has_line(&self.config.border_styles[0].top_line);
+        for (row, border) in self.config.border_styles.iter().enumerate() {
+            row_offsets.push(height);
+            height += row_heights[row] + has_line(&border.bottom_line);
+        }
+
+        // offsets are taken from the first row as if it had no spans
+        let all_columns = (0..count_columns).collect::<Vec<_>>();
+        let separators = separators(
+            &self.config.border_styles[0].inner,
+            &self.config.overrides.vertical,
+            0,
+            &all_columns,
+            count_columns,
+        );
+        let mut column_offsets = Vec::with_capacity(count_columns);
+        let mut x = 0;
+        for (column, width) in column_widths.iter().enumerate() {
+            if separators[column].is_some() {
+                x += 1;
+            }
+
+            column_offsets.push(x);
+            x += width;
+        }
+
+        let measurement = Measurement {
+            column_widths,
+            row_heights,
+            column_offsets,
+            row_offsets,
+            width,
+            height,
+        };
+
+        (measurement, layout, row_spans)
+    }
+
+    /// Draw renders the grid using a [Measurement] which was made by [Grid::measure].
+    ///
+    /// # Panics
+    ///
+    /// The grid must not be changed in between, a measurement of a grid with a different size causes a panic.
+    pub fn draw(&self, measurement: &Measurement) -> String {
+        struct Draw<'a, 'b>(&'a Grid<'b>, &'a Measurement);
+
+        impl Display for Draw<'_, '_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt_measured(f, self.1)
+            }
+        }
+
+        Draw(self, measurement).to_string()
+    }
+
+    /// Validate checks settings of the grid which can't be applied as they are,
+    /// so they don't silently produce an unexpected output.
+    ///
+    /// The grid is rendered the same way whether or not it's valid,
+    /// problems are collected in an order of rows and columns.
+    ///
+    /// A text set for a cell, a row or a column out of the grid is dropped
+    /// and reported as [ValidationError::EntityOutOfGrid] as well as other settings of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, ValidationError};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     assert_eq!(grid.validate(), Ok(()));
+    ///
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().set_span(2));
+    ///     grid.set(Entity::Row(5), Settings::new().indent(1, 1, 0, 0));
+    ///     assert_eq!(
+    ///         grid.validate(),
+    ///         Err(vec![
+    ///             ValidationError::EntityOutOfGrid(Entity::Row(5)),
+    ///             ValidationError::SpanOutOfGrid { row: 0, column: 1, span: 2 },
+    ///         ])
+    ///     );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+        let mut errors = Vec::new();
+
+        let is_in_grid = |entity: &Entity| match *entity {
+            Entity::Global => true,
+            Entity::Column(column) => column < count_columns,
+            Entity::Row(row) => row < count_rows,
+            Entity::Cell(row, column) => row < count_rows && column < count_columns,
+        };
+        let mut entities = (self.config.styles.keys())
+            .chain(self.config.colors.keys())
+            .chain(self.config.backgrounds.keys())
+            .chain(self.config.classes.keys())
+            .chain(self.config.texts_out_of_grid.iter())
+            .filter(|entity| !is_in_grid(entity))
+            .copied()
+            .collect::<Vec<_>>();
+        entities.sort_by_key(|entity| match *entity {
+            Entity::Global => (0, 0, 0),
+            Entity::Row(row) => (1, row, 0),
+            Entity::Column(column) => (2, column, 0),
+            Entity::Cell(row, column) => (3, row, column),
+        });
+        entities.dedup();
+        errors.extend(entities.into_iter().map(ValidationError::EntityOutOfGrid));
+
+        let mut min_widths = (self.config.min_widths.keys())
+            .filter(|&&column| column >= count_columns)
+            .copied()
+            .collect::<Vec<_>>();
+        min_widths.sort_unstable();
+        errors.extend(
+            min_widths
+                .into_iter()
+                .map(ValidationError::MinWidthOutOfGrid),
+        );
+
+        let mut min_heights = (self.config.min_heights.keys())
+            .filter(|&&row| row >= count_rows)
+            .copied()
+            .collect::<Vec<_>>();
+        min_heights.sort_unstable();
+        errors.extend(
+            min_heights
+                .into_iter()
+                .map(ValidationError::MinHeightOutOfGrid),
+        );
+
+        let mut covered = vec![vec![false; count_columns]; count_rows];
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let style = self.style(row, column);
+                if covered[row][column] {
+                    if style.span > 1 || style.row_span > 1 {
+                        errors.push(ValidationError::OverlappingSpans { row, column });
+                    }
+
+                    continue;
+                }
+
+                if style.span > count_columns - column {
+                    errors.push(ValidationError::SpanOutOfGrid {
+                        row,
+                        column,
+                        span: style.span,
+                    });
+                }
+
+                if style.row_span > count_rows - row {
+                    errors.push(ValidationError::RowSpanOutOfGrid {
+                        row,
+                        column,
+                        span: style.row_span,
+                    });
+                }
+
+                let span = cell_span(&style, column, count_columns);
+                let row_span = max(1, min(style.row_span, count_rows - row));
+                for covered in &mut covered[row..row + row_span] {
+                    covered[column..column + span]
+                        .iter_mut()
+                        .for_each(|cell| *cell = true);
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Render_to_file renders the grid into a file, which is created or truncated.
+    ///
+    /// The output is streamed through a buffer of a fixed size instead of being built in memory,
+    /// so it's suitable for exporting very big grids.
+    /// `progress` is called after each row with an amount of rendered rows and a total amount of rows.
+    pub fn render_to_file<P, F>(&self, path: P, progress: F) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+        F: FnMut(usize, usize),
+    {
+        let file = File::create(path)?;
+        self.render_to(BufWriter::new(file), progress)
+    }
+
+    /// Render_to renders the grid into a writer without building the output in memory.
+    ///
+    /// `progress` is called like in [Grid::render_to_file].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let grid = Grid::from_fn(2, 1, |row, _| row);
+    ///
+    ///     let mut output = Vec::new();
+    ///     let mut rendered = Vec::new();
+    ///     grid.render_to(&mut output, |row, total| rendered.push((row, total))).unwrap();
+    ///
+    ///     assert_eq!(output, grid.to_string().into_bytes());
+    ///     assert_eq!(rendered, [(1, 2), (2, 2)]);
+    /// ```
+    pub fn render_to<W, F>(&self, mut writer: W, progress: F) -> io::Result<()>
+    where
+        W: Write,
+        F: FnMut(usize, usize),
+    {
+        write!(writer, "{}", Render(self, RefCell::new(progress)))?;
+        writer.flush()
+    }
+
+    /// Render_lines renders the grid line by line without building the whole output in memory.
+    ///
+    /// Each line is written into `buffer` without a trailing `\n` and passed to `on_line`.
+    /// The buffer is cleared before each line, so the same buffer can be reused between renders
+    /// and only grows up to the longest line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let grid = Grid::from_fn(2, 2, |row, column| row * 2 + column);
+    ///
+    ///     let mut buffer = String::new();
+    ///     let mut lines = Vec::new();
+    ///     grid.render_lines(&mut buffer, |line| lines.push(line.to_owned()));
+    ///
+    ///     assert_eq!(lines, ["+-+-+", "|0|1|", "+-+-+", "|2|3|", "+-+-+"]);
+    /// ```
+    pub fn render_lines<F>(&self, buffer: &mut String, on_line: F)
+    where
+        F: FnMut(&str),
+    {
+        struct Lines<'a, F> {
+            buffer: &'a mut String,
+            on_line: F,
+        }
+
+        impl<F: FnMut(&str)> fmt::Write for Lines<'_, F> {
+            fn write_str(&mut self, mut text: &str) -> fmt::Result {
+                while let Some(end) = text.find('\n') {
+                    self.buffer.push_str(&text[..end]);
+                    (self.on_line)(self.buffer);
+                    self.buffer.clear();
+                    text = &text[end + 1..];
+                }
+
+                self.buffer.push_str(text);
+                Ok(())
+            }
+
+            // lines are mostly written by repeated chars, so they're not searched for `\n`
+            fn write_char(&mut self, c: char) -> fmt::Result {
+                if c == '\n' {
+                    (self.on_line)(self.buffer);
+                    self.buffer.clear();
+                } else {
+                    self.buffer.push(c);
+                }
+
+                Ok(())
+            }
+        }
+
+        buffer.clear();
+        let mut lines = Lines { buffer, on_line };
+        fmt::Write::write_fmt(
+            &mut lines,
+            format_args!("{}", Render(self, RefCell::new(|_, _| {}))),
+        )
+        .expect("rendering into a buffer doesn't fail");
+        lines.buffer.clear();
+    }
+
+    /// Lines returns an iterator over lines of the rendered grid without trailing `\n`.
+    ///
+    /// The grid is measured on a first call of `next` and then drawn row by row,
+    /// so rows which are not reached are never drawn, e.g. for a preview of a few lines.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let grid = Grid::from_fn(1000, 2, |row, column| row * 2 + column);
+    ///
+    ///     let head = grid.lines().take(3).collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(head, ["+----+----+", "|0   |1   |", "+----+----+"]);
+    /// ```
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        GridLines {
+            grid: self,
+            drawing: None,
+            row: 0,
+            pending: Vec::new().into_iter(),
+        }
+    }
+
+    /// Render_deterministic renders the grid in a normalized form which is stable across platforms,
+    /// so it can be compared with golden files.
+    ///
+    /// Trailing whitespace is removed from each line and each line ends with a single `\n`,
+    /// including lines which end by a `\r` of a content.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Border, Entity, Grid, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("a").indent(1, 1, 0, 0));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("b\r"));
+    ///     for row in 0..2 {
+    ///         grid.get_border_mut(row).empty().inner(Some(' '), None, None);
+    ///     }
+    ///
+    ///     assert_eq!(grid.render_deterministic(), " a   a\n a   b\n");
+    /// ```
+    pub fn render_deterministic(&self) -> String {
+        let mut output = String::new();
+        self.render_lines(&mut String::new(), |line| {
+            output.push_str(line.trim_end());
+            output.push('\n');
+        });
+
+        output
+    }
+
+    /// Build_cells returns contents of cells as they are rendered, but without borders.
+    ///
+    /// Each cell is aligned and padded to its final size, lines of a cell are joined by `\n`.
+    /// A spanned cell takes the size of all cells it covers (including split lines between rows),
+    /// and covered cells are empty, as well as cells which aren't rendered at all.
+    ///
+    /// It lets a content be painted by other means while reusing the sizing of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+    ///     let mut grid = Grid::from_fn(2, 2, |row, column| row * 10 + column);
+    ///     grid.set(Entity::Global, Settings::new().alignment(AlignmentHorizontal::Right));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("a\nb"));
+    ///
+    ///     assert_eq!(
+    ///         grid.build_cells(),
+    ///         [[" 0", "1"], ["10\n  ", "a\nb"]]
+    ///     );
+    /// ```
+    pub fn build_cells(&self) -> Vec<Vec<String>> {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+        let mut contents = vec![vec![String::new(); count_columns]; count_rows];
+        if count_rows == 0 || count_columns == 0 {
+            return contents;
+        }
+
+        let (measurement, layout, row_spans) = self.measure_layout();
+        let span_heights = span_heights(&row_spans, &self.split_lines(), &measurement.row_heights);
+        let is_covered = |row: usize, column: usize| {
+            row_spans
+                .iter()
+                .any(|&(r, c, span)| c == column && r < row && row < r + span)
+        };
+
+        for (row, cells) in layout.cells.iter().enumerate() {
+            for (i, (cell, style)) in cells.iter().enumerate() {
+                let column = layout.columns[row][i];
+                if is_covered(row, column) {
+                    continue;
+                }
+
+                let width = layout.widths[row][i];
+                let height = span_heights
+                    .get(&(row, column))
+                    .copied()
+                    .unwrap_or(measurement.row_heights[row]);
+                contents[row][column] = match &style.nested {
+                    Some(nested) => {
+                        let mut text = stretch_grid(nested, width, height);
+                        text.pop();
+                        text
+                    }
+                    None => render_cell(cell, style, width, height).join("\n"),
+                };
+            }
+        }
+
+        contents
+    }
+
+    fn fmt_measured(&self, f: &mut fmt::Formatter<'_>, measurement: &Measurement) -> fmt::Result {
+        self.fmt_with_progress(f, measurement, &mut |_, _| {})
+    }
+
+    // progress is called with an amount of rendered rows and a total amount of rows after each row
+    fn fmt_with_progress(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        measurement: &Measurement,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> fmt::Result {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+
+        // It may happen when all cells removed via `remove_row`, `remove_column` methods
+        if count_rows == 0 || count_columns == 0 {
+            return Ok(());
+        }
+
+        assert!(
+            measurement.column_widths.len() == count_columns
+                && measurement.row_heights.len() == count_rows,
+            "a measurement was made for a grid of a different size"
+        );
+
+        let cells = self.styled_cells(count_rows, count_columns);
+        let row_spans = self.row_spans(&cells);
+        let layout = self.layout(cells, &measurement.column_widths);
+        self.fmt_layout(f, measurement, layout, &row_spans, progress)
+    }
+
+    fn fmt_layout(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        measurement: &Measurement,
+        layout: Layout<'_>,
+        row_spans: &[(usize, usize, usize)],
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> fmt::Result {
+        if !self.config.trim_trailing_whitespace {
+            return self.draw_layout(f, measurement, layout, row_spans, progress);
+        }
+
+        // lines are drawn through a writer which drops whitespace before each `\n`
+        let draw = Once::new(|f: &mut fmt::Formatter<'_>| {
+            self.draw_layout(f, measurement, layout, row_spans, progress)
+        });
+        let mut writer = TrimLines {
+            inner: f,
+            pending: String::new(),
+        };
+        fmt::Write::write_fmt(&mut writer, format_args!("{}", draw))
+    }
+
+    fn draw_layout(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        measurement: &Measurement,
+        layout: Layout<'_>,
+        row_spans: &[(usize, usize, usize)],
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> fmt::Result {
+        let count_rows = self.count_rows();
+        let mut drawing = self.prepare_drawing(measurement, layout, row_spans);
+        for row in 0..count_rows {
+            self.draw_row(f, measurement, &mut drawing, row)?;
+            progress(row + 1, count_rows);
+        }
+
+        Ok(())
+    }
+
+    // cells which can't be drawn line by line are rendered in advance
+    fn prepare_drawing<'c>(
+        &self,
+        measurement: &Measurement,
+        layout: Layout<'c>,
+        row_spans: &[(usize, usize, usize)],
+    ) -> Drawing<'c> {
+        let split_lines = self.split_lines();
+        let row_heights = &measurement.row_heights;
+        let Layout {
+            mut cells,
+            columns,
+            widths,
+            ragged_length,
+            ..
+        } = layout;
+
+        let span_heights = span_heights(row_spans, &split_lines, row_heights);
+
+        // nested grids are rendered in advance as their size is known only now
+        let nested = cells
+            .iter()
+            .enumerate()
+            .flat_map(|(row, cells)| {
+                cells.iter().enumerate().filter_map(move |(i, (_, style))| {
+                    style.nested.as_ref().map(|nested| (row, i, nested))
+                })
+            })
+            .map(|(row, i, nested)| {
+                let height = span_heights
+                    .get(&(row, columns[row][i]))
+                    .copied()
+                    .unwrap_or(row_heights[row]);
+                let text = stretch_grid(nested, widths[row][i], height);
+                (row, i, text)
+            })
+            .collect::<Vec<_>>();
+        for (row, i, text) in nested {
+            let (cell, style) = &mut cells[row][i];
+            *cell = text
+                .lines()
+                .map(|line| Cow::Owned(line.to_owned()))
+                .collect();
+            style.nested = None;
+            style.raw = true;
+        }
+
+        // a cell which spans over several rows is rendered in advance and cut into pieces
+        let mut spanned_lines = HashMap::new();
+        let mut pieces = Vec::new();
+        for &(row, column, span) in row_spans {
+            let i = match columns[row].iter().position(|&c| c == column) {
+                Some(i) => i,
+                None => continue,
+            };
+
+            let (cell, style) = &cells[row][i];
+            let height = span_heights[&(row, column)];
+            let mut lines = render_cell(cell, style, widths[row][i], height).into_iter();
+            for covered in row..row + span {
+                let piece = lines
+                    .by_ref()
+                    .take(row_heights[covered])
+                    .collect::<Vec<_>>();
+                if let Some(i) = columns[covered].iter().position(|&c| c == column) {
+                    pieces.push((covered, i, piece));
+                }
+
+                if covered + 1 < row + span && split_lines[covered] {
+                    if let Some(line) = lines.next() {
+                        spanned_lines.insert((covered + 1, column), line);
+                    }
+                }
+            }
+        }
+        for (row, i, piece) in pieces {
+            let (cell, style) = &mut cells[row][i];
+            *cell = piece.into_iter().map(Cow::Owned).collect();
+            *style = Style {
+                span: style.span,
+                raw: true,
+                ..Style::default()
+            };
+        }
+
+        Drawing {
+            cells,
+            columns,
+            widths,
+            ragged_length,
+            spanned_lines,
+        }
+    }
+
+    // returns a style of all cells when a grid can be drawn without building a layout,
+    // which is the case when there are no spans, multiline contents and settings of particular cells
+    fn simple_style(&self) -> Option<Style> {
+        let config = &self.config;
+        let is_simple = config.styles.len() == 1
+            && config.colors.is_empty()
+            && config.backgrounds.is_empty()
+            && config.last_row_length.is_none()
+            && config.min_widths.is_empty()
+            && config.min_heights.is_empty()
+            && !config.trim_trailing_whitespace;
+        if !is_simple {
+            return None;
+        }
+
+        let style = self.style(0, 0);
+        if style.span != 1 || style.row_span != 1 || style.raw || style.nested.is_some() {
+            return None;
+        }
+
+        let is_single_line = self
+            .records
+            .cells
+            .iter()
+            .flatten()
+            .all(|text| !text.contains(['\n', '\r', '\t']));
+        if !is_single_line {
+            return None;
+        }
+
+        Some(style)
+    }
+
+    // draws a grid which was checked by `simple_style`,
+    // contents are measured and drawn in place as all cells share the same style
+    fn fmt_simple(&self, f: &mut fmt::Formatter<'_>, style: &Style) -> fmt::Result {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+        let cells = &self.records.cells;
+
+        let mut widths = vec![0; count_columns];
+        let mut heights = vec![1; count_rows];
+        for (row, texts) in cells.iter().enumerate() {
+            for (column, text) in texts.iter().enumerate() {
+                let (width, height) = self.widths.cell_size(simple_cell(text), style);
+                widths[column] = max(widths[column], width);
+                heights[row] = max(heights[row], height);
+            }
+        }
+
+        let columns = (0..count_columns).collect::<Vec<_>>();
+        let contents = HashMap::new();
+        for (row, &height) in heights.iter().enumerate() {
+            let border = &self.config.border_styles[row];
+            let color = border.color.as_ref();
+
+            if row == 0 {
+                let line = SplitLine {
+                    index: row,
+                    columns: &columns,
+                    count_columns,
+                    contents: &contents,
+                    above: None,
+                    below: Some((&columns, count_columns)),
+                };
+                build_split_line(
+                    f,
+                    &widths,
+                    &border.top_line,
+                    &line,
+                    &self.junctions(row),
+                    &self.config.overrides,
+                    color,
+                )?;
+            }
+
+            let separators = separators(
+                &border.inner,
+                &self.config.overrides.vertical,
+                row,
+                &columns,
+                count_columns,
+            );
+            for index in 0..height {
+                build_line(f, count_columns, &separators, color, |f, column| {
+                    let cell = simple_cell(&cells[row][column]);
+                    cell_line(f, cell, style, widths[column], height, index)
+                })?;
+            }
+
+            let line = SplitLine {
+                index: row + 1,
+                columns: &columns,
+                count_columns,
+                contents: &contents,
+                above: Some((&columns, count_columns)),
+                below: (row + 1 < count_rows).then_some((columns.as_slice(), count_columns)),
+            };
+            build_split_line(
+                f,
+                &widths,
+                &border.bottom_line,
+                &line,
+                &self.junctions(row),
+                &self.config.overrides,
+                color,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // a row is drawn together with a split line below it and a top line of the grid
+    fn draw_row(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        measurement: &Measurement,
+        drawing: &mut Drawing<'_>,
+        row_index: usize,
+    ) -> fmt::Result {
+        let count_columns = self.count_columns();
+        let row_heights = &measurement.row_heights;
+        let Drawing {
+            cells,
+            columns,
+            widths,
+            ragged_length,
+            spanned_lines,
+        } = drawing;
+        let ragged_length = *ragged_length;
+
+        let last_row = self.count_rows() - 1;
+        let row_length = |row| match ragged_length {
+            Some(length) if row == last_row => length,
+            _ => count_columns,
+        };
+
+        let row = std::mem::take(&mut cells[row_index]);
+        let border = self
+            .config
+            .border_styles
+            .get(row_index)
+            .expect("it's expected that grid has N styles where N is an amount of rows");
+        let count_columns = row_length(row_index);
+
+        if row_index == 0 {
+            let line = SplitLine {
+                index: row_index,
+                columns: &columns[row_index],
+                count_columns,
+                contents: spanned_lines,
+                above: None,
+                below: Some((&columns[row_index], count_columns)),
+            };
+            build_split_line(
+                f,
+                &measurement.column_widths,
+                &border.top_line,
+                &line,
+                &self.junctions(row_index),
+                &self.config.overrides,
+                border.color.as_ref(),
+            )?;
+        }
+
+        let separators = separators(
+            &border.inner,
+            &self.config.overrides.vertical,
+            row_index,
+            &columns[row_index],
+            count_columns,
+        );
+        build_row(
+            f,
+            row,
+            &widths[row_index],
+            row_heights[row_index],
+            &separators,
+            border.color.as_ref(),
+        )?;
+
+        let line = SplitLine {
+            index: row_index + 1,
+            columns: &columns[row_index],
+            count_columns,
+            contents: spanned_lines,
+            above: Some((&columns[row_index], count_columns)),
+            below: columns
+                .get(row_index + 1)
+                .map(|below| (below.as_slice(), row_length(row_index + 1))),
+        };
+
+        match ragged_length {
+            Some(length) if row_index + 1 == last_row => {
+                let bottom = &self.config.border_styles[last_row].bottom_line;
+                build_ragged_split_line(
+                    f,
+                    &widths[row_index],
+                    (&border.bottom_line, bottom, &border.inner),
+                    &line,
+                    length,
+                    &self.config.overrides,
+                    border.color.as_ref(),
+                )
+            }
+            _ => build_split_line(
+                f,
+                &measurement.column_widths,
+                &border.bottom_line,
+                &line,
+                &self.junctions(row_index),
+                &self.config.overrides,
+                border.color.as_ref(),
+            ),
+        }
+    }
+}
+
+// GridLines draws a grid row by row when its lines are requested
+struct GridLines<'a, 'b> {
+    grid: &'a Grid<'b>,
+    drawing: Option<(Measurement, Drawing<'a>)>,
+    row: usize,
+    pending: std::vec::IntoIter<String>,
+}
+
+impl Iterator for GridLines<'_, '_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            if let Some(line) = self.pending.next() {
+                return Some(line);
+            }
+
+            let grid = self.grid;
+            if self.row == grid.count_rows() || grid.count_columns() == 0 {
+                return None;
+            }
+
+            let (measurement, drawing) = self.drawing.get_or_insert_with(|| {
+                let (measurement, layout, row_spans) = grid.measure_layout();
+                let drawing = grid.prepare_drawing(&measurement, layout, &row_spans);
+                (measurement, drawing)
+            });
+
+            let row = self.row;
+            let text =
+                Once::new(|f: &mut fmt::Formatter<'_>| grid.draw_row(f, measurement, drawing, row))
+                    .to_string();
+            self.row += 1;
+
+            let trim = grid.config.trim_trailing_whitespace;
+            self.pending = text
+                .split_terminator('\n')
+                .map(|line| if trim { line.trim_end() } else { line })
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+                .into_iter();
+        }
+    }
+}
+
+// Drawing holds a layout which is ready to be drawn row by row
+struct Drawing<'a> {
+    cells: Vec<Vec<StyledCell<'a>>>,
+    columns: Vec<Vec<usize>>,
+    widths: Vec<Vec<usize>>,
+    ragged_length: Option<usize>,
+    // lines of cells which span over split lines
+    spanned_lines: HashMap<(usize, usize), String>,
+}
+
+// returns lines of a single line content the same way as they're split for a layout
+fn simple_cell<'a, 'b>(text: &'a Cow<'b, str>) -> &'a [Cow<'b, str>] {
+    if text.is_empty() {
+        &[]
+    } else {
+        std::slice::from_ref(text)
+    }
+}
+
+// StyledCell holds lines of a cell content and a style of the cell
+type StyledCell<'a> = (Vec<Cow<'a, str>>, Style);
+
+// Layout holds visible cells and their widths
+struct Layout<'a> {
+    cells: Vec<Vec<StyledCell<'a>>>,
+    // indexes of visible columns in each row
+    columns: Vec<Vec<usize>>,
+    widths: Vec<Vec<usize>>,
+    count_columns: usize,
+    ragged_length: Option<usize>,
+}
+
+impl Layout<'_> {
+    fn row_length(&self, row: usize) -> usize {
+        match self.ragged_length {
+            Some(length) if row + 1 == self.cells.len() => length,
+            _ => self.count_columns,
+        }
+    }
+}
+
+fn build_row(
+    f: &mut std::fmt::Formatter<'_>,
+    row: Vec<StyledCell<'_>>,
+    widths: &[usize],
+    height: usize,
+    separators: &[Option<char>],
+    color: Option<&Color>,
+) -> fmt::Result {
+    for index in 0..height {
+        build_line(f, row.len(), separators, color, |f, column| {
+            let (cell, style) = &row[column];
+            cell_line(f, cell, style, widths[column], height, index)
+        })?;
+    }
+
+    Ok(())
+}
+
+// writes a line of a cell of a given size
+fn cell_line(
+    f: &mut std::fmt::Formatter<'_>,
+    cell: &[Cow<'_, str>],
+    style: &Style,
+    width: usize,
+    height: usize,
+    index: usize,
+) -> fmt::Result {
+    if let Some(background) = &style.background {
+        // a content color resets the background by its suffix, so it's set again after it
+        let color = style.color.as_ref().map(|color| Color {
+            prefix: color.prefix.clone(),
+            suffix: color.suffix.clone() + &background.prefix,
+        });
+        let style = Style {
+            color,
+            background: None,
+            ..style.clone()
+        };
+
+        f.write_str(&background.prefix)?;
+        cell_line(f, cell, &style, width, height, index)?;
+        return f.write_str(&background.suffix);
+    }
+
+    let top_indent = top_indent(cell, style, height);
+    if top_indent > index {
+        return empty_line(f, width);
+    }
+
+    match cell.get(index - top_indent) {
+        // the last line of a justified paragraph is not stretched
+        Some(text) if cell.len() > 1 && index - top_indent + 1 == cell.len() => {
+            line(f, text, width, style, true)
+        }
+        Some(text) => line(f, text, width, style, false),
+        None => empty_line(f, width),
+    }
+}
+
+// a cell which spans over several rows takes split lines between them
+fn span_heights(
+    row_spans: &[(usize, usize, usize)],
+    split_lines: &[bool],
+    row_heights: &[usize],
+) -> HashMap<(usize, usize), usize> {
+    row_spans
+        .iter()
+        .map(|&(row, column, span)| {
+            let lines = split_lines[row..row + span - 1]
+                .iter()
+                .filter(|&&line| line)
+                .count();
+            let height = row_heights[row..row + span].iter().sum::<usize>() + lines;
+            ((row, column), height)
+        })
+        .collect()
+}
+
+// renders lines of a cell of a given size
+fn render_cell(cell: &[Cow<'_, str>], style: &Style, width: usize, height: usize) -> Vec<String> {
+    struct CellLine<'a>(&'a [Cow<'a, str>], &'a Style, usize, usize, usize);
+
+    impl Display for CellLine<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+            cell_line(f, self.0, self.1, self.2, self.3, self.4)
+        }
+    }
+
+    (0..height)
+        .map(|index| CellLine(cell, style, width, height, index).to_string())
+        .collect()
+}
+
+// renders a grid so it takes exactly a given width and height
+fn stretch_grid(grid: &Grid<'static>, width: usize, height: usize) -> String {
+    let count_rows = grid.count_rows();
+    let count_columns = grid.count_columns();
+    if count_rows == 0 || count_columns == 0 {
+        return String::new();
+    }
+
+    let measurement = grid.measure();
+    let mut grid = grid.clone();
+    let last_column = count_columns - 1;
+    let last_row = count_rows - 1;
+    grid.set_min_column_width(
+        last_column,
+        measurement.column_widths[last_column] + width.saturating_sub(measurement.width()),
+    );
+    grid.set_min_row_height(
+        last_row,
+        measurement.row_heights[last_row] + height.saturating_sub(measurement.height()),
+    );
+
+    grid.to_string()
+}
+
+fn top_indent(cell: &[Cow<'_, str>], style: &Style, height: usize) -> usize {
+    let height = height - style.indent.top;
+    let content_height = cell_height(cell, style) - style.indent.top - style.indent.bottom;
+    let indent = style
+        .alignment_v
+        .top_ident(height, content_height, style.remainder);
+    indent + style.indent.top
+}
+
+fn empty_line(f: &mut std::fmt::Formatter<'_>, n: usize) -> fmt::Result {
+    write!(f, "{:1$}", "", n)
+}
+
+fn repeat_char(f: &mut std::fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
+    Repeat(c, n).fmt(f)
+}
+
+fn line(
+    f: &mut std::fmt::Formatter<'_>,
+    text: &str,
+    width: usize,
+    style: &Style,
+    is_last: bool,
+) -> fmt::Result {
+    if style.raw {
+        f.write_str(text)?;
+        return repeat_char(f, ' ', width.saturating_sub(string_width(text)));
+    }
+
+    let alignment = match style.alignment_h {
+        AlignmentHorizontal::Justify if is_last => AlignmentHorizontal::Left,
+        alignment => alignment,
+    };
+
+    let left_indent = style.indent.left;
+    let right_indent = style.indent.right;
+    match style.indent_position {
+        IndentPosition::Outside => {
+            repeat_char(f, ' ', left_indent)?;
+            alignment.align(
+                f,
+                text,
+                width - left_indent - right_indent,
+                style.color.as_ref(),
+                style.remainder,
+                style.fill,
+            )?;
+            repeat_char(f, ' ', right_indent)?;
+        }
+        IndentPosition::Inside => {
+            // a content is kept as it is, only an indent is put between it and a border
+            alignment.align_with_indent(f, text, width, style)?;
+        }
+    }
+
+    Ok(())
+}
+
+// separators must contain `length + 1` characters: a left border, intersections and a right border
+fn build_line<F: Fn(&mut std::fmt::Formatter<'_>, usize) -> fmt::Result>(
+    f: &mut std::fmt::Formatter<'_>,
+    length: usize,
+    separators: &[Option<char>],
+    color: Option<&Color>,
+    writer: F,
+) -> fmt::Result {
+    for (i, separator) in separators[..length].iter().enumerate() {
+        write_option(f, separator.map(|c| Colored(c, color)))?;
+        writer(f, i)?;
+    }
+
+    write_option(f, separators[length].map(|c| Colored(c, color)))?;
+
+    writeln!(f)?;
+
+    Ok(())
+}
+
+// builds a list of separators of a line taking overrides into account,
+// `columns` are indexes of visible cells
+fn separators(
+    border: &LineStyle,
+    overrides: &HashMap<(usize, usize), char>,
+    index: usize,
+    columns: &[usize],
+    count_columns: usize,
+) -> Vec<Option<char>> {
+    let get = |c: Option<char>, separator: usize| {
+        c.map(|c| overrides.get(&(index, separator)).copied().unwrap_or(c))
+    };
+
+    let mut separators = Vec::with_capacity(columns.len() + 1);
+    separators.push(get(border.left_intersection, 0));
+    separators.extend(
+        columns
+            .iter()
+            .skip(1)
+            .map(|&column| get(border.intersection, column)),
+    );
+    separators.push(get(border.right_intersection, count_columns));
+
+    separators
+}
+
+struct SplitLine<'a> {
+    index: usize,
+    columns: &'a [usize],
+    count_columns: usize,
+    // lines of cells which span over the split line by their columns
+    contents: &'a HashMap<(usize, usize), String>,
+    // visible columns and lengths of rows above and below the line
+    above: Option<(&'a [usize], usize)>,
+    below: Option<(&'a [usize], usize)>,
+}
+
+impl SplitLine<'_> {
+    fn content(&self, i: usize) -> Option<&str> {
+        self.contents
+            .get(&(self.index, self.columns[i]))
+            .map(String::as_str)
+    }
+
+    // whether a vertical line of a row above or below comes to a given separator
+    fn is_crossed(row: Option<(&[usize], usize)>, separator: usize) -> bool {
+        matches!(row, Some((columns, length))
+            if separator == 0 || separator == length || columns.contains(&separator))
+    }
+}
+
+// Junctions holds lines which characters of junctions are taken from,
+// so a junction of a split line is drawn by what meets there
+struct Junctions<'a> {
+    // a line where only lines below meet like `┬`
+    top: &'a LineStyle,
+    // a line where only lines above meet like `┴`
+    bottom: &'a LineStyle,
+    // vertical lines which go through a split line
+    vertical: &'a LineStyle,
+}
+
+impl Junctions<'_> {
+    // returns a character for a junction by lines which meet in it
+    fn resolve(
+        &self,
+        line: &LineStyle,
+        separator: usize,
+        count_columns: usize,
+        (up, down, left, right): (bool, bool, bool, bool),
+    ) -> Option<char> {
+        let pick = |style: &LineStyle| match (left, right) {
+            (true, true) => style.intersection,
+            (false, true) => style.left_intersection,
+            _ => style.right_intersection,
+        };
+
+        if !left && !right {
+            return match separator {
+                0 => self.vertical.left_intersection,
+                _ if separator == count_columns => self.vertical.right_intersection,
+                _ => self.vertical.intersection,
+            };
+        }
+
+        match (up, down) {
+            (true, true) => pick(line),
+            (false, true) => pick(self.top).or_else(|| pick(line)),
+            (true, false) => pick(self.bottom).or_else(|| pick(line)),
+            (false, false) => line.main,
+        }
+    }
+}
+
+// a split line is built by columns, so lines of rows above and below which don't cross the line
+// still get a junction
+fn build_split_line(
+    f: &mut std::fmt::Formatter<'_>,
+    column_widths: &[usize],
+    border: &LineStyle,
+    line: &SplitLine<'_>,
+    junctions: &Junctions<'_>,
+    overrides: &BorderOverrides,
+    color: Option<&Color>,
+) -> fmt::Result {
+    if border.is_empty() {
+        return Ok(());
+    }
+
+    let separators = separators(
+        border,
+        &overrides.intersection,
+        line.index,
+        line.columns,
+        line.count_columns,
+    );
+    let count = line.columns.len();
+    let junction = |i: usize| {
+        let separator = line.columns.get(i).copied().unwrap_or(line.count_columns);
+        let c = separators[i]?;
+        if overrides
+            .intersection
+            .contains_key(&(line.index, separator))
+        {
+            return Some(c);
+        }
+
+        let lines = (
+            SplitLine::is_crossed(line.above, separator),
+            SplitLine::is_crossed(line.below, separator),
+            i > 0 && line.content(i - 1).is_none(),
+            i < count && line.content(i).is_none(),
+        );
+        junctions
+            .resolve(border, separator, line.count_columns, lines)
+            .or(Some(c))
+    };
+
+    for i in 0..count {
+        write_option(f, junction(i).map(|c| Colored(c, color)))?;
+
+        if let Some(content) = line.content(i) {
+            f.write_str(content)?;
+            continue;
+        }
+
+        let start = line.columns[i];
+        let end = line
+            .columns
+            .get(i + 1)
+            .copied()
+            .unwrap_or(line.count_columns);
+        let main = border.main.map(|main| {
+            overrides
+                .horizontal
+                .get(&(line.index, start))
+                .copied()
+                .unwrap_or(main)
+        });
+        for (column, width) in column_widths.iter().enumerate().take(end).skip(start) {
+            // a place of a separator inside of a span is crossed only by lines below
+            if column > start {
+                let c = match main {
+                    Some(_) if SplitLine::is_crossed(line.below, column) => junctions
+                        .resolve(
+                            border,
+                            column,
+                            line.count_columns,
+                            (false, true, true, true),
+                        )
+                        .or(main),
+                    _ => main,
+                };
+                write_option(f, c.map(|c| Colored(c, color)))?;
+            }
+
+            write_option(f, main.map(|m| Colored(Repeat(m, *width), color)))?;
+        }
+    }
+
+    write_option(f, junction(count).map(|c| Colored(c, color)))?;
+    writeln!(f)
+}
+
+// returns a new position of each index,
+// panics if `order` is not a permutation of `count` indexes
+// copies settings of a row and its cells to given rows
+fn copy_row_entities<T: Clone>(
+    map: &mut HashMap<Entity, T>,
+    row: usize,
+    rows: std::ops::Range<usize>,
+) {
+    let copies = map
+        .iter()
+        .flat_map(|(&entity, value)| {
+            rows.clone().filter_map(move |to| match entity {
+                Entity::Row(r) if r == row => Some((Entity::Row(to), value.clone())),
+                Entity::Cell(r, column) if r == row => {
+                    Some((Entity::Cell(to, column), value.clone()))
+                }
+                _ => None,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    map.extend(copies);
+}
+
+// moves keys of a map by their first or second index, keys moved to `None` are dropped
+fn move_keys<V>(
+    map: &mut HashMap<(usize, usize), V>,
+    shift: &dyn Fn(usize) -> Option<usize>,
+    by_first: bool,
+) {
+    *map = map
+        .drain()
+        .filter_map(|((a, b), value)| {
+            let key = if by_first {
+                shift(a).map(|a| (a, b))
+            } else {
+                shift(b).map(|b| (a, b))
+            };
+            key.map(|key| (key, value))
+        })
+        .collect();
+}
+
+fn permutation_positions(order: &[usize], count: usize) -> Option<Vec<usize>> {
+    if order.len() != count {
+        return None;
+    }
+
+    let mut positions = vec![None; count];
+    for (position, &index) in order.iter().enumerate() {
+        if index >= count || positions[index].is_some() {
+            return None;
+        }
+
+        positions[index] = Some(position);
+    }
+
+    positions.into_iter().collect()
+}
+
+// builds a line between a row and a ragged last row,
+// a part of the line above missing cells of the last row is drawn as a bottom border,
+// `lines` are a split line, a bottom line and an inner line of the row above
+fn build_ragged_split_line(
+    f: &mut std::fmt::Formatter<'_>,
+    widths: &[usize],
+    lines: (&LineStyle, &LineStyle, &LineStyle),
+    line: &SplitLine<'_>,
+    length: usize,
+    overrides: &BorderOverrides,
+    color: Option<&Color>,
+) -> fmt::Result {
+    let (split, bottom, inner) = lines;
+    if split.is_empty() && bottom.is_empty() {
+        return Ok(());
+    }
+
+    // when there's no split line the row above is closed by its vertical lines
+    let head = if split.is_empty() { inner } else { split };
+    let head_separators = separators(
+        head,
+        &overrides.intersection,
+        line.index,
+        line.columns,
+        line.count_columns,
+    );
+    let tail_separators = separators(
+        bottom,
+        &overrides.intersection,
+        line.index,
+        line.columns,
+        line.count_columns,
+    );
+
+    let separators = head_separators
+        .into_iter()
+        .zip(tail_separators)
+        .enumerate()
+        .map(|(i, (head, tail))| {
+            let column = line.columns.get(i).copied().unwrap_or(line.count_columns);
+            if column > length {
+                tail
+            } else {
+                head
+            }
+        })
+        .collect::<Vec<_>>();
+
+    build_line(f, widths.len(), &separators, color, |f, i| {
+        if let Some(content) = line.content(i) {
+            return f.write_str(content);
+        }
+
+        let column = line.columns[i];
+        if column >= length {
+            let main = bottom.main.map(|main| {
+                overrides
+                    .horizontal
+                    .get(&(line.index, column))
+                    .copied()
+                    .unwrap_or(main)
+            });
+
+            return write_option(f, main.map(|m| Colored(Repeat(m, widths[i]), color)));
+        }
+
+        match head.main {
+            Some(main) => {
+                let main = overrides
+                    .horizontal
+                    .get(&(line.index, column))
+                    .copied()
+                    .unwrap_or(main);
+                write!(f, "{}", Colored(Repeat(main, widths[i]), color))
+            }
+            None => empty_line(f, widths[i]),
+        }
+    })
+}
+
+fn write_option<D: Display>(f: &mut std::fmt::Formatter<'_>, text: Option<D>) -> fmt::Result {
+    match text {
+        Some(text) => write!(f, "{}", text),
+        None => Ok(()),
+    }
+}
+
+#[cfg(not(feature = "color"))]
+fn string_width(text: &str) -> usize {
+    real_string_width(text)
+}
+
+#[cfg(feature = "color")]
+fn string_width(text: &str) -> usize {
+    let b = strip_ansi_escapes::strip(text.as_bytes()).unwrap();
+    let s = std::str::from_utf8(&b).unwrap();
+    real_string_width(s)
+}
+
+fn real_string_width(text: &str) -> usize {
+    text.lines()
+        .map(unicode_width::UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0)
+}
+
+// a width of a column is shared by all rows,
+// so a spanned cell can't shift columns of other rows
+fn columns_width(
+    cells: &[Vec<StyledCell<'_>>],
+    sizes: &[Vec<(usize, usize)>],
+    min_widths: &HashMap<usize, usize>,
+    options: &LayoutOptions,
+    count_rows: usize,
+    count_columns: usize,
+) -> Vec<usize> {
+    let mut columns = (0..count_columns)
+        .map(|column| min_widths.get(&column).copied().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let mut spanned = Vec::new();
+    (0..count_rows).for_each(|row| {
+        (0..count_columns)
+            .filter(|&column| is_cell_visible(&cells[row], column))
+            .for_each(|column| {
+                let style = &cells[row][column].1;
+                let width = sizes[row][column].0;
+                let span = cell_span(style, column, count_columns);
+                if span > 1 {
+                    spanned.push((span, column, width));
+                } else {
+                    columns[column] = max(columns[column], width);
+                }
+            });
+    });
+
+    // narrow spans are handled first so wide ones take their expansion into account
+    spanned.sort_by_key(|&(span, _, _)| span);
+    for (span, column, width) in spanned {
+        expand_columns(&mut columns[column..column + span], width, options);
+    }
+
+    columns
+}
+
+// returns widths of visible cells and removes not visible ones
+fn cells_width(
+    cells: &mut [Vec<StyledCell<'_>>],
+    columns: &[usize],
+    count_rows: usize,
+    count_columns: usize,
+) -> Vec<Vec<usize>> {
+    let mut widths = (0..count_rows)
+        .map(|row| {
+            (0..count_columns)
+                .map(|column| {
+                    if !is_cell_visible(&cells[row], column) {
+                        return 0;
+                    }
+
+                    let span = cell_span(&cells[row][column].1, column, count_columns);
+                    spanned_width(&columns[column..column + span])
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // remove not visible cells to print everything correctly
+    (0..count_rows).for_each(|row| {
+        let mut n_removed = 0;
+        (0..count_columns)
+            .filter(|&column| !is_cell_visible(&cells[row], column))
+            .collect::<Vec<_>>() // it's here becouse of borrow rules...
+            .into_iter()
+            .for_each(|column| {
+                widths[row].remove(column - n_removed);
+                cells[row].remove(column - n_removed);
+                n_removed += 1;
+            });
+    });
+
+    widths
+}
+
+// a span can't go beyond the last column
+fn cell_span(style: &Style, column: usize, count_columns: usize) -> usize {
+    max(1, min(style.span, count_columns - column))
+}
+
+// a width of spanned columns including separators between them
+fn spanned_width(columns: &[usize]) -> usize {
+    columns.iter().sum::<usize>() + columns.len() - 1
+}
+
+// expands columns until they fit a given width
+fn expand_columns(columns: &mut [usize], width: usize, options: &LayoutOptions) {
+    let current = match options.span_separators {
+        SeparatorOwnership::Span => spanned_width(columns),
+        SeparatorOwnership::Columns => columns.iter().sum(),
+    };
+    if width <= current {
+        return;
+    }
+
+    spread(columns, width - current, options.span_spread);
+}
+
+// gives an extra size to columns or rows
+fn spread(sizes: &mut [usize], extra: usize, spread: Spread) {
+    let count = sizes.len();
+    match spread {
+        Spread::Leading => (0..extra)
+            .zip((0..count).cycle())
+            .for_each(|(_, i)| sizes[i] += 1),
+        Spread::Trailing => (0..extra)
+            .zip((0..count).rev().cycle())
+            .for_each(|(_, i)| sizes[i] += 1),
+        Spread::First => sizes[0] += extra,
+        Spread::Last => sizes[count - 1] += extra,
+        Spread::Proportional => spread_proportionally(sizes, extra),
+    }
+}
+
+// largest remainder allocation, so a result doesn't depend on an order of iterations
+fn spread_proportionally(sizes: &mut [usize], extra: usize) {
+    let total = sizes.iter().sum::<usize>();
+    if total == 0 {
+        return spread(sizes, extra, Spread::Leading);
+    }
+
+    let mut remainders = Vec::with_capacity(sizes.len());
+    let mut left = extra;
+    for (i, size) in sizes.iter_mut().enumerate() {
+        let share = *size * extra;
+        *size += share / total;
+        left -= share / total;
+        remainders.push((share % total, i));
+    }
+
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for &(_, i) in remainders.iter().take(left) {
+        sizes[i] += 1;
+    }
+}
+
+fn is_cell_visible(row: &[StyledCell<'_>], column: usize) -> bool {
+    !row[..column]
+        .iter()
+        .enumerate()
+        .any(|(i, (_, style))| i + style.span > column)
+}
+
+// replaces tabs by spaces up to a next tab stop, a width of 0 removes tabs
+fn expand_tabs(line: &str, tab_width: usize) -> Cow<'_, str> {
+    if !line.contains('\t') {
+        return Cow::Borrowed(line);
+    }
+
+    let mut expanded = String::with_capacity(line.len() + tab_width);
+    let mut width = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            if tab_width > 0 {
+                let spaces = tab_width - width % tab_width;
+                expanded.extend(std::iter::repeat_n(' ', spaces));
+                width += spaces;
+            }
+        } else {
+            expanded.push(c);
+            width += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        }
+    }
+
+    Cow::Owned(expanded)
+}
+
+// returns a width and a height of each cell
+#[cfg(not(feature = "parallel"))]
+fn cells_size(cells: &[Vec<StyledCell<'_>>]) -> Vec<Vec<(usize, usize)>> {
+    cells.iter().map(|row| row_size(row)).collect()
+}
+
+// returns a width and a height of each cell, rows are measured in parallel
+#[cfg(feature = "parallel")]
+fn cells_size(cells: &[Vec<StyledCell<'_>>]) -> Vec<Vec<(usize, usize)>> {
+    use rayon::prelude::*;
+
+    cells.par_iter().map(|row| row_size(row)).collect()
+}
+
+fn row_size(row: &[StyledCell<'_>]) -> Vec<(usize, usize)> {
+    row.iter()
+        .map(|(cell, style)| (cell_width(cell, style), cell_height(cell, style)))
+        .collect()
+}
+
+fn cell_width(cell: &[Cow<'_, str>], style: &Style) -> usize {
+    if let Some(nested) = &style.nested {
+        return nested.measure().width();
+    }
+
+    let content_width = cell.iter().map(|l| string_width(l)).max().unwrap_or(0);
+    content_width + style.indent.left + style.indent.right
+}
+
+fn rows_height(
+    cells: &[Vec<StyledCell<'_>>],
+    sizes: &[Vec<(usize, usize)>],
+    row_spans: &[(usize, usize, usize)],
+    split_lines: &[bool],
+    min_heights: &HashMap<usize, usize>,
+    options: &LayoutOptions,
+    count_rows: usize,
+) -> Vec<usize> {
+    // default height is 1 as we consider empty string has height 1
+    //
+    // it's crusial since if the default height will be equal to 0
+    // cell line will be not present on the grid like this
+    //
+    //  default 0      default 1
+    //    +++            +++
+    //    +++            |||
+    //    +++            +++
+    //                   |||
+    //                   +++
+    let mut row_heights = (0..count_rows)
+        .map(|row| max(1, min_heights.get(&row).copied().unwrap_or(0)))
+        .collect::<Vec<_>>();
+    (0..count_rows).for_each(|row_index| {
+        (0..cells[row_index].len()).for_each(|column_index| {
+            if cells[row_index][column_index].1.row_span < 2 {
+                let height = sizes[row_index][column_index].1;
+                row_heights[row_index] = max(row_heights[row_index], height);
+            }
+        });
+    });
+
+    // like columns rows are expanded by narrow spans first
+    let mut row_spans = row_spans.to_vec();
+    row_spans.sort_by_key(|&(_, _, span)| span);
+    for (row, column, span) in row_spans {
+        let lines = split_lines[row..row + span - 1]
+            .iter()
+            .filter(|&&line| line)
+            .count();
+        let current = row_heights[row..row + span].iter().sum::<usize>() + lines;
+        let height = sizes[row][column].1;
+        if height > current {
+            spread(
+                &mut row_heights[row..row + span],
+                height - current,
+                options.span_spread,
+            );
+        }
+    }
+
+    row_heights
+}
+
+fn cell_height(cell: &[Cow<'_, str>], style: &Style) -> usize {
+    if let Some(nested) = &style.nested {
+        return nested.measure().height();
+    }
+
+    let content_height = cell.len();
+    content_height + style.indent.top + style.indent.bottom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_1x1_test() {
+        let mut grid = Grid::new(1, 1);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+\n\
+             |asd|\n\
+             +---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_entity_settings_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Column(0), Settings::new().text("zxc"));
+        grid.set(Entity::Row(0), Settings::new().text("qwe"));
+        grid.set(Entity::Cell(1, 1), Settings::new().text("iop"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |qwe|qwe|\n\
+             +---+---+\n\
+             |zxc|iop|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_alignment_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd    "));
+        grid.set(
+            Entity::Column(0),
+            Settings::new().alignment(AlignmentHorizontal::Left),
+        );
+        grid.set(
+            Entity::Column(1),
+            Settings::new().alignment(AlignmentHorizontal::Right),
+        );
+        let str = grid.to_string();
+
+        assert_eq!(
+            str,
+            "+-------+-------+\n\
+             |asd    |    asd|\n\
+             +-------+-------+\n\
+             |asd    |    asd|\n\
+             +-------+-------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_indent_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new().text("asd").indent(1, 1, 1, 1),
+        );
+        grid.set(Entity::Column(0), Settings::new().indent(0, 0, 0, 0));
+        let str = grid.to_string();
+
+        assert_eq!(
+            str,
+            "+---+-----+\n\
+             |asd|     |\n\
+             |   | asd |\n\
+             |   |     |\n\
+             +---+-----+\n\
+             |asd|     |\n\
+             |   | asd |\n\
+             |   |     |\n\
+             +---+-----+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_vertical_resize_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Cell(1, 1), Settings::new().text("asd     "));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+--------+\n\
+             |asd|asd     |\n\
+             +---+--------+\n\
+             |asd|asd     |\n\
+             +---+--------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_without_frame_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.get_border_mut(0).empty().inner(Some(' '), None, None);
+        grid.get_border_mut(1).empty().inner(Some(' '), None, None);
+
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "asd asd\n\
+             asd asd\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_custom_border_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+
+        grid.get_border_mut(0)
+            .top('*', ' ', Some(' '), Some(' '))
+            .inner(Some('@'), Some('$'), Some('%'));
+        grid.get_border_mut(1)
+            .top('*', ' ', Some(' '), Some(' '))
+            .bottom('*', ' ', Some(' '), Some(' '))
+            .inner(Some('^'), Some('#'), Some('!'));
+
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            " *** *** \n\
+             $asd@asd%\n\
+             +---+---+\n\
+             #asd^asd!\n\
+             \u{0020}*** *** \n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_remove_row_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.remove_row(0);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_remove_column_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.remove_column(0);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+\n\
+             |asd|\n\
+             +---+\n\
+             |asd|\n\
+             +---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_remove_shrinks_spans_test() {
+        let mut grid = Grid::from_fn(3, 4, |row, column| format!("{}{}", row, column));
+        grid.set(Entity::Cell(0, 0), Settings::new().set_row_span(3));
+        grid.set(Entity::Cell(1, 1), Settings::new().set_span(3));
+
+        grid.remove_row(2);
+        grid.remove_column(2);
+
+        assert_eq!(grid.validate(), Ok(()));
+        assert_eq!(
+            grid.to_string(),
+            "+--+--+--+\n\
+             |00|01|03|\n\
+             |  +--+--+\n\
+             |  |11   |\n\
+             +--+-----+\n"
+        );
+    }
+
+    #[test]
+    fn grid_insert_and_remove_keep_settings_test() {
+        let mut grid = Grid::from_fn(2, 2, |row, column| format!("{}{}", row, column));
+        grid.set(
+            Entity::Row(1),
+            Settings::new().alignment(AlignmentHorizontal::Right),
+        );
+        grid.set(Entity::Column(1), Settings::new().text("x"));
+        grid.set_min_column_width(1, 3);
+
+        grid.insert_row(0);
+        grid.push_row();
+        grid.insert_column(1);
+        grid.push_column();
+        grid.set(Entity::Global, Settings::new().indent(0, 0, 0, 0));
+        grid.set(Entity::Cell(3, 3), Settings::new().text("end"));
+
+        assert_eq!(
+            grid.to_string(),
+            "+--++---+---+\n\
+             |  ||   |   |\n\
+             +--++---+---+\n\
+             |00||x  |   |\n\
+             +--++---+---+\n\
+             |10||  x|   |\n\
+             +--++---+---+\n\
+             |  ||   |end|\n\
+             +--++---+---+\n"
+        );
+
+        grid.remove_row(2);
+        grid.remove_column(2);
+
+        assert_eq!(
+            grid.to_string(),
+            "+--++---+\n\
+             |  ||   |\n\
+             +--++---+\n\
+             |00||   |\n\
+             +--++---+\n\
+             |  ||end|\n\
+             +--++---+\n"
+        );
+    }
+
+    #[test]
+    fn grid_incremental_tracks_changed_cells_test() {
+        let mut grid = Grid::from_fn(2, 2, |row, column| row * 2 + column);
+        grid.set_incremental(true);
+        assert_eq!(grid.to_string(), "+-+-+\n|0|1|\n+-+-+\n|2|3|\n+-+-+\n");
+
+        for cell in grid.row_mut(1) {
+            *cell = Cow::Borrowed("xy");
+        }
+        grid.set_cell_content(0, 0, "abc");
+        assert_eq!(
+            grid.to_string(),
+            "+---+--+\n|abc|1 |\n+---+--+\n|xy |xy|\n+---+--+\n"
+        );
+
+        grid.set_tab_width(1);
+        grid.set_cell_content(0, 1, "\t");
+        assert_eq!(
+            grid.to_string(),
+            "+---+--+\n|abc|  |\n+---+--+\n|xy |xy|\n+---+--+\n"
+        );
+    }
+
+    #[test]
+    fn grid_simple_path_matches_layout_test() {
+        let settings = [
+            Settings::new().alignment(AlignmentHorizontal::Left),
+            Settings::new()
+                .indent(1, 2, 1, 0)
+                .alignment(AlignmentHorizontal::Right),
+            Settings::new()
+                .indent(0, 0, 0, 1)
+                .alignment(AlignmentHorizontal::Center)
+                .fill('.'),
+        ];
+
+        for settings in settings {
+            let mut grid = Grid::from_fn(3, 3, |row, column| match (row, column) {
+                (1, 1) => String::new(),
+                (2, 0) => String::from("\u{1F600} wide"),
+                _ => (row * column).to_string().repeat(row + 1),
+            });
+            grid.set(Entity::Global, settings.clone());
+            grid.get_border_mut(1).bottom_line = LineStyle::default();
+            grid.set_horizontal_char(0, 1, '#');
+            grid.set_vertical_char(2, 1, '!');
+            assert!(grid.simple_style().is_some());
+
+            let mut layout = grid.clone();
+            layout.set(Entity::Cell(0, 0), settings);
+            assert!(layout.simple_style().is_none());
+
+            assert_eq!(grid.to_string(), layout.to_string());
+        }
+    }
+
+    #[test]
+    fn grid_simple_path_is_not_used_for_multiline_test() {
+        let grid = Grid::from_fn(1, 2, |_, column| if column == 0 { "a\nb" } else { "c" });
+        assert!(grid.simple_style().is_none());
+        assert_eq!(grid.to_string(), "+-+-+\n|a|c|\n|b| |\n+-+-+\n");
+    }
+
+    #[test]
+    fn grid_validate_test() {
+        let mut grid = Grid::new(3, 3);
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().set_span(2).set_row_span(4),
+        );
+        grid.set(Entity::Cell(1, 1), Settings::new().set_span(2));
+        grid.set(Entity::Column(7), Settings::new().indent(1, 0, 0, 0));
+        grid.set_min_column_width(4, 10);
+        grid.set_min_column_width(1, 10);
+
+        assert_eq!(
+            grid.validate(),
+            Err(vec![
+                ValidationError::EntityOutOfGrid(Entity::Column(7)),
+                ValidationError::MinWidthOutOfGrid(4),
+                ValidationError::RowSpanOutOfGrid {
+                    row: 0,
+                    column: 0,
+                    span: 4
+                },
+                ValidationError::OverlappingSpans { row: 1, column: 1 },
+            ])
+        );
+
+        grid.remove_column(0);
+        assert_eq!(
+            grid.validate(),
+            Err(vec![
+                ValidationError::EntityOutOfGrid(Entity::Column(6)),
+                ValidationError::MinWidthOutOfGrid(3),
+            ])
+        );
+    }
+
+    #[test]
+    fn grid_validate_text_out_of_grid_test() {
+        let mut grid = Grid::new(0, 0);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("x"));
+        grid.set(Entity::Row(1), Settings::new().text("x"));
+        grid.set(Entity::Column(2), Settings::new().text("x"));
+        grid.set(Entity::Global, Settings::new().text("x"));
+
+        assert_eq!(grid.to_string(), "");
+        assert_eq!(
+            grid.validate(),
+            Err(vec![
+                ValidationError::EntityOutOfGrid(Entity::Row(1)),
+                ValidationError::EntityOutOfGrid(Entity::Column(2)),
+                ValidationError::EntityOutOfGrid(Entity::Cell(0, 0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn grid_width_cache_test() {
+        let mut grid = Grid::from_fn(2, 2, |_, column| column);
+        grid.set_width_cache(true);
+        assert_eq!(grid.to_string(), "+-+-+\n|0|1|\n+-+-+\n|0|1|\n+-+-+\n");
+
+        grid.set_cell_content(1, 1, "\u{1F600}");
+        grid.set(Entity::Cell(1, 0), Settings::new().indent(1, 1, 0, 0));
+        assert_eq!(
+            grid.to_string(),
+            "+---+--+\n|0  |1 |\n+---+--+\n| 0 |\u{1F600}|\n+---+--+\n"
+        );
+
+        let stats = grid.width_cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (5, 3, 3));
+
+        grid.set(Entity::Global, Settings::new().text("a"));
+        assert_eq!(
+            grid.to_string(),
+            "+---+-+\n|a  |a|\n+---+-+\n| a |a|\n+---+-+\n"
+        );
+
+        let stats = grid.width_cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (8, 4, 1));
+
+        grid.clear_width_cache();
+        assert_eq!(grid.width_cache_stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn grid_2x3_set_column_order_test() {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Row(0), Settings::new().text("a"));
+        grid.set(Entity::Row(1), Settings::new().text("b"));
+        grid.set(Entity::Cell(0, 0), Settings::new().text("qwe"));
+        grid.set(Entity::Column(2), Settings::new().indent(1, 1, 0, 0));
+        grid.set_column_order(&[2, 0, 1]).unwrap();
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+-+\n\
+             | a |qwe|a|\n\
+             +---+---+-+\n\
+             | b |b  |b|\n\
+             +---+---+-+\n"
+        )
+    }
+
+    #[test]
+    fn grid_set_column_order_moves_spans_test() {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("span").set_span(2));
+        grid.set_column_order(&[1, 2, 0]).unwrap();
+        assert_eq!(
+            grid.to_string(),
+            "+----+-+\n\
+             |span|a|\n\
+             +--+-+-+\n\
+             |a |a|a|\n\
+             +--+-+-+\n"
+        )
+    }
+
+    #[test]
+    fn grid_set_column_order_split_span_test() {
+        let mut grid = Grid::from_fn(2, 3, |_, column| column);
+        grid.set(Entity::Cell(1, 1), Settings::new().set_span(2));
+        let rendered = grid.to_string();
+
+        assert_eq!(
+            grid.set_column_order(&[2, 0, 1]),
+            Err(OrderError::SplitSpan { row: 1, column: 1 })
+        );
+        assert_eq!(
+            grid.set_column_order(&[0, 2, 1]),
+            Err(OrderError::SplitSpan { row: 1, column: 1 })
+        );
+        assert_eq!(grid.to_string(), rendered);
+    }
+
+    #[test]
+    fn grid_set_column_order_not_permutation_test() {
+        let mut grid = Grid::new(2, 3);
+        assert_eq!(
+            grid.set_column_order(&[0, 0, 1]),
+            Err(OrderError::NotPermutation)
+        );
+        assert_eq!(
+            grid.set_column_order(&[0, 1, 3]),
+            Err(OrderError::NotPermutation)
+        );
+    }
+
+    #[test]
+    fn grid_set_row_order_moves_spans_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(
+            Entity::Cell(1, 0),
+            Settings::new().text("b").set_row_span(2),
+        );
+        grid.set_row_order(&[1, 2, 0]);
+        assert_eq!(
+            grid.to_string(),
+            "+-+-+\n\
+             |b|a|\n\
+             | +-+\n\
+             | |a|\n\
+             +-+-+\n\
+             |a|a|\n\
+             +-+-+\n"
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_set_row_order_split_span_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Cell(1, 0), Settings::new().set_row_span(2));
+        grid.set_row_order(&[2, 0, 1]);
+    }
+
+    #[test]
+    fn grid_3x2_set_row_order_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Row(0), Settings::new().text("a"));
+        grid.set(Entity::Row(1), Settings::new().text("b"));
+        grid.set(Entity::Row(2), Settings::new().text("c"));
+        grid.set(Entity::Cell(1, 0), Settings::new().text("qwe"));
+        grid.set(Entity::Row(2), Settings::new().indent(1, 1, 0, 0));
+        grid.set_row_order(&[0, 2, 1]);
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |a  |a  |\n\
+             +---+---+\n\
+             | c | c |\n\
+             +---+---+\n\
+             |qwe|b  |\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_set_row_order_not_permutation_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set_row_order(&[1, 1]);
+    }
+
+    #[test]
+    fn grid_set_column_order_wrong_length_test() {
+        let mut grid = Grid::new(2, 3);
+        assert_eq!(
+            grid.set_column_order(&[1, 0]),
+            Err(OrderError::NotPermutation)
+        );
+        assert_eq!(
+            grid.set_column_order(&[1, 0, 2, 3]),
+            Err(OrderError::NotPermutation)
+        );
+    }
+
+    #[test]
+    fn grid_2x2_entities_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(
+            Entity::Column(1),
+            Settings::new().alignment(AlignmentHorizontal::Right),
+        );
+        grid.set(Entity::Cell(1, 1), Settings::new().text("qwe"));
+
+        let entities: Vec<_> = grid.entities().collect();
+        assert_eq!(entities.len(), 4);
+
+        let (entity, settings) = &entities[3];
+        assert_eq!(*entity, Entity::Cell(1, 1));
+        assert_eq!(settings.get_text(), Some("qwe"));
+        assert_eq!(settings.get_alignment(), Some(AlignmentHorizontal::Right));
+        assert_eq!(settings.get_indent(), Some((0, 0, 0, 0)));
+
+        let (_, settings) = &entities[0];
+        assert_eq!(settings.get_alignment(), Some(AlignmentHorizontal::Left));
+    }
+
+    #[test]
+    fn grid_render_fit_height_test() {
+        let mut grid = Grid::new(4, 1);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("h"));
+        grid.set(Entity::Cell(1, 0), Settings::new().text("1"));
+        grid.set(Entity::Cell(2, 0), Settings::new().text("2\n2"));
+        grid.set(Entity::Cell(3, 0), Settings::new().text("3"));
+
+        assert_eq!(
+            grid.render_fit_height(8, Anchor::Bottom),
+            "+-+\n\
+             |h|\n\
+             +-+\n\
+             |2|\n\
+             |2|\n\
+             +-+\n\
+             |3|\n\
+             +-+\n"
+        );
+        assert_eq!(
+            grid.render_fit_height(7, Anchor::Top),
+            "+-+\n\
+             |h|\n\
+             +-+\n\
+             |1|\n\
+             +-+\n"
+        );
+        assert_eq!(grid.render_fit_height(2, Anchor::Top), "+-+\n|h|\n");
+        assert_eq!(grid.render_fit_height(100, Anchor::Top), grid.to_string());
+    }
+
+    #[test]
+    fn grid_render_fit_height_framed_test() {
+        let mut grid = Grid::new(4, 2);
+        grid.set(Entity::Global, Settings::new().text("ab"));
+        for row in 0..4 {
+            let top = if row == 0 {
+                ('─', '┬', Some('┌'), Some('┐'))
+            } else {
+                ('─', '┼', Some('├'), Some('┤'))
+            };
+            let bottom = if row == 3 {
+                ('─', '┴', Some('└'), Some('┘'))
+            } else {
+                ('─', '┼', Some('├'), Some('┤'))
+            };
+            grid.get_border_mut(row)
+                .empty()
+                .top(top.0, top.1, top.2, top.3)
+                .bottom(bottom.0, bottom.1, bottom.2, bottom.3)
+                .inner(Some('│'), Some('│'), Some('│'));
+        }
+
+        assert_eq!(
+            grid.render_fit_height(7, Anchor::Top),
+            "┌──┬──┐\n\
+             │ab│ab│\n\
+             ├──┼──┤\n\
+             │ab│ab│\n\
+             ├──┼──┤\n\
+             │ab│ab│\n\
+             └──┴──┘\n"
+        );
+        assert_eq!(
+            grid.render_fit_height(6, Anchor::Bottom),
+            "┌──┬──┐\n\
+             │ab│ab│\n\
+             ├──┼──┤\n\
+             │ab│ab│\n\
+             └──┴──┘\n"
+        );
+        assert_eq!(
+            grid.render_fit_height(4, Anchor::Top),
+            "┌──┬──┐\n\
+             │ab│ab│\n\
+             └──┴──┘\n"
+        );
+        assert_eq!(grid.render_fit_height(9, Anchor::Top), grid.to_string());
+    }
+
+    #[test]
+    fn grid_3x2_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    #[ignore = "I am not sure what is the right behaiviour here"]
+    fn hieroglyph_handling() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("哈哈"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("哈"));
+        let s = grid.to_string();
+        assert_eq!(
+            s,
+            "+----+--+\n\
+             |哈哈  |哈 |\n\
+             +----+--+\n"
+        )
+    }
+
+    #[test]
+    #[ignore = "I am not sure what is the right behaiviour here"]
+    fn hieroglyph_multiline_handling() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("哈哈"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("哈\n哈"));
+        let s = grid.to_string();
+        assert_eq!(
+            s,
+            "+----+--+\n\
+             |哈哈  |哈 |\n\
+             |    |哈 |\n\
+             +----+--+\n"
+        )
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn colored_string_width_test() {
+        use colored::Colorize;
+        assert_eq!(string_width(&"hello world".red().to_string()), 11);
+        assert_eq!(string_width(&"hello\nworld".blue().to_string()), 5);
+        assert_eq!(string_width("\u{1b}[34m0\u{1b}[0m"), 1);
+        assert_eq!(string_width(&"0".red().to_string()), 1);
+    }
+
+    #[test]
+    fn string_width_emojie_test() {
+        // ...emojis such as “joy”, which normally take up two columns when printed in a terminal
+        // https://github.com/mgeisler/textwrap/pull/276
+        assert_eq!(string_width("🎩"), 2);
+        assert_eq!(string_width("Rust 💕"), 7);
+        assert_eq!(string_width("Go 👍\nC 😎"), 5);
+    }
+
+    #[test]
+    fn horizontal_aligment_test() {
+        use std::fmt;
+
+        struct F<'a>(&'a str, AlignmentHorizontal, usize);
+
+        impl fmt::Display for F<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.1.align(f, self.0, self.2, None, Remainder::End, ' ')
+            }
+        }
+
+        assert_eq!(F("AAA", AlignmentHorizontal::Right, 4).to_string(), " AAA");
+        assert_eq!(F("AAA", AlignmentHorizontal::Left, 4).to_string(), "AAA ");
+        assert_eq!(F("AAA", AlignmentHorizontal::Center, 4).to_string(), "AAA ");
+        assert_eq!(F("🎩", AlignmentHorizontal::Center, 4).to_string(), " 🎩 ");
+        assert_eq!(F("🎩", AlignmentHorizontal::Center, 3).to_string(), "🎩 ");
+        #[cfg(feature = "color")]
+        {
+            use colored::Colorize;
+            let text = "Colored Text".red().to_string();
+            assert_eq!(
+                F(&text, AlignmentHorizontal::Center, 15).to_string(),
+                format!(" {}  ", text)
+            );
+        }
+    }
+
+    #[test]
+    fn grid_2x2_indent_position_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new()
+                .text("asd")
+                .indent(3, 0, 0, 0)
+                .alignment(AlignmentHorizontal::Center),
+        );
+        grid.set(Entity::Cell(0, 0), Settings::new().text("asdasd"));
+        grid.set(
+            Entity::Column(1),
+            Settings::new()
+                .indent(3, 0, 0, 0)
+                .alignment(AlignmentHorizontal::Center)
+                .indent_position(IndentPosition::Inside),
+        );
+        grid.set(Entity::Cell(0, 1), Settings::new().text("asdasd"));
+        let str = grid.to_string();
+
+        assert_eq!(
+            str,
+            "+---------+---------+\n\
+             |   asdasd|   asdasd|\n\
+             +---------+---------+\n\
+             |    asd  |   asd   |\n\
+             +---------+---------+\n"
+        )
+    }
+
+    #[test]
+    fn vertical_aligment_test() {
+        assert_eq!(AlignmentVertical::Bottom.top_ident(1, 1, Remainder::End), 0);
+        assert_eq!(AlignmentVertical::Top.top_ident(1, 1, Remainder::End), 0);
+        assert_eq!(AlignmentVertical::Center.top_ident(1, 1, Remainder::End), 0);
+        assert_eq!(AlignmentVertical::Bottom.top_ident(3, 1, Remainder::End), 2);
+        assert_eq!(AlignmentVertical::Top.top_ident(3, 1, Remainder::End), 0);
+        assert_eq!(AlignmentVertical::Center.top_ident(3, 1, Remainder::End), 1);
+        assert_eq!(AlignmentVertical::Center.top_ident(4, 1, Remainder::End), 1);
+    }
+
+    #[test]
+    fn grid_2x2_span_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Cell(0, 0), Settings::new().text("123").set_span(2));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+-------+\n\
+             |123    |\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn grid_2x2_color_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new()
+                .text("asd")
+                .alignment(AlignmentHorizontal::Center),
+        );
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("a").color(Color::red()),
+        );
+        grid.get_border_mut(1)
+            .empty()
+            .inner(Some('|'), None, None)
+            .color(Color::dim());
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             | \u{1b}[31ma\u{1b}[0m |asd|\n\
+             +---+---+\n\
+             asd\u{1b}[2m|\u{1b}[0masd\n"
+        )
+    }
+
+    #[test]
+    fn grid_3x3_wide_span_keeps_columns_aligned_test() {
+        let mut grid = Grid::new(3, 3);
+        grid.set(Entity::Global, Settings::new().text("a").indent(1, 1, 0, 0));
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("a long spanned text").set_span(2),
+        );
+        grid.set(Entity::Cell(1, 2), Settings::new().text("xx"));
+        grid.set(
+            Entity::Cell(2, 1),
+            Settings::new().text("long\nmulti line").set_span(2),
+        );
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+-------------------+----+\n\
+             |a long spanned text| a  |\n\
+             +---------+---------+----+\n\
+             | a       | a       | xx |\n\
+             +---------+---------+----+\n\
+             | a       |long          |\n\
+             |         |multi line    |\n\
+             +---------+--------------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_3x3_nested_spans_with_indent_test() {
+        let mut grid = Grid::new(3, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new()
+                .text("1234567")
+                .set_span(2)
+                .indent(2, 2, 1, 0),
+        );
+        grid.set(
+            Entity::Cell(1, 0),
+            Settings::new().text("123456789012345").set_span(3),
+        );
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+-------------+-+\n\
+             |             |a|\n\
+             |  1234567    | |\n\
+             +-------------+-+\n\
+             |123456789012345|\n\
+             +------+------+-+\n\
+             |a     |a     |a|\n\
+             +------+------+-+\n"
+        )
+    }
+
+    #[test]
+    fn measure_matches_rendered_grid_test() {
+        let mut grid = Grid::new(3, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("1234567").set_span(2),
+        );
+        grid.set(Entity::Cell(2, 2), Settings::new().text("b\nc\nd"));
+
+        let measurement = grid.measure();
+        let rendered = grid.to_string();
+
+        assert_eq!(measurement.column_widths(), &[3, 3, 1]);
+        assert_eq!(measurement.row_heights(), &[1, 1, 3]);
+        assert_eq!(measurement.height(), rendered.lines().count());
+        assert_eq!(
+            measurement.width(),
+            rendered.lines().map(|l| l.chars().count()).max().unwrap()
+        );
+        assert_eq!(grid.draw(&measurement), rendered);
+    }
+
+    #[test]
+    fn measure_ragged_grid_test() {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set_last_row_length(1);
+
+        let measurement = grid.measure();
+        assert_eq!(measurement.width(), 7);
+        assert_eq!(measurement.height(), 5);
+        assert_eq!(grid.draw(&measurement), grid.to_string());
+    }
+
+    #[test]
+    fn measure_empty_grid_test() {
+        let grid = Grid::new(0, 0);
+        let measurement = grid.measure();
+        assert_eq!(measurement, Measurement::default());
+        assert_eq!(grid.draw(&measurement), "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn draw_with_measurement_of_another_grid_test() {
+        let measurement = Grid::new(1, 1).measure();
+        Grid::new(2, 2).draw(&measurement);
+    }
+
+    #[test]
+    fn grid_from_fn_test() {
+        let mut calls = Vec::new();
+        let grid = Grid::from_fn(2, 2, |row, column| {
+            calls.push((row, column));
+            format!("{}{}", row, column)
+        });
+
+        assert_eq!(calls, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.get_cell_content(1, 0), "10");
+        assert_eq!(
+            grid.to_string(),
+            "+--+--+\n\
+             |00|01|\n\
+             +--+--+\n\
+             |10|11|\n\
+             +--+--+\n"
+        )
+    }
+
+    #[test]
+    fn grid_from_fn_empty_test() {
+        let grid = Grid::from_fn(0, 3, |_, _| -> &str { unreachable!() });
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn grid_borrowed_content_test() {
+        let data = [String::from("hello\nworld"), String::from("!")];
+
+        let mut grid = Grid::new(1, 2);
+        grid.set_cell_content(0, 0, data[0].as_str());
+        grid.set_cell_content(0, 1, data[1].as_str());
+
+        assert!(std::ptr::eq(grid.get_cell_content(0, 0), data[0].as_str()));
+        assert_eq!(
+            grid.to_string(),
+            "+-----+-+\n\
+             |hello|!|\n\
+             |world| |\n\
+             +-----+-+\n"
+        );
+
+        grid.set(Entity::Cell(0, 1), Settings::new().text("?"));
+        assert_eq!(grid.get_cell_content(0, 1), "?");
+        assert_eq!(grid.get_cell_content(0, 0), "hello\nworld");
+    }
+
+    #[test]
+    fn grid_parts_test() {
+        let mut styled = Grid::new(2, 1);
+        styled.set(Entity::Global, Settings::new().indent(1, 1, 0, 0));
+        let (_, config) = styled.into_parts();
+
+        let mut records = Records::new(2, 1);
+        records.set(0, 0, "a");
+        records.set(1, 0, String::from("b"));
+
+        let grid = Grid::from_parts(records, config);
+        assert_eq!(grid.records().get(1, 0), "b");
+        assert_eq!(grid.to_string(), "+---+\n| a |\n+---+\n| b |\n+---+\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_set_config_of_different_size_test() {
+        let mut grid = Grid::new(2, 1);
+        grid.set_config(Grid::new(3, 1).config().clone());
+    }
+
+    #[test]
+    fn grid_row_and_column_test() {
+        let mut grid = Grid::from_fn(2, 3, |row, column| format!("{}{}", row, column));
+
+        assert_eq!(grid.row(1).collect::<Vec<_>>(), ["10", "11", "12"]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), ["02", "12"]);
+        assert_eq!(grid.column(0).len(), 2);
+
+        grid.row_mut(0).for_each(|cell| cell.to_mut().push('!'));
+        *grid.column_mut(1).last().unwrap() = "x".into();
+
+        assert_eq!(
+            grid.to_string(),
+            "+---+---+---+\n\
+             |00!|01!|02!|\n\
+             +---+---+---+\n\
+             |10 |x  |12 |\n\
+             +---+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn grid_from_fixed_width_test() {
+        let text = "\
+            Name  Size\n\
+            \n\
+            café  1K\n\
+            a     \n";
+
+        let grid = Grid::from_fixed_width(text, &[0..6, 6..10, 10..12]);
+
+        assert_eq!(grid.count_rows(), 3);
+        assert_eq!(grid.row(0).collect::<Vec<_>>(), ["Name", "Size", ""]);
+        assert_eq!(grid.row(1).collect::<Vec<_>>(), ["café", "1K", ""]);
+        assert_eq!(grid.row(2).collect::<Vec<_>>(), ["a", "", ""]);
+        assert!(std::ptr::eq(
+            grid.get_cell_content(1, 0).as_ptr(),
+            text[text.find("café").unwrap()..].as_ptr()
+        ));
+    }
+
+    #[test]
+    fn grid_render_to_file_test() {
+        let grid = Grid::from_fn(3, 2, |row, column| row * column);
+        let path = std::env::temp_dir().join(format!("papergrid-{}.txt", std::process::id()));
+
+        let mut progress = Vec::new();
+        grid.render_to_file(&path, |row, total| progress.push((row, total)))
+            .unwrap();
+        let rendered = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(rendered, grid.to_string());
+        assert_eq!(progress, [(1, 3), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn grid_render_to_file_error_test() {
+        let grid = Grid::new(1, 1);
+        let path = std::env::temp_dir()
+            .join("papergrid-missing-dir")
+            .join("grid.txt");
+
+        assert!(grid.render_to_file(path, |_, _| {}).is_err());
+    }
+
+    #[test]
+    fn grid_select_rows_test() {
+        let mut grid = Grid::from_fn(4, 2, |row, column| row * 10 + column);
+        grid.set(Entity::Row(2), Settings::new().text("x"));
+        grid.set(Entity::Cell(3, 1), Settings::new().text("y"));
+        grid.set_min_row_height(3, 2);
+
+        let page = grid.select_rows(&[0, 3, 2, 3]);
+
+        assert_eq!(page.count_rows(), 4);
+        assert_eq!(page.get_cell_content(1, 1), "y");
+        assert_eq!(page.get_cell_content(2, 0), "x");
+        assert_eq!(page.get_cell_content(3, 0), "30");
+        assert_eq!(page.measure().row_heights(), [1, 2, 1, 2]);
+        assert_eq!(grid.get_cell_content(1, 0), "10");
+    }
+
+    #[test]
+    fn grid_select_no_rows_test() {
+        let grid = Grid::from_fn(2, 2, |row, column| row + column);
+        let page = grid.select_rows(&[]);
+
+        assert_eq!(page.count_rows(), 0);
+        assert_eq!(page.count_columns(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_select_rows_out_of_grid_test() {
+        Grid::new(2, 2).select_rows(&[2]);
+    }
+
+    fn spanned_grid(options: LayoutOptions) -> Grid<'static> {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("0123456789").set_span(3),
+        );
+        grid.set_layout_options(options);
+        grid
+    }
+
+    #[test]
+    fn grid_layout_default_test() {
+        assert_eq!(
+            spanned_grid(LayoutOptions::new()).measure().column_widths(),
+            [3, 3, 2]
+        );
+        assert_eq!(
+            spanned_grid(LayoutOptions::default()).to_string(),
+            spanned_grid(LayoutOptions::new().span_spread(Spread::Leading)).to_string()
+        );
+    }
+
+    #[test]
+    fn grid_layout_span_spread_test() {
+        let widths = |spread| {
+            let grid = spanned_grid(LayoutOptions::new().span_spread(spread));
+            grid.measure().column_widths().to_vec()
+        };
+
+        assert_eq!(widths(Spread::Trailing), [2, 3, 3]);
+        assert_eq!(widths(Spread::First), [6, 1, 1]);
+        assert_eq!(widths(Spread::Last), [1, 1, 6]);
+        assert_eq!(widths(Spread::Proportional), [3, 3, 2]);
+    }
+
+    #[test]
+    fn spread_proportionally_test() {
+        let spread = |mut sizes: Vec<usize>, extra| {
+            super::spread(&mut sizes, extra, Spread::Proportional);
+            sizes
+        };
+
+        assert_eq!(spread(vec![1, 4, 0], 10), [3, 12, 0]);
+        assert_eq!(spread(vec![1, 2], 4), [2, 5]);
+        assert_eq!(spread(vec![2, 2, 2], 4), [4, 3, 3]);
+        assert_eq!(spread(vec![0, 0, 0], 4), [2, 1, 1]);
+        assert_eq!(spread(vec![3, 1], 0), [3, 1]);
+    }
+
+    #[test]
+    fn grid_layout_span_separators_test() {
+        let grid = spanned_grid(LayoutOptions::new().span_separators(SeparatorOwnership::Columns));
+
+        assert_eq!(grid.measure().column_widths(), [4, 3, 3]);
+        assert_eq!(
+            grid.to_string(),
+            "+------------+\n\
+             |0123456789  |\n\
+             +----+---+---+\n\
+             |a   |a  |a  |\n\
+             +----+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn grid_layout_center_remainder_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new()
+                .alignment(AlignmentHorizontal::Center)
+                .vertical_alignment(AlignmentVertical::Center),
+        );
+        grid.set(Entity::Cell(0, 0), Settings::new().text("ab\nabcde\nab"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("x\nx"));
+
+        assert_eq!(
+            grid.to_string(),
+            "+-----+-+\n\
+             | ab  |x|\n\
+             |abcde|x|\n\
+             | ab  | |\n\
+             +-----+-+\n"
+        );
+
+        grid.set_layout_options(LayoutOptions::new().center_remainder(Remainder::Start));
+        assert_eq!(
+            grid.to_string(),
+            "+-----+-+\n\
+             |  ab | |\n\
+             |abcde|x|\n\
+             |  ab |x|\n\
+             +-----+-+\n"
+        );
+    }
+
+    #[test]
+    fn grid_nested_participates_in_layout_test() {
+        let mut nested = Grid::from_fn(2, 2, |row, column| row * 100 + column);
+        nested.set(Entity::Global, Settings::new().indent(1, 1, 0, 0));
+        let mut grid = Grid::from_fn(2, 2, |row, column| row + column);
+        grid.set(Entity::Cell(0, 1), Settings::new().nested(nested));
+
+        let measurement = grid.measure();
+        assert_eq!(measurement.column_widths(), [1, 13]);
+        assert_eq!(measurement.row_heights(), [5, 1]);
+        assert_eq!(
+            grid.to_string(),
+            "+-+-------------+\n\
+             |0|+-----+-----+|\n\
+             | || 0   | 1   ||\n\
+             | |+-----+-----+|\n\
+             | || 100 | 101 ||\n\
+             | |+-----+-----+|\n\
+             +-+-------------+\n\
+             |1|2            |\n\
+             +-+-------------+\n"
+        );
+    }
+
+    #[test]
+    fn grid_nested_is_stretched_test() {
+        let mut nested = Grid::from_fn(1, 2, |_, column| column);
+        nested
+            .get_border_mut(0)
+            .empty()
+            .inner(Some('|'), None, None);
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("abcdef"));
+        grid.set(Entity::Cell(1, 0), Settings::new().text("a\nb"));
+        grid.set(Entity::Cell(1, 1), Settings::new().nested(nested));
+
+        assert_eq!(
+            grid.to_string(),
+            "+------+------+\n\
+             |abcdef|abcdef|\n\
+             +------+------+\n\
+             |a     |0|1   |\n\
+             |b     | |    |\n\
+             +------+------+\n"
+        );
+    }
+
+    #[test]
+    fn grid_nested_in_span_test() {
+        let nested = Grid::from_fn(1, 1, |_, _| "nested");
+        let mut grid = Grid::from_fn(2, 2, |row, column| row + column);
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().set_span(2).nested(nested),
+        );
+
+        assert_eq!(
+            grid.to_string(),
+            "+--------+\n\
+             |+------+|\n\
+             ||nested||\n\
+             |+------+|\n\
+             +----+---+\n\
+             |1   |2  |\n\
+             +----+---+\n"
+        );
+    }
+
+    #[test]
+    fn grid_nested_settings_test() {
+        let nested = Grid::from_fn(1, 1, |_, _| "x");
+        let mut grid = Grid::from_fn(2, 1, |row, _| row);
+        grid.set(Entity::Cell(0, 0), Settings::new().nested(nested.clone()));
+
+        let settings = grid.get_cell_settings(0, 0);
+        assert_eq!(
+            settings.get_nested().map(|grid| grid.to_string()),
+            Some(nested.to_string())
+        );
+        assert!(grid.get_cell_settings(1, 0).get_nested().is_none());
+
+        grid.set_row_order(&[1, 0]);
+        assert!(grid.get_cell_settings(1, 0).get_nested().is_some());
+    }
+
+    #[test]
+    fn grid_row_span_content_takes_split_lines_test() {
+        let mut grid = Grid::from_fn(3, 2, |row, column| row * 2 + column);
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("a\nb\nc").set_row_span(2),
+        );
+
+        assert_eq!(grid.measure().row_heights(), [1, 1, 1]);
+        assert_eq!(
+            grid.to_string(),
+            "+-+-+\n\
+             |a|1|\n\
+             |b+-+\n\
+             |c|3|\n\
+             +-+-+\n\
+             |4|5|\n\
+             +-+-+\n"
+        );
+    }
+
+    #[test]
+    fn grid_lines_match_rendering_test() {
+        let mut grid = Grid::from_fn(3, 3, |row, column| row * 3 + column);
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("a\nb\nc").set_row_span(2),
+        );
+        grid.set(Entity::Cell(2, 1), Settings::new().text("x").set_span(2));
+        grid.set(
+            Entity::Cell(1, 2),
+            Settings::new().nested(Grid::from_fn(1, 2, |_, column| column)),
+        );
+        grid.set_last_row_length(2);
+
+        let lines = grid.lines().map(|line| line + "\n").collect::<String>();
+        assert_eq!(lines, grid.to_string());
+
+        grid.set_trim_trailing_whitespace(true);
+        grid.get_border_mut(2).empty();
+        let lines = grid.lines().map(|line| line + "\n").collect::<String>();
+        assert_eq!(lines, grid.to_string());
+    }
+
+    #[test]
+    fn grid_row_span_expands_rows_test() {
+        let mut grid = Grid::from_fn(3, 2, |row, column| row * 2 + column);
+        grid.set(
+            Entity::Cell(1, 1),
+            Settings::new().text("a\nb\nc\nd\ne").set_row_span(2),
+        );
+
+        assert_eq!(grid.measure().row_heights(), [1, 2, 2]);
+        assert_eq!(
+            grid.to_string(),
+            "+-+-+\n\
+             |0|1|\n\
+             +-+-+\n\
+             |2|a|\n\
+             | |b|\n\
+             +-+c|\n\
+             |4|d|\n\
+             | |e|\n\
+             +-+-+\n"
+        );
+    }
+
+    #[test]
+    fn grid_row_span_is_limited_by_grid_test() {
+        let mut grid = Grid::from_fn(2, 2, |row, column| row * 2 + column);
+        grid.set(Entity::Cell(1, 0), Settings::new().set_row_span(5));
+
+        assert_eq!(
+            grid.to_string(),
+            Grid::from_fn(2, 2, |row, column| row * 2 + column).to_string()
+        );
+    }
+
+    #[test]
+    fn grid_row_span_settings_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Cell(0, 1), Settings::new().set_row_span(2));
+
+        assert_eq!(grid.get_cell_settings(0, 1).get_row_span(), Some(2));
+        assert_eq!(grid.get_cell_settings(0, 0).get_row_span(), Some(1));
+    }
+
+    #[test]
+    fn grid_render_lines_test() {
+        let mut grid = Grid::from_fn(3, 2, |row, column| row * 2 + column);
+        grid.set(Entity::Cell(1, 0), Settings::new().text("a\nb").set_span(2));
+        grid.set(
+            Entity::Cell(2, 0),
+            Settings::new().text("c").set_row_span(1),
+        );
+
+        let mut buffer = String::from("garbage");
+        let mut lines = Vec::new();
+        grid.render_lines(&mut buffer, |line| lines.push(line.to_owned()));
+
+        assert_eq!(lines, grid.to_string().lines().collect::<Vec<_>>());
+        assert!(buffer.is_empty());
+
+        let mut count = 0;
+        Grid::new(0, 0).render_lines(&mut buffer, |_| count += 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn grid_render_lines_trim_test() {
+        let mut grid = Grid::new(2, 1);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+        grid.set(Entity::Cell(1, 0), Settings::new().text("abc\nd"));
+        for row in 0..2 {
+            grid.get_border_mut(row).empty();
+        }
+        grid.set_trim_trailing_whitespace(true);
+
+        let mut lines = Vec::new();
+        grid.render_lines(&mut String::new(), |line| lines.push(line.to_owned()));
+
+        assert_eq!(lines, ["a", "abc", "d"]);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn grid_parallel_measure_test() {
+        let mut grid = Grid::from_fn(5_000, 3, |row, column| "x".repeat((row * 7 + column) % 13));
+        grid.set(Entity::Cell(42, 1), Settings::new().text("a\nb\nc"));
+
+        let cells = grid.styled_cells(grid.count_rows(), grid.count_columns());
+        let sizes = cells.iter().map(|row| row_size(row)).collect::<Vec<_>>();
+        assert_eq!(cells_size(&cells), sizes);
+
+        let measurement = grid.measure();
+        assert_eq!(measurement.column_widths(), [12, 12, 12]);
+        assert_eq!(measurement.row_heights()[42], 3);
+    }
+
+    #[test]
+    fn grid_build_cells_test() {
+        let mut grid = Grid::from_fn(3, 3, |row, column| row * 3 + column);
+        grid.set(Entity::Global, Settings::new().indent(1, 0, 0, 0));
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("spanned").set_span(2),
+        );
+        grid.set(
+            Entity::Cell(1, 2),
+            Settings::new().text("a").set_row_span(2),
+        );
+        grid.set(
+            Entity::Cell(2, 0),
+            Settings::new().nested(Grid::from_fn(1, 1, |_, _| "n")),
+        );
+
+        assert_eq!(
+            grid.build_cells(),
+            [
+                ["spanned", "", " 2"],
+                [" 3  ", " 4", "a \n  \n  \n  \n  "],
+                ["+--+\n|n |\n+--+", " 7\n  \n  ", ""],
+            ]
+        );
+
+        assert_eq!(
+            grid.to_string(),
+            "+-------+--+\n\
+             |spanned| 2|\n\
+             +----+--+--+\n\
+             | 3  | 4|a |\n\
+             +----+--+  |\n\
+             |+--+| 7|  |\n\
+             ||n ||  |  |\n\
+             |+--+|  |  |\n\
+             +----+--+--+\n"
+        );
+        assert!(Grid::new(0, 2).build_cells().is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+use papergrid::{AlignmentHorizontal, CompactGrid, CompactLine, CompactStyle};
+
+#[test]
+fn compact_render() {
+    let grid = CompactGrid::new([["0-0", "0-1"], ["1-0", "1-1"]]);
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|0-0|0-1|\n",
+        "+---+---+\n",
+        "|1-0|1-1|\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn compact_render_multiline() {
+    let grid = CompactGrid::new([["a\nb\nc", "1"], ["2", "34"]])
+        .padding(1, 0)
+        .alignment(AlignmentHorizontal::Center);
+
+    let expected = concat!(
+        "+--+---+\n",
+        "| a| 1 |\n",
+        "| b|   |\n",
+        "| c|   |\n",
+        "+--+---+\n",
+        "| 2| 34|\n",
+        "+--+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn compact_render_custom_style() {
+    const STYLE: CompactStyle = CompactStyle::blank()
+        .vertical(Some('│'))
+        .horizontal(Some(CompactLine::new('─', '┼', '├', '┤')));
+
+    let grid = CompactGrid::new([["id", "name"], ["1", "Grodno"], ["2", "Минск"]]).style(STYLE);
+
+    let expected = concat!(
+        "id│name  \n",
+        "──┼──────\n",
+        "1 │Grodno\n",
+        "──┼──────\n",
+        "2 │Минск \n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn compact_render_empty() {
+    let grid: CompactGrid<'_, 0, 3> = CompactGrid::new([]);
+    assert_eq!(grid.to_string(), "");
+
+    let grid = CompactGrid::new([[""]]);
+    assert_eq!(grid.to_string(), "++\n||\n++\n");
+}
+
+#[test]
+fn compact_set_cell() {
+    let mut grid = CompactGrid::new([["a", "b"]]);
+    grid.set_cell(0, 1, "long");
+
+    assert_eq!(grid.get_cell(0, 1), Some("long"));
+    assert_eq!(grid.get_cell(1, 0), None);
+    assert_eq!(grid.column_widths(), [1, 4]);
+    assert_eq!(grid.to_string(), "+-+----+\n|a|long|\n+-+----+\n");
+}
+
+#[test]
+fn compact_render_justify() {
+    let grid = CompactGrid::new([["a b c", "x"], ["long word", "y"]])
+        .padding(1, 1)
+        .alignment(AlignmentHorizontal::Justify);
+
+    let expected = concat!(
+        "+-----------+---+\n",
+        "| a   b   c | x |\n",
+        "+-----------+---+\n",
+        "| long word | y |\n",
+        "+-----------+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
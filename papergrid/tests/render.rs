@@ -84,8 +84,7 @@ fn render_multilane_alignment() {
             .alignment(AlignmentHorizontal::Right),
     );
 
-    let expected = concat!(
-        "+----------------------------------------+---------+\n\
+    let expected = "+----------------------------------------+---------+\n\
          |                  left                  |right one|\n\
          |                  cell                  |         |\n\
          +----------------------------------------+---------+\n\
@@ -95,8 +94,7 @@ fn render_multilane_alignment() {
          |                                        |        a|\n\
          |                                        |     long|\n\
          |                                        |   string|\n\
-         +----------------------------------------+---------+\n"
-    );
+         +----------------------------------------+---------+\n";
 
     let g = grid.to_string();
     assert_eq!(expected, g);
@@ -125,8 +123,7 @@ fn render_multilane_vertical_alignment() {
             .alignment(AlignmentHorizontal::Right),
     );
 
-    let expected = concat!(
-        "+----------------------------------------+---------+\n\
+    let expected = "+----------------------------------------+---------+\n\
          |                  left                  |right one|\n\
          |                  cell                  |         |\n\
          +----------------------------------------+---------+\n\
@@ -136,8 +133,7 @@ fn render_multilane_vertical_alignment() {
          |                                        |        a|\n\
          |                                        |     long|\n\
          |                                        |   string|\n\
-         +----------------------------------------+---------+\n"
-    );
+         +----------------------------------------+---------+\n";
 
     let g = grid.to_string();
     assert_eq!(expected, g);
@@ -209,7 +205,7 @@ fn render_row_span() {
     let expected = concat!(
         "+-------+\n",
         "|  0-0  |\n",
-        "+-------+\n",
+        "+---+---+\n",
         "|1-0|1-1|\n",
         "+---+---+\n"
     );
@@ -235,7 +231,7 @@ fn render_miltiline_span() {
         "+-------+\n",
         "|  0-0  |\n",
         "|  0-1  |\n",
-        "+-------+\n",
+        "+---+---+\n",
         "|1-0|1-1|\n",
         "+---+---+\n"
     );
@@ -265,7 +261,7 @@ fn render_row_span_multilane() {
     let expected = concat!(
         "+----------+----+\n",
         "|first line|e.g.|\n",
-        "+----------+----+\n",
+        "+-----+----+----+\n",
         "|0    |1   |2   |\n",
         "+-----+----+----+\n",
         "|0    |1   |2   |\n",
@@ -296,7 +292,7 @@ fn render_row_span_with_horizontal_ident() {
     let expected = concat!(
         "+---------------+\n",
         "|0-0            |\n",
-        "+---------------+\n",
+        "+-----------+---+\n",
         "|    1-0    |1-1|\n",
         "+-----------+---+\n",
         "|2-0        |2-1|\n",
@@ -315,7 +311,7 @@ fn render_row_span_with_odd_length() {
     grid.set(Entity::Cell(1, 0), Settings::new().text("2"));
     grid.set(Entity::Cell(1, 1), Settings::new().text("4"));
 
-    let expected = concat!("+----+\n", "|3   |\n", "+----+\n", "|2 |4|\n", "+--+-+\n",);
+    let expected = concat!("+----+\n", "|3   |\n", "+--+-+\n", "|2 |4|\n", "+--+-+\n",);
 
     assert_eq!(expected, grid.to_string());
 }
@@ -0,0 +1,94 @@
+use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+use papergrid::{Entity, Grid, Settings};
+
+/// Abbreviate shortens words of a cell content so each line fits a given width.
+///
+/// It keeps headers of matrix-like tables with many narrow columns compact.
+/// Words of a line are cut to the same length and a cut word ends with a dot,
+/// if even initials don't fit the line is truncated.
+/// Line breaks of a content are kept and each line is abbreviated on its own.
+///
+/// A header can also be rendered vertically, one char per line, by [crate::Rotate::Transpose].
+///
+/// ```rust
+/// use tabled::{Abbreviate, Head, Modify, Style, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Report {
+///     #[header("Net Income")]
+///     income: i32,
+///     #[header("Operating Expenses")]
+///     expenses: i32,
+/// }
+///
+/// let table = Table::new([Report { income: 10, expenses: 7 }])
+///     .with(Modify::new(Head).with(Abbreviate(6)))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " Ne.In. | Op.Ex. \n",
+///         "--------+--------\n",
+///         "   10   |   7    \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Abbreviate(pub usize);
+
+impl CellOption for Abbreviate {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if grid.is_cell_raw(row, column) {
+            return;
+        }
+
+        let content = grid
+            .get_cell_content(row, column)
+            .lines()
+            .map(|line| abbreviate(line, self.0))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+fn abbreviate(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_owned();
+    }
+
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    let longest = words
+        .iter()
+        .map(|word| word.chars().count())
+        .max()
+        .unwrap_or(0);
+    (1..longest)
+        .rev()
+        .map(|length| cut_words(&words, length))
+        .find(|text| text.chars().count() <= width)
+        .unwrap_or_else(|| cut_words(&words, 1).chars().take(width).collect())
+}
+
+// a cut word ends with a dot, so it doesn't need a space after it
+fn cut_words(words: &[&str], length: usize) -> String {
+    let mut text = String::new();
+    for word in words {
+        if !text.is_empty() && !text.ends_with('.') {
+            text.push(' ');
+        }
+
+        if word.chars().count() > length {
+            text.extend(word.chars().take(length));
+            text.push('.');
+        } else {
+            text.push_str(word);
+        }
+    }
+
+    text
+}
@@ -11,6 +11,7 @@ use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity, Grid, Settings};
 ///     let table = Table::new(&data).with(Modify::new(Row(..1)).with(Alignment::center_horizontal()));
 /// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Alignment {
     Horizontal(AlignmentHorizontal),
     Vertical(AlignmentVertical),
@@ -47,6 +48,32 @@ impl Alignment {
         Self::horizontal(AlignmentHorizontal::Center)
     }
 
+    /// Justify constructs a horizontal alignment to [AlignmentHorizontal::Justify]
+    ///
+    /// Words of a content are spread over the whole width of a cell,
+    /// the last line of a multiline content is aligned to the left.
+    ///
+    /// ```rust
+    /// use tabled::{Alignment, Full, Modify, Style, Table};
+    ///
+    /// let table = Table::new(["Tabled renders tables", "of structs"])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Full).with(Alignment::justify()));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "&str                 \n",
+    ///         "---------------------\n",
+    ///         "Tabled renders tables\n",
+    ///         "of            structs\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn justify() -> Self {
+        Self::horizontal(AlignmentHorizontal::Justify)
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::Horizontal(alignment)
@@ -68,3 +95,36 @@ impl CellOption for Alignment {
         grid.set(Entity::Cell(row, column), setting)
     }
 }
+
+/// Fill sets a character which fills a space left by an alignment of a cell content.
+///
+/// It's put only between a content and an indent, the indent stays blank.
+/// It can be used for leader lines or for padding numbers with zeros.
+///
+/// ```rust
+/// use tabled::{Alignment, Column, Fill, Modify, Style, Table};
+///
+/// let table = Table::new([("Tea", 3), ("Cinnamon bun", 105)])
+///     .with(Style::noborder())
+///     .with(Modify::new(Column(..1)).with(Alignment::left()).with(Fill('.')))
+///     .with(Modify::new(Column(1..)).with(Alignment::right()).with(Fill('0')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "&str........ i32\n",
+///         "Tea......... 003\n",
+///         "Cinnamon bun 105\n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Fill(pub char);
+
+impl CellOption for Fill {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        // other settings of the cell are kept, so a fill can be added to an aligned cell
+        let settings = grid.get_cell_settings(row, column).fill(self.0);
+        grid.set(Entity::Cell(row, column), settings)
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{new_grid, Table};
+use papergrid::{Entity, Settings};
+use std::fmt::Display;
+
+/// Builder creates a [Table] from a dynamic set of rows.
+///
+/// Rows may have a different amount of cells,
+/// missing cells are filled by a default text.
+///
+/// ```rust
+/// use tabled::{Builder, Style};
+///
+/// let table = Builder::new()
+///     .header(["name", "year"])
+///     .add_row(["Rust", "2010"])
+///     .add_row(["Go"])
+///     .build()
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " name | year \n",
+///         "------+------\n",
+///         " Rust | 2010 \n",
+///         "  Go  |      \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    default_text: String,
+}
+
+impl Builder {
+    /// New creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Header sets a first row of a table.
+    pub fn header<H, T>(mut self, header: H) -> Self
+    where
+        H: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.header = Some(header.into_iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Add_row adds a row to a table.
+    pub fn add_row<R, T>(mut self, row: R) -> Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.rows
+            .push(row.into_iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Set_default_text sets a text which is used for missing cells of short rows.
+    ///
+    /// By default it's an empty string.
+    pub fn set_default_text<T: Into<String>>(mut self, text: T) -> Self {
+        self.default_text = text.into();
+        self
+    }
+
+    /// Build creates a [Table].
+    ///
+    /// Contents are kept as they are, see [Builder::build_checked] to replace control characters.
+    pub fn build(self) -> Table {
+        self.build_grid(false).0
+    }
+
+    /// Build_checked creates a [Table] and a [BuildReport]
+    /// which describes how the input was normalized.
+    ///
+    /// Control characters which break a layout are replaced by [char::REPLACEMENT_CHARACTER],
+    /// while new lines, tabs and escape characters of colors are kept.
+    ///
+    /// ```rust
+    /// use tabled::{Builder, BuildReport};
+    ///
+    /// let (_, report) = Builder::new()
+    ///     .header(["name", "year"])
+    ///     .add_row(["Rust\u{7}", "2010"])
+    ///     .add_row(["Go"])
+    ///     .build_checked();
+    ///
+    /// assert_eq!(
+    ///     report,
+    ///     BuildReport {
+    ///         count_rows: 3,
+    ///         count_columns: 2,
+    ///         padded_rows: 1,
+    ///         replaced_control_chars: 1,
+    ///     }
+    /// );
+    /// ```
+    pub fn build_checked(self) -> (Table, BuildReport) {
+        self.build_grid(true)
+    }
+
+    fn build_grid(self, replace_controls: bool) -> (Table, BuildReport) {
+        let rows: Vec<_> = self.header.into_iter().chain(self.rows).collect();
+        let count_rows = rows.len();
+        let count_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut report = BuildReport {
+            count_rows,
+            count_columns,
+            ..BuildReport::default()
+        };
+
+        let mut grid = new_grid(count_rows, count_columns);
+        for (row, cells) in rows.into_iter().enumerate() {
+            if cells.len() < count_columns {
+                report.padded_rows += 1;
+            }
+
+            for column in 0..count_columns {
+                let text = match cells.get(column) {
+                    Some(text) if replace_controls => {
+                        let (text, replaced) = replace_control_chars(text);
+                        report.replaced_control_chars += replaced;
+                        text
+                    }
+                    Some(text) => text.clone(),
+                    None => self.default_text.clone(),
+                };
+
+                grid.set(Entity::Cell(row, column), Settings::new().text(text));
+            }
+        }
+
+        (Table { grid }, report)
+    }
+}
+
+/// BuildReport describes a [Table] built by [Builder::build_checked]
+/// and normalizations which were applied to an input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildReport {
+    /// An amount of rows including a header.
+    pub count_rows: usize,
+    /// An amount of columns, which is a length of the longest row.
+    pub count_columns: usize,
+    /// An amount of rows which were padded by a default text.
+    pub padded_rows: usize,
+    /// An amount of control characters which were replaced by [char::REPLACEMENT_CHARACTER].
+    pub replaced_control_chars: usize,
+}
+
+// control characters break a layout so they are replaced,
+// a new line, a tab (which is expanded by a grid) and an escape character (used by colors) are kept
+fn replace_control_chars(text: &str) -> (String, usize) {
+    let text = text.replace("\r\n", "\n");
+    let mut replaced = 0;
+    let text = text
+        .chars()
+        .map(|c| {
+            if c.is_control() && !matches!(c, '\n' | '\t' | '\u{1b}') {
+                replaced += 1;
+                char::REPLACEMENT_CHARACTER
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    (text, replaced)
+}
@@ -0,0 +1,119 @@
+use crate::{Table, TableOption};
+#[cfg(feature = "color")]
+use papergrid::Color;
+use papergrid::{Entity, Grid, Settings};
+
+/// Changes marks cells which contents differ from the same cells of a previous [Table].
+///
+/// It's meant for tables which are rendered again and again with fresh data,
+/// like metrics in a watch mode.
+/// Cells which are out of the previous table are considered changed.
+///
+/// A changed cell is prefixed by a marker, which is `*` by default,
+/// or its content is replaced by a custom formatter of an old and a new content.
+/// Under the `color` feature a changed cell can be colored as well.
+///
+/// ```rust
+/// use tabled::{Changes, Style, Table};
+///
+/// let before = Table::new([("api", 3), ("db", 1)]);
+/// let after = Table::new([("api", 4), ("db", 1)]);
+///
+/// let table = after
+///     .with(Changes::new(&before).format(|old, new| format!("{} → {}", old, new)))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str |  i32  \n",
+///         "------+-------\n",
+///         " api  | 3 → 4 \n",
+///         "  db  |   1   \n",
+///     )
+/// );
+/// ```
+pub struct Changes {
+    previous: Vec<Vec<String>>,
+    marker: String,
+    format: Option<Formatter>,
+    #[cfg(feature = "color")]
+    color: Option<Color>,
+}
+
+type Formatter = Box<dyn Fn(&str, &str) -> String>;
+
+impl Changes {
+    /// New creates a comparison with contents of a previous table.
+    pub fn new(previous: &Table) -> Self {
+        let grid = &previous.grid;
+        let previous = (0..grid.count_rows())
+            .map(|row| grid.row(row).map(str::to_owned).collect())
+            .collect();
+
+        Self {
+            previous,
+            marker: String::from("*"),
+            format: None,
+            #[cfg(feature = "color")]
+            color: None,
+        }
+    }
+
+    /// Marker sets a text which is put before a content of a changed cell.
+    pub fn marker<S: Into<String>>(mut self, marker: S) -> Self {
+        self.marker = marker.into();
+        self
+    }
+
+    /// Format sets a function which makes a content of a changed cell from an old and a new content,
+    /// it's used instead of a marker.
+    ///
+    /// An old content of a cell which is out of the previous table is empty.
+    pub fn format<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str, &str) -> String + 'static,
+    {
+        self.format = Some(Box::new(f));
+        self
+    }
+
+    /// Color sets a color of changed cells.
+    #[cfg(feature = "color")]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl TableOption for Changes {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                let old = self
+                    .previous
+                    .get(row)
+                    .and_then(|cells| cells.get(column))
+                    .map(String::as_str);
+                let new = grid.get_cell_content(row, column);
+                if old == Some(new) {
+                    continue;
+                }
+
+                let content = match &self.format {
+                    Some(format) => format(old.unwrap_or(""), new),
+                    None => format!("{}{}", self.marker, new),
+                };
+                grid.set(Entity::Cell(row, column), Settings::new().text(content));
+
+                #[cfg(feature = "color")]
+                if let Some(color) = &self.color {
+                    grid.set(
+                        Entity::Cell(row, column),
+                        Settings::new().color(color.clone()),
+                    );
+                }
+            }
+        }
+    }
+}
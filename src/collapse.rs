@@ -0,0 +1,97 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+
+/// Collapse removes rows and columns of a [Table] where every cell is empty,
+/// which is handy for tables built from sparse data.
+///
+/// A cell which consists only of whitespaces is considered empty.
+/// The first row is considered a header so it's not checked by default,
+/// a column which has only a name is removed and the header itself is never removed.
+/// Use [Collapse::with_header] to check it like any other row.
+///
+/// ```rust
+/// use tabled::{Collapse, Style, Table};
+///
+/// let data = [["1", "", "Debian"], ["", "", ""], ["3", "", "Arch"]];
+/// let table = Table::new(&data)
+///     .with(Collapse::all())
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " 0 |   2    \n",
+///         "---+--------\n",
+///         " 1 | Debian \n",
+///         " 3 |  Arch  \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Collapse {
+    rows: bool,
+    columns: bool,
+    header: bool,
+}
+
+impl Collapse {
+    /// Rows creates a [Collapse] which removes empty rows.
+    pub fn rows() -> Self {
+        Self {
+            rows: true,
+            columns: false,
+            header: false,
+        }
+    }
+
+    /// Columns creates a [Collapse] which removes empty columns.
+    pub fn columns() -> Self {
+        Self {
+            rows: false,
+            columns: true,
+            header: false,
+        }
+    }
+
+    /// All creates a [Collapse] which removes both empty rows and empty columns.
+    pub fn all() -> Self {
+        Self {
+            rows: true,
+            columns: true,
+            header: false,
+        }
+    }
+
+    /// With_header makes the first row be checked like any other one.
+    pub fn with_header(mut self) -> Self {
+        self.header = true;
+        self
+    }
+}
+
+impl TableOption for Collapse {
+    fn change(&mut self, grid: &mut Grid) {
+        let first_row = if self.header { 0 } else { 1 };
+        let is_empty =
+            |grid: &Grid, row, column| grid.get_cell_content(row, column).trim().is_empty();
+
+        if self.rows {
+            for row in (first_row..grid.count_rows()).rev() {
+                if (0..grid.count_columns()).all(|column| is_empty(grid, row, column)) {
+                    grid.remove_row(row);
+                }
+            }
+        }
+
+        // a table without data is kept as it is
+        if self.columns && first_row < grid.count_rows() {
+            for column in (0..grid.count_columns()).rev() {
+                if (first_row..grid.count_rows()).all(|row| is_empty(grid, row, column)) {
+                    grid.remove_column(column);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{CellOption, TableOption};
+use papergrid::{Entity, Grid, Settings};
+
+pub use papergrid::Color;
+
+/// Color can be used to set a color of a cell content.
+///
+/// Colors are not counted in a width of a cell.
+///
+/// ```rust,no_run
+///   # use tabled::{Color, Head, Modify, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data).with(Modify::new(Head).with(Color::bold()));
+/// ```
+impl CellOption for Color {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        grid.set(
+            Entity::Cell(row, column),
+            Settings::new().color(self.clone()),
+        )
+    }
+}
+
+/// BorderColor sets a color of all border characters on a [Table].
+///
+/// The color is kept when a [crate::Style] is changed.
+///
+/// ```rust,no_run
+///   # use tabled::{BorderColor, Color, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data).with(BorderColor(Color::red()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BorderColor(pub Color);
+
+impl TableOption for BorderColor {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            grid.get_border_mut(row).color(self.0.clone());
+        }
+    }
+}
+
+/// RowColoring colors backgrounds of data rows of a [Table] in turn by a list of colors.
+///
+/// A background takes a whole cell including its padding,
+/// a [Color] of a cell content is drawn over it.
+/// A header row is skipped.
+///
+/// ```rust,no_run
+///   # use tabled::{Color, RowColoring, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data).with(RowColoring::alternating(Color::on_black(), Color::on_blue()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RowColoring(Vec<Color>);
+
+impl RowColoring {
+    /// Alternating colors odd data rows by `a` and even ones by `b`.
+    pub fn alternating(a: Color, b: Color) -> Self {
+        Self(vec![a, b])
+    }
+}
+
+impl TableOption for RowColoring {
+    fn change(&mut self, grid: &mut Grid) {
+        for (row, color) in (1..grid.count_rows()).zip(self.0.iter().cycle()) {
+            grid.set(Entity::Row(row), Settings::new().background(color.clone()));
+        }
+    }
+}
@@ -0,0 +1,160 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::{Entity, Grid, Settings};
+
+/// Concat concatenates tables along a vertical or horizontal axis.
+///
+/// If tables have a different size a smaller one is padded with empty cells,
+/// a content of such cells can be set by [Concat::default_cell].
+///
+/// # Example
+///
+/// ```rust
+/// use tabled::{Concat, Style, Table};
+///
+/// let table1 = Table::new(&[(0, "Grodno")]);
+/// let table2 = Table::new(&[(true,)]);
+///
+/// let table = table1.with(Concat::horizontal(table2)).with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " i32 |  &str  | bool \n",
+///         "-----+--------+------\n",
+///         "  0  | Grodno | true \n",
+///     )
+/// );
+/// ```
+pub struct Concat {
+    table: Grid<'static>,
+    mode: ConcatMode,
+    default_cell: String,
+}
+
+#[derive(Debug)]
+enum ConcatMode {
+    Vertical,
+    Horizontal,
+}
+
+impl Concat {
+    /// Vertical concatenates tables so a given table is placed below.
+    pub fn vertical(table: Table) -> Self {
+        Self {
+            table: table.grid,
+            mode: ConcatMode::Vertical,
+            default_cell: String::new(),
+        }
+    }
+
+    /// Horizontal concatenates tables so a given table is placed on the right.
+    pub fn horizontal(table: Table) -> Self {
+        Self {
+            table: table.grid,
+            mode: ConcatMode::Horizontal,
+            default_cell: String::new(),
+        }
+    }
+
+    /// Default_cell sets a content of cells which are added to pad a smaller table.
+    ///
+    /// ```rust
+    /// use tabled::{Concat, Style, Table};
+    ///
+    /// let table1 = Table::new(&[(0, "Grodno"), (1, "Minsk")]);
+    /// let table2 = Table::new(&[(true,)]);
+    ///
+    /// let table = table1
+    ///     .with(Concat::horizontal(table2).default_cell("-"))
+    ///     .with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " i32 |  &str  | bool \n",
+    ///         "-----+--------+------\n",
+    ///         "  0  | Grodno | true \n",
+    ///         "  1  | Minsk  |  -   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn default_cell<S: Into<String>>(mut self, text: S) -> Self {
+        self.default_cell = text.into();
+        self
+    }
+}
+
+impl TableOption for Concat {
+    fn change(&mut self, grid: &mut Grid) {
+        let (row_shift, column_shift) = match self.mode {
+            ConcatMode::Vertical => (grid.count_rows(), 0),
+            ConcatMode::Horizontal => (0, grid.count_columns()),
+        };
+
+        let count_rows = std::cmp::max(grid.count_rows(), row_shift + self.table.count_rows());
+        let count_columns = std::cmp::max(
+            grid.count_columns(),
+            column_shift + self.table.count_columns(),
+        );
+
+        let mut new = Grid::new(count_rows, count_columns);
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let settings = match self.mode {
+                    _ if row < grid.count_rows() && column < grid.count_columns() => {
+                        grid.get_cell_settings(row, column)
+                    }
+                    _ if row >= row_shift
+                        && column >= column_shift
+                        && row - row_shift < self.table.count_rows()
+                        && column - column_shift < self.table.count_columns() =>
+                    {
+                        self.table
+                            .get_cell_settings(row - row_shift, column - column_shift)
+                    }
+                    // a padding cell looks like the closest cell of a table it pads
+                    ConcatMode::Horizontal if column < column_shift => padding_settings(
+                        grid,
+                        grid.count_rows().saturating_sub(1),
+                        column,
+                        &self.default_cell,
+                    ),
+                    ConcatMode::Horizontal => padding_settings(
+                        &self.table,
+                        self.table.count_rows().saturating_sub(1),
+                        column - column_shift,
+                        &self.default_cell,
+                    ),
+                    ConcatMode::Vertical if row < row_shift => padding_settings(
+                        grid,
+                        row,
+                        grid.count_columns().saturating_sub(1),
+                        &self.default_cell,
+                    ),
+                    ConcatMode::Vertical => padding_settings(
+                        &self.table,
+                        row - row_shift,
+                        self.table.count_columns().saturating_sub(1),
+                        &self.default_cell,
+                    ),
+                };
+
+                new.set(Entity::Cell(row, column), settings);
+            }
+        }
+
+        *grid = new;
+    }
+}
+
+fn padding_settings(grid: &Grid, row: usize, column: usize, text: &str) -> Settings {
+    if grid.count_rows() == 0 || grid.count_columns() == 0 {
+        return Settings::new().text(text.to_owned());
+    }
+
+    grid.get_cell_settings(row, column)
+        .text(text.to_owned())
+        .set_span(1)
+}
@@ -0,0 +1,252 @@
+use crate::{CellOption, Format};
+use papergrid::{Entity, Grid, Settings};
+
+impl Format<fn(&str) -> String> {
+    /// Datetime formats RFC3339 timestamps by a `pattern` in a given time zone.
+    ///
+    /// Unix epoch seconds are formatted only if they're turned on by [FormatDateTime::epoch_seconds],
+    /// so plain numbers such as years are not taken for timestamps.
+    /// A content which is not a timestamp or which is out of a representable range is left unchanged.
+    ///
+    /// The pattern supports `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%z` and `%%`.
+    ///
+    /// ```
+    /// use tabled::{Table, Format, Full, Modify, Style, TimeZone};
+    ///
+    /// let data = ["2021-09-06T10:00:00Z", "1630922400", "unknown"];
+    ///
+    /// let table = Table::new(&data)
+    ///                .with(Modify::new(Full).with(Format::datetime("%d.%m.%Y %H:%M", TimeZone::offset(3, 0)).epoch_seconds()))
+    ///                .with(Style::psql())
+    ///                .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "       &str       \n",
+    ///         "------------------\n",
+    ///         " 06.09.2021 13:00 \n",
+    ///         " 06.09.2021 13:00 \n",
+    ///         "     unknown      \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn datetime<S: Into<String>>(pattern: S, tz: TimeZone) -> FormatDateTime {
+        FormatDateTime {
+            pattern: pattern.into(),
+            tz,
+            epoch: false,
+        }
+    }
+}
+
+/// FormatDateTime renders timestamps in a given time zone.
+///
+/// It's constructed by [Format::datetime].
+#[derive(Debug)]
+pub struct FormatDateTime {
+    pattern: String,
+    tz: TimeZone,
+    epoch: bool,
+}
+
+impl FormatDateTime {
+    /// Epoch_seconds makes contents which are integers be formatted as unix epoch seconds.
+    pub fn epoch_seconds(mut self) -> Self {
+        self.epoch = true;
+        self
+    }
+}
+
+impl CellOption for FormatDateTime {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        if let Some(timestamp) = parse_timestamp(content.trim(), self.epoch) {
+            if let Some(content) = format_timestamp(timestamp, &self.pattern, &self.tz) {
+                grid.set(Entity::Cell(row, column), Settings::new().text(content))
+            }
+        }
+    }
+}
+
+/// TimeZone is a fixed offset from UTC which is used to render timestamps.
+///
+/// A local time zone of a system and daylight saving time are not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeZone {
+    // an offset in seconds
+    offset: i64,
+}
+
+impl TimeZone {
+    /// Utc constructs a UTC time zone.
+    pub fn utc() -> Self {
+        Self { offset: 0 }
+    }
+
+    /// Offset constructs a time zone which is `hours` and `minutes` ahead of UTC.
+    ///
+    /// Use negative values for zones which are behind UTC.
+    pub fn offset(hours: i32, minutes: i32) -> Self {
+        Self {
+            offset: hours as i64 * 3600 + minutes as i64 * 60,
+        }
+    }
+}
+
+// returns unix time in seconds
+fn parse_timestamp(s: &str, epoch: bool) -> Option<i64> {
+    let is_epoch = epoch
+        && !s.is_empty()
+        && s.strip_prefix('-')
+            .unwrap_or(s)
+            .chars()
+            .all(|c| c.is_ascii_digit());
+    if is_epoch {
+        return s.parse().ok();
+    }
+
+    parse_rfc3339(s)
+}
+
+// YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)
+fn parse_rfc3339(s: &str) -> Option<i64> {
+    let number = |from: usize, to: usize| -> Option<i64> {
+        let part = s.get(from..to)?;
+        if part.chars().all(|c| c.is_ascii_digit()) {
+            part.parse().ok()
+        } else {
+            None
+        }
+    };
+
+    let bytes = s.as_bytes();
+    let separators = [(4, b'-'), (7, b'-'), (13, b':'), (16, b':')];
+    if bytes.len() < 20
+        || separators.iter().any(|&(i, c)| bytes[i] != c)
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+    {
+        return None;
+    }
+
+    let (year, month, day) = (number(0, 4)?, number(5, 7)?, number(8, 10)?);
+    let (hour, minute, second) = (number(11, 13)?, number(14, 16)?, number(17, 19)?);
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.chars().take_while(char::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+
+        rest = &fraction[digits..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes().first()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let rest = &rest[1..];
+            if rest.len() != 5 || rest.as_bytes()[2] != b':' {
+                return None;
+            }
+
+            let hours: i64 = rest[..2].parse().ok()?;
+            let minutes: i64 = rest[3..].parse().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    let days = days_from_civil(year, month, day);
+    Some(days * 86400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+fn format_timestamp(timestamp: i64, pattern: &str, tz: &TimeZone) -> Option<String> {
+    let local = timestamp.checked_add(tz.offset)?;
+    let days = local.div_euclid(86400);
+    let seconds = local.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+
+    let mut out = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('Y') => out.push_str(&format!("{:04}", year)),
+            Some('m') => out.push_str(&format!("{:02}", month)),
+            Some('d') => out.push_str(&format!("{:02}", day)),
+            Some('H') => out.push_str(&format!("{:02}", seconds / 3600)),
+            Some('M') => out.push_str(&format!("{:02}", seconds % 3600 / 60)),
+            Some('S') => out.push_str(&format!("{:02}", seconds % 60)),
+            Some('z') => {
+                let sign = if tz.offset < 0 { '-' } else { '+' };
+                let offset = tz.offset.abs();
+                out.push_str(&format!(
+                    "{}{:02}:{:02}",
+                    sign,
+                    offset / 3600,
+                    offset % 3600 / 60
+                ));
+            }
+            Some('%') => out.push('%'),
+            Some(c) => {
+                out.push('%');
+                out.push(c);
+            }
+            None => out.push('%'),
+        }
+    }
+
+    Some(out)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// an amount of days since 1970-01-01,
+// see http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
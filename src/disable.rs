@@ -2,14 +2,16 @@
 use crate::Table;
 use crate::{bounds_to_usize, TableOption};
 use papergrid::Grid;
-use std::ops::RangeBounds;
+use std::{cmp::min, ops::RangeBounds};
 
 /// Disable removes particular rows/columns from a [Table].
 ///
+/// A range which goes beyond the table is truncated to the table size.
+///
 /// ```rust,no_run
 ///   # use tabled::{Disable, Table};
 ///   # let data: Vec<&'static str> = Vec::new();
-///     let table = Table::new(&data).with(Disable::Row(..1));
+///     let table = Table::new(&data).with(Disable::row(..1));
 /// ```
 #[derive(Debug)]
 pub enum Disable<R: RangeBounds<usize>> {
@@ -21,12 +23,25 @@ pub enum Disable<R: RangeBounds<usize>> {
     Row(R),
 }
 
+impl<R: RangeBounds<usize>> Disable<R> {
+    /// Row constructs a [Disable] which removes rows located by a range.
+    pub fn row(range: R) -> Self {
+        Self::Row(range)
+    }
+
+    /// Column constructs a [Disable] which removes columns located by a range.
+    pub fn column(range: R) -> Self {
+        Self::Column(range)
+    }
+}
+
 impl<R: RangeBounds<usize>> TableOption for Disable<R> {
     fn change(&mut self, grid: &mut Grid) {
         match self {
             Self::Column(range) => {
                 let (x, y) =
                     bounds_to_usize(range.start_bound(), range.end_bound(), grid.count_columns());
+                let y = min(y, grid.count_columns());
                 for (shifted, i) in (x..y).enumerate() {
                     grid.remove_column(i - shifted);
                 }
@@ -34,6 +49,7 @@ impl<R: RangeBounds<usize>> TableOption for Disable<R> {
             Self::Row(range) => {
                 let (x, y) =
                     bounds_to_usize(range.start_bound(), range.end_bound(), grid.count_rows());
+                let y = min(y, grid.count_rows());
 
                 // It's kind of a bad design that we must controll shift.
                 // It basically unveils an implementation...
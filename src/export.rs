@@ -0,0 +1,169 @@
+use crate::{Semantic, Table};
+use std::cmp::min;
+
+impl Table {
+    /// To_markdown renders the table as a Markdown table where the first row is a header.
+    ///
+    /// Cell contents are taken after all options are applied,
+    /// so contents which were wrapped or truncated by [crate::Wrap], [crate::Truncate]
+    /// or [crate::MaxWidth] are exported the same way.
+    /// A line break in a cell is exported as `<br>`.
+    /// A [Semantic] of a cell is exported as emphasis markers.
+    ///
+    /// Markdown has no spans, so a spanned cell is exported in its first column
+    /// and cells covered by it are exported empty.
+    ///
+    /// ```rust
+    /// use tabled::{Table, Modify, Full, Wrap};
+    ///
+    /// let table = Table::new(["Hello World"]).with(Modify::new(Full).with(Wrap(5)));
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     concat!(
+    ///         "| &str |\n",
+    ///         "|---|\n",
+    ///         "| Hello<br> Worl<br>d |\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let rows = self.rows(|text| text.replace('|', "\\|"));
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|cell| match cell {
+                        Some(cell) => match cell.class.as_deref().and_then(Semantic::from_class) {
+                            Some(semantic) => semantic.markdown(&cell.text),
+                            None => cell.text,
+                        },
+                        None => String::new(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut out = String::new();
+        for (i, row) in rows.iter().enumerate() {
+            out.push_str(&format!("| {} |\n", row.join(" | ")));
+
+            if i == 0 {
+                out.push_str(&format!("|{}|\n", vec!["---"; row.len()].join("|")));
+            }
+        }
+
+        out
+    }
+
+    /// To_html renders the table as an HTML table where the first row is a header.
+    ///
+    /// Cell contents are taken after all options are applied, like in [Table::to_markdown].
+    /// A line break in a cell is exported as `<br>`.
+    /// A class of a cell, like the one set by [Semantic], is exported as a `class` attribute.
+    /// A spanned cell is exported with `colspan` and `rowspan` attributes and cells covered by it are skipped.
+    ///
+    /// ```rust
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["<b>", "a\nb"]);
+    ///
+    /// assert_eq!(
+    ///     table.to_html(),
+    ///     concat!(
+    ///         "<table>\n",
+    ///         "<tr><th>&amp;str</th></tr>\n",
+    ///         "<tr><td>&lt;b&gt;</td></tr>\n",
+    ///         "<tr><td>a<br>b</td></tr>\n",
+    ///         "</table>\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        let rows = self.rows(escape_html);
+
+        let mut out = String::from("<table>\n");
+        for (i, row) in rows.iter().enumerate() {
+            let tag = if i == 0 { "th" } else { "td" };
+
+            out.push_str("<tr>");
+            for cell in row.iter().flatten() {
+                let mut attributes = String::new();
+                if let Some(class) = &cell.class {
+                    attributes.push_str(&format!(
+                        " class=\"{}\"",
+                        escape_html(class).replace('"', "&quot;")
+                    ));
+                }
+                if cell.span > 1 {
+                    attributes.push_str(&format!(" colspan=\"{}\"", cell.span));
+                }
+                if cell.row_span > 1 {
+                    attributes.push_str(&format!(" rowspan=\"{}\"", cell.row_span));
+                }
+
+                out.push_str(&format!(
+                    "<{tag}{}>{}</{tag}>",
+                    attributes,
+                    cell.text,
+                    tag = tag
+                ));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+
+        out
+    }
+
+    // returns escaped cell contents where lines are joined by `<br>` together with their classes and spans,
+    // cells which are covered by a span are `None`
+    fn rows<F: Fn(&str) -> String>(&self, escape: F) -> Vec<Vec<Option<ExportedCell>>> {
+        let count_rows = self.grid.count_rows();
+        let count_columns = self.grid.count_columns();
+
+        let mut rows = vec![vec![None; count_columns]; count_rows];
+        let mut covered = vec![vec![false; count_columns]; count_rows];
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                if covered[row][column] {
+                    continue;
+                }
+
+                let settings = self.grid.get_cell_settings(row, column);
+                let span = self.grid.get_cell_span(row, column);
+                let row_span = min(settings.get_row_span().unwrap_or(1), count_rows - row).max(1);
+                for covered in &mut covered[row..row + row_span] {
+                    for covered in &mut covered[column..column + span] {
+                        *covered = true;
+                    }
+                }
+
+                let text = self.grid.get_cell_content(row, column);
+                let text = text.lines().map(&escape).collect::<Vec<_>>().join("<br>");
+                rows[row][column] = Some(ExportedCell {
+                    text,
+                    class: settings.get_class().map(ToOwned::to_owned),
+                    span,
+                    row_span,
+                });
+            }
+        }
+
+        rows
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ExportedCell {
+    text: String,
+    class: Option<String>,
+    span: usize,
+    row_span: usize,
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
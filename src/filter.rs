@@ -0,0 +1,67 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+
+/// Filter hides rows of a [Table] for which a predicate returns `false`.
+///
+/// A predicate gets contents of a row.
+/// A header row is always kept and kept rows retain their settings.
+///
+/// ```rust
+/// use tabled::{Filter, Style, Table};
+///
+/// let data = [("apples", 3), ("pears", 0), ("plums", 7)];
+///
+/// let table = Table::new(&data)
+///     .with(Filter::rows(|record| record[1] != "0"))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "  &str  | i32 \n",
+///         "--------+-----\n",
+///         " apples |  3  \n",
+///         " plums  |  7  \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Filter<F> {
+    predicate: F,
+}
+
+impl<F> Filter<F>
+where
+    F: FnMut(&[&str]) -> bool,
+{
+    /// Rows creates a filter which keeps rows matching a predicate.
+    pub fn rows(predicate: F) -> Self {
+        Self { predicate }
+    }
+}
+
+impl<F> TableOption for Filter<F>
+where
+    F: FnMut(&[&str]) -> bool,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        if count_rows == 0 {
+            return;
+        }
+
+        let hidden = (1..count_rows)
+            .filter(|&row| {
+                let record = grid.row(row).collect::<Vec<_>>();
+                !(self.predicate)(&record)
+            })
+            .collect::<Vec<_>>();
+
+        // rows are removed in place so row spans of kept rows are shrunk instead of split
+        for &row in hidden.iter().rev() {
+            grid.remove_row(row);
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::CellOption;
+use crate::{CellOption, TableOption};
 use papergrid::{Entity, Grid, Settings};
+use std::{cell::RefCell, fmt::Display, rc::Rc};
 
 /// Formatting of particular cells on a [Grid].
 ///
@@ -42,6 +43,69 @@ impl<F: Fn(&str) -> String> CellOption for Format<F> {
     }
 }
 
+impl Format<fn(&str) -> String> {
+    /// Try_with constructs a [TryFormat] from a formatter which may fail.
+    pub fn try_with<F, E>(f: F) -> TryFormat<F>
+    where
+        F: FnMut(&str) -> Result<String, E>,
+        E: Display,
+    {
+        TryFormat::new(f)
+    }
+
+    /// Diff constructs a [FormatDiff] which combines a content of 2 columns
+    /// into a change description `old → new`.
+    pub fn diff(old_column: usize, new_column: usize) -> FormatDiff {
+        FormatDiff::new(old_column, new_column)
+    }
+
+    /// List constructs a [FormatList] which keeps only first `limit` items of a list
+    /// separated by a `delimiter`.
+    pub fn list<S: Into<String>>(delimiter: S, limit: usize) -> FormatList {
+        FormatList::new(delimiter, limit)
+    }
+
+    /// With_position constructs a formatter which is provided with a content
+    /// and a `(row, column)` position of a cell.
+    ///
+    /// It's handy for a conditional formatting of cells located by an [crate::Object].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, Format, Full, Modify, Style};
+    ///
+    /// let data = vec![("a", 10), ("b", -3)];
+    ///
+    /// let table = Table::new(&data)
+    ///                .with(Modify::new(Full).with(Format::with_position(|s, (row, column)| {
+    ///                    match (row, column) {
+    ///                        (0, _) => s.to_uppercase(),
+    ///                        (_, 1) if s.starts_with('-') => format!("({})", &s[1..]),
+    ///                        _ => s.to_string(),
+    ///                    }
+    ///                })))
+    ///                .with(Style::psql())
+    ///                .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " &STR | I32 \n",
+    ///         "------+-----\n",
+    ///         "  a   | 10  \n",
+    ///         "  b   | (3) \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn with_position<F>(mut f: F) -> FormatWithIndex<impl FnMut(&str, usize, usize) -> String>
+    where
+        F: FnMut(&str, (usize, usize)) -> String,
+    {
+        FormatWithIndex(move |s: &str, row: usize, column: usize| f(s, (row, column)))
+    }
+}
+
 impl<F> CellOption for F
 where
     F: for<'r> FnMut(&'r str) -> String,
@@ -64,7 +128,7 @@ where
 ///     .to_string();
 /// ```
 pub fn multiline<F: 'static + Fn(&str) -> String>(f: F) -> Box<dyn Fn(&str) -> String> {
-    Box::new(move |s: &str| s.lines().map(|s| f(s)).collect::<Vec<_>>().join("\n"))
+    Box::new(move |s: &str| s.lines().map(&f).collect::<Vec<_>>().join("\n"))
 }
 
 /// FormatFrom repeatedly uses first possible element
@@ -155,3 +219,344 @@ impl<F: FnMut(&str, usize, usize) -> String> CellOption for FormatWithIndex<F> {
         grid.set(Entity::Cell(row, column), Settings::new().text(content))
     }
 }
+
+/// FormatDiff sets a content of a cell to a change between 2 columns of the same row.
+///
+/// If values are equal only the new value is shown.
+/// While `color` feature is on an old value is striked through.
+/// A cell is left unchanged if any of the columns is out of the table.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Format, Column, Row, Modify, Disable, Style, Object};
+///
+/// let data = vec![
+///     ("replicas", "1", "3"),
+///     ("image", "nginx", "nginx"),
+/// ];
+///
+/// let table = Table::new(&data)
+///                .with(Modify::new(Column(2..).not(Row(..1))).with(Format::diff(1, 2)))
+///                .with(Disable::column(1..2))
+///                .with(Style::psql())
+///                .to_string();
+///
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "   &str   | &str  \n",
+///         "----------+-------\n",
+///         " replicas | 1 → 3 \n",
+///         "  image   | nginx \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct FormatDiff {
+    old_column: usize,
+    new_column: usize,
+}
+
+impl FormatDiff {
+    /// Constructs a [FormatDiff] from an old and a new columns.
+    pub fn new(old_column: usize, new_column: usize) -> Self {
+        Self {
+            old_column,
+            new_column,
+        }
+    }
+}
+
+impl CellOption for FormatDiff {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let count_columns = grid.count_columns();
+        if self.old_column >= count_columns || self.new_column >= count_columns {
+            return;
+        }
+
+        let old = grid.get_cell_content(row, self.old_column);
+        let new = grid.get_cell_content(row, self.new_column);
+
+        let content = if old == new {
+            new.to_owned()
+        } else {
+            format!("{} → {}", strike(old), new)
+        };
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+/// FormatList collapses a long list in a cell into its first items
+/// followed by an amount of hidden ones, like `a, b +3 more`.
+///
+/// A cell which has no more items than a limit is left unchanged.
+/// Different columns may use different limits.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Format, Column, Row, Modify, Style, Object};
+///
+/// let data = vec![
+///     ("tabled", "cli, table, derive, ascii"),
+///     ("serde", "encoding"),
+/// ];
+///
+/// let table = Table::new(&data)
+///                .with(Modify::new(Column(1..).not(Row(..1))).with(Format::list(", ", 2)))
+///                .with(Style::psql())
+///                .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "  &str  |        &str        \n",
+///         "--------+--------------------\n",
+///         " tabled | cli, table +2 more \n",
+///         " serde  |      encoding      \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct FormatList {
+    delimiter: String,
+    limit: usize,
+}
+
+impl FormatList {
+    /// Constructs a [FormatList] from a delimiter of items and an amount of items to keep.
+    pub fn new<S: Into<String>>(delimiter: S, limit: usize) -> Self {
+        Self {
+            delimiter: delimiter.into(),
+            limit,
+        }
+    }
+}
+
+impl CellOption for FormatList {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        if self.delimiter.is_empty() {
+            return;
+        }
+
+        let items = content.split(self.delimiter.as_str()).collect::<Vec<_>>();
+        if items.len() <= self.limit {
+            return;
+        }
+
+        let hidden = items.len() - self.limit;
+        let content = if self.limit == 0 {
+            format!("+{} more", hidden)
+        } else {
+            let kept = items[..self.limit].join(&self.delimiter);
+            format!("{} +{} more", kept, hidden)
+        };
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+/// TryFormat formats cells by a function which may fail.
+///
+/// A failed cell is rendered according to [OnError]
+/// and an error is collected into a [FormatReport] so it can be read after the table is built.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Format, Row, Modify, Style, OnError};
+///
+/// let data = ["1.5", "abc"];
+///
+/// let format = Format::try_with(|s: &str| s.parse::<f64>().map(|n| format!("{:.2}", n)))
+///     .on_error(OnError::Placeholder("⚠ invalid".to_owned()));
+/// let report = format.report();
+///
+/// let table = Table::new(&data)
+///                .with(Modify::new(Row(1..)).with(format))
+///                .with(Style::psql())
+///                .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "   &str    \n",
+///         "-----------\n",
+///         "   1.50    \n",
+///         " ⚠ invalid \n",
+///     )
+/// );
+///
+/// let errors = report.errors();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!((errors[0].row, errors[0].column), (2, 0));
+/// assert_eq!(errors[0].content, "abc");
+/// ```
+#[derive(Debug)]
+pub struct TryFormat<F> {
+    f: F,
+    on_error: OnError,
+    errors: Rc<RefCell<Vec<FormatError>>>,
+}
+
+impl<F, E> TryFormat<F>
+where
+    F: FnMut(&str) -> Result<String, E>,
+    E: Display,
+{
+    /// Constructs a [TryFormat] which renders a placeholder on error.
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            on_error: OnError::Placeholder(String::from("⚠ invalid")),
+            errors: Rc::default(),
+        }
+    }
+
+    /// Sets a behaviour on error.
+    pub fn on_error(mut self, on_error: OnError) -> Self {
+        self.on_error = on_error;
+        self
+    }
+
+    /// Returns a [FormatReport] which collects errors of this formatter.
+    ///
+    /// The report stays valid after the formatter is moved into a [crate::Modify].
+    pub fn report(&self) -> FormatReport {
+        FormatReport {
+            errors: self.errors.clone(),
+        }
+    }
+}
+
+impl<F, E> CellOption for TryFormat<F>
+where
+    F: FnMut(&str) -> Result<String, E>,
+    E: Display,
+{
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        let content = match (self.f)(content) {
+            Ok(content) => content,
+            Err(err) => {
+                self.errors.borrow_mut().push(FormatError {
+                    row,
+                    column,
+                    content: content.to_owned(),
+                    message: err.to_string(),
+                });
+
+                match &self.on_error {
+                    OnError::Placeholder(placeholder) => placeholder.clone(),
+                    OnError::Raw => return,
+                }
+            }
+        };
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+/// FormatReport is a shared view of errors collected by a [TryFormat].
+#[derive(Debug, Clone, Default)]
+pub struct FormatReport {
+    errors: Rc<RefCell<Vec<FormatError>>>,
+}
+
+impl FormatReport {
+    /// Returns errors which happened while formatting.
+    pub fn errors(&self) -> Vec<FormatError> {
+        self.errors.borrow().clone()
+    }
+
+    /// Checks whether any cell failed to format.
+    pub fn is_empty(&self) -> bool {
+        self.errors.borrow().is_empty()
+    }
+}
+
+/// OnError defines how a cell is rendered when [TryFormat] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OnError {
+    /// A placeholder is rendered instead of a content.
+    Placeholder(String),
+    /// A content is left unchanged.
+    Raw,
+}
+
+/// FormatError describes a cell which [TryFormat] failed to format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError {
+    /// A row of the cell.
+    pub row: usize,
+    /// A column of the cell.
+    pub column: usize,
+    /// An original content of the cell.
+    pub content: String,
+    /// An error message.
+    pub message: String,
+}
+
+/// EditCell changes a content of a particular cell of a [Grid].
+///
+/// It's constructed by [crate::Cell::append], [crate::Cell::prepend] and [crate::Cell::map_content].
+/// A cell which is out of a [Grid] is ignored.
+///
+/// ```
+/// use tabled::{Cell, Style, Table};
+///
+/// let table = Table::new([("latency", 15)])
+///     .with(Cell(1, 1).append(" ms"))
+///     .with(Cell(1, 0).map_content(|s| s.to_uppercase()))
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "  &str   |  i32  \n",
+///         "---------+-------\n",
+///         " LATENCY | 15 ms \n",
+///     )
+/// );
+/// ```
+pub struct EditCell {
+    row: usize,
+    column: usize,
+    f: Box<dyn Fn(&str) -> String>,
+}
+
+impl EditCell {
+    pub(crate) fn new(row: usize, column: usize, f: Box<dyn Fn(&str) -> String>) -> Self {
+        Self { row, column, f }
+    }
+}
+
+impl TableOption for EditCell {
+    fn change(&mut self, grid: &mut Grid) {
+        if self.row >= grid.count_rows() || self.column >= grid.count_columns() {
+            return;
+        }
+
+        let content = (self.f)(grid.get_cell_content(self.row, self.column));
+        grid.set(
+            Entity::Cell(self.row, self.column),
+            Settings::new().text(content),
+        )
+    }
+}
+
+fn strike(s: &str) -> String {
+    #[cfg(not(feature = "color"))]
+    {
+        s.to_owned()
+    }
+    #[cfg(feature = "color")]
+    {
+        format!("\u{1b}[9m{}\u{1b}[29m", s)
+    }
+}
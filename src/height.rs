@@ -0,0 +1,239 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{CellOption, TableOption};
+use papergrid::{Entity, Grid, Settings};
+
+/// Height is a set of options which change a height of rows of a [Table].
+///
+/// The options can be applied to a whole table or to particular cells by [crate::Modify].
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Height, Style, Table};
+///
+/// let data = ["1\n2\n3\n4", "5"];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Height::limit(2).ellipsis("..."))
+///     .with(Height::increase(2))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str \n",
+///         "      \n",
+///         "------\n",
+///         "  1   \n",
+///         " ...  \n",
+///         "  5   \n",
+///         "      \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Height;
+
+impl Height {
+    /// Limit constructs a [HeightLimit] which cuts lines of cells which are higher than `height`.
+    pub fn limit(height: usize) -> HeightLimit {
+        HeightLimit {
+            height,
+            ellipsis: None,
+        }
+    }
+
+    /// Increase constructs a [HeightIncrease] which makes rows at least `height` lines high.
+    pub fn increase(height: usize) -> HeightIncrease {
+        HeightIncrease { height }
+    }
+
+    /// Limit_table constructs a [TableHeightLimit] which cuts rows of a table
+    /// so it's rendered in `height` lines at most.
+    pub fn limit_table(height: usize) -> TableHeightLimit<fn(usize) -> String> {
+        TableHeightLimit {
+            height,
+            indicator: more_rows,
+        }
+    }
+}
+
+/// HeightLimit cuts lines of a cell content which go beyond a given amount of lines.
+///
+/// It's constructed by [Height::limit].
+#[derive(Debug)]
+pub struct HeightLimit {
+    height: usize,
+    ellipsis: Option<String>,
+}
+
+impl HeightLimit {
+    /// Ellipsis sets a line which replaces the last kept line of a cut content.
+    pub fn ellipsis<S: Into<String>>(mut self, ellipsis: S) -> Self {
+        self.ellipsis = Some(ellipsis.into());
+        self
+    }
+}
+
+impl CellOption for HeightLimit {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        let mut lines = content.lines().collect::<Vec<_>>();
+        if lines.len() <= self.height {
+            return;
+        }
+
+        lines.truncate(self.height);
+        if let Some(ellipsis) = &self.ellipsis {
+            if let Some(last) = lines.last_mut() {
+                *last = ellipsis;
+            }
+        }
+
+        let content = lines.join("\n");
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+impl TableOption for HeightLimit {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                self.change_cell(grid, row, column);
+            }
+        }
+    }
+}
+
+/// HeightIncrease sets a minimal height of rows including a vertical indent.
+///
+/// Rows with a higher content are not changed.
+///
+/// It's constructed by [Height::increase].
+#[derive(Debug)]
+pub struct HeightIncrease {
+    height: usize,
+}
+
+impl CellOption for HeightIncrease {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, _: usize) {
+        grid.set_min_row_height(row, self.height);
+    }
+}
+
+impl TableOption for HeightIncrease {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            grid.set_min_row_height(row, self.height);
+        }
+    }
+}
+
+/// TableHeightLimit cuts rows of a table which go beyond a given amount of lines
+/// and adds a last row which tells how many rows were cut, `▼ 42 more rows` by default.
+///
+/// A header row is always kept, and rows are either kept or cut as a whole,
+/// so a multiline row is never split.
+/// If even a header with an indicator doesn't fit they are rendered anyway.
+///
+/// A table height depends on borders, so it must be used after a [crate::Style].
+///
+/// It's constructed by [Height::limit_table].
+///
+/// ```
+/// use tabled::{Height, Style, Table};
+///
+/// let table = Table::new(["1\n1", "2", "3", "4"])
+///     .with(Style::psql())
+///     .with(Height::limit_table(5))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "    &str     \n",
+///         "-------------\n",
+///         "      1      \n",
+///         "      1      \n",
+///         "▼ 3 more rows\n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TableHeightLimit<F> {
+    height: usize,
+    indicator: F,
+}
+
+impl<F> TableHeightLimit<F> {
+    /// Indicator sets a function which makes a text of the last row from an amount of cut rows.
+    pub fn indicator<I>(self, indicator: I) -> TableHeightLimit<I>
+    where
+        I: FnMut(usize) -> String,
+    {
+        TableHeightLimit {
+            height: self.height,
+            indicator,
+        }
+    }
+}
+
+impl<F> TableOption for TableHeightLimit<F>
+where
+    F: FnMut(usize) -> String,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        if count_rows < 2 || grid.measure().height() <= self.height {
+            return;
+        }
+
+        // the more rows are kept the higher a table is,
+        // so the biggest amount which fits is searched for
+        let (mut low, mut high) = (0, count_rows - 2);
+        while low < high {
+            let kept = (low + high).div_ceil(2);
+            let indicator = (self.indicator)(count_rows - 1 - kept);
+            if cut_rows(grid, kept, indicator).measure().height() <= self.height {
+                low = kept;
+            } else {
+                high = kept - 1;
+            }
+        }
+
+        let indicator = (self.indicator)(count_rows - 1 - low);
+        *grid = cut_rows(grid, low, indicator);
+    }
+}
+
+// keeps a header and `kept` rows after it, other rows are replaced by an indicator
+fn cut_rows<'a>(grid: &Grid<'a>, kept: usize, indicator: String) -> Grid<'a> {
+    let mut grid = grid.clone();
+    let count_rows = grid.count_rows();
+    let last_border = grid.get_border_mut(count_rows - 1).clone();
+    for row in (kept + 1..count_rows).rev() {
+        grid.remove_row(row);
+    }
+
+    let row = grid.count_rows();
+    grid.insert_row(row);
+    *grid.get_border_mut(row) = last_border;
+    grid.set(
+        Entity::Cell(row, 0),
+        Settings::new()
+            .text(indicator)
+            .set_span(grid.count_columns()),
+    );
+
+    grid
+}
+
+fn more_rows(count: usize) -> String {
+    if count == 1 {
+        String::from("▼ 1 more row")
+    } else {
+        format!("▼ {} more rows", count)
+    }
+}
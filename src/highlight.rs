@@ -0,0 +1,128 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{style::Border, Cell, CellOption, Object, TableOption};
+use papergrid::Grid;
+use std::collections::BTreeSet;
+
+/// Highlight draws a different border around a set of cells located by an [Object].
+///
+/// The set may be of any shape, e.g. a row, a column or a not rectangular region.
+///
+/// A border is changed only on places where a [crate::Style] has a line,
+/// so it's supposed to be used after a style is set.
+///
+/// # Example
+///
+/// ```rust
+/// use tabled::{Table, Highlight, Row, style::Border};
+///
+/// let data = vec![(0, "Grodno"), (1, "Minsk")];
+///
+/// let table = Table::new(&data)
+///     .with(Highlight::new(Row(1..2), Border::filled('*')))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+-----+--------+\n",
+///         "| i32 |  &str  |\n",
+///         "****************\n",
+///         "*  0  | Grodno *\n",
+///         "****************\n",
+///         "|  1  | Minsk  |\n",
+///         "+-----+--------+\n",
+///     )
+/// );
+/// ```
+pub struct Highlight<O> {
+    target: O,
+    border: Border,
+}
+
+impl<O> Highlight<O>
+where
+    O: Object,
+{
+    /// Creates a [Highlight] of cells located by `target`.
+    pub fn new(target: O, border: Border) -> Self {
+        Self { target, border }
+    }
+}
+
+impl<O> TableOption for Highlight<O>
+where
+    O: Object,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let cells = self
+            .target
+            .cells(grid.count_rows(), grid.count_columns())
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        let has = |row: Option<usize>, column: Option<usize>| match (row, column) {
+            (Some(row), Some(column)) => cells.contains(&(row, column)),
+            _ => false,
+        };
+
+        for &(row, column) in &cells {
+            if let Some(c) = self.border.top {
+                if !has(row.checked_sub(1), Some(column)) {
+                    grid.set_horizontal_char(row, column, c);
+                }
+            }
+
+            if let Some(c) = self.border.bottom {
+                if !has(Some(row + 1), Some(column)) {
+                    grid.set_horizontal_char(row + 1, column, c);
+                }
+            }
+
+            if let Some(c) = self.border.left {
+                if !has(Some(row), column.checked_sub(1)) {
+                    grid.set_vertical_char(row, column, c);
+                }
+            }
+
+            if let Some(c) = self.border.right {
+                if !has(Some(row), Some(column + 1)) {
+                    grid.set_vertical_char(row, column + 1, c);
+                }
+            }
+        }
+
+        // check all corners of the cells
+        let points = cells
+            .iter()
+            .flat_map(|&(row, column)| {
+                vec![
+                    (row, column),
+                    (row, column + 1),
+                    (row + 1, column),
+                    (row + 1, column + 1),
+                ]
+            })
+            .collect::<BTreeSet<_>>();
+
+        for (line, separator) in points {
+            let top_left = has(line.checked_sub(1), separator.checked_sub(1));
+            let top_right = has(line.checked_sub(1), Some(separator));
+            let bottom_left = has(Some(line), separator.checked_sub(1));
+            let bottom_right = has(Some(line), Some(separator));
+
+            let c = self
+                .border
+                .intersection(top_left, top_right, bottom_left, bottom_right);
+
+            if let Some(c) = c {
+                grid.set_intersection_char(line, separator, c);
+            }
+        }
+    }
+}
+
+impl CellOption for Border {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        Highlight::new(Cell(row, column), self.clone()).change(grid);
+    }
+}
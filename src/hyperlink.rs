@@ -0,0 +1,75 @@
+use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+use papergrid::{Entity, Grid, Settings};
+
+/// Hyperlink makes a cell content a clickable link by OSC 8 escape sequences,
+/// which are supported by most of modern terminals.
+///
+/// Escape sequences are not counted in a width of a cell.
+/// Each line of a content is linked separately so borders are never part of a link.
+/// Raw cells are left as they are.
+///
+/// ```rust
+/// use tabled::{Full, Hyperlink, Modify, Style, Table};
+///
+/// let table = Table::new(["docs"])
+///     .with(Style::psql())
+///     .with(Modify::new(Full).with(Hyperlink::new("https://docs.rs")));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " \u{1b}]8;;https://docs.rs\u{1b}\\&str\u{1b}]8;;\u{1b}\\ \n",
+///         "------\n",
+///         " \u{1b}]8;;https://docs.rs\u{1b}\\docs\u{1b}]8;;\u{1b}\\ \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hyperlink {
+    url: Option<String>,
+}
+
+impl Hyperlink {
+    /// Links a content to the given url.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+        }
+    }
+
+    /// Links a content to itself,
+    /// which is handy for cells which hold urls or file paths like `file:///etc/hosts`.
+    pub fn content() -> Self {
+        Self { url: None }
+    }
+}
+
+impl CellOption for Hyperlink {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if grid.is_cell_raw(row, column) {
+            return;
+        }
+
+        let content = grid.get_cell_content(row, column);
+        let content = content
+            .split('\n')
+            .map(|line| {
+                let url = self.url.as_deref().unwrap_or(line).trim();
+                if line.trim().is_empty() || url.is_empty() {
+                    line.to_owned()
+                } else {
+                    link(url, line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+fn link(url: &str, text: &str) -> String {
+    format!("\u{1b}]8;;{}\u{1b}\\{}\u{1b}]8;;\u{1b}\\", url, text)
+}
@@ -0,0 +1,65 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+
+/// Incremental makes a [Table] keep sizes of its cells between renders,
+/// so a table which is refreshed cell by cell measures only changed cells.
+///
+/// Any option which changes more than contents of cells makes all cells to be measured again,
+/// see [papergrid::Grid::set_incremental].
+///
+/// ```rust
+/// use tabled::{Cell, Incremental, Style, Table};
+///
+/// let mut table = Table::new([("cpu", 3), ("mem", 40)])
+///     .with(Style::psql())
+///     .with(Incremental);
+///
+/// assert_eq!(table.to_string(), " &str | i32 \n------+-----\n cpu  |  3  \n mem  | 40  \n");
+///
+/// table = table.with(Cell(1, 1).map_content(|_| String::from("100")));
+///
+/// assert_eq!(table.to_string(), " &str | i32 \n------+-----\n cpu  | 100 \n mem  | 40  \n");
+/// ```
+#[derive(Debug)]
+pub struct Incremental;
+
+impl TableOption for Incremental {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_incremental(true);
+    }
+}
+
+/// WidthCache makes a [Table] keep widths of contents of its cells by hashes of the contents,
+/// so a table which is rendered again and again with mostly the same data
+/// doesn't scan the same strings on every render.
+///
+/// The cache is kept by [Table::with_records], so it's handy for live updated tables.
+/// Its efficiency can be checked by [Table::width_cache_stats],
+/// see [papergrid::Grid::set_width_cache].
+///
+/// ```rust
+/// use tabled::{Style, Table, WidthCache};
+///
+/// let table = Table::new([("cpu", 3), ("mem", 40)])
+///     .with(Style::psql())
+///     .with(WidthCache);
+///
+/// assert_eq!(table.to_string(), " &str | i32 \n------+-----\n cpu  |  3  \n mem  | 40  \n");
+///
+/// let table = table.with_records([("cpu", 4), ("mem", 40)]);
+///
+/// assert_eq!(table.to_string(), " &str | i32 \n------+-----\n cpu  |  4  \n mem  | 40  \n");
+///
+/// let stats = table.width_cache_stats();
+/// assert_eq!((stats.hits, stats.misses), (5, 7));
+/// ```
+#[derive(Debug)]
+pub struct WidthCache;
+
+impl TableOption for WidthCache {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_width_cache(true);
+    }
+}
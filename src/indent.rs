@@ -1,5 +1,5 @@
 use crate::CellOption;
-use papergrid::{Entity, Grid, Settings};
+use papergrid::{Entity, Grid, IndentPosition, Settings};
 
 /// Indent is responsible for a left/right/top/bottom indent of particular cells.
 ///
@@ -8,21 +8,43 @@ use papergrid::{Entity, Grid, Settings};
 ///   # let data: Vec<&'static str> = Vec::new();
 ///     let table = Table::new(&data).with(Modify::new(Row(..1)).with(Indent::new(0, 0, 1, 1)));
 /// ```
+///
+/// By default the indent is put around an already aligned content.
+/// Use [Indent::inside_alignment] to pad the content first and then align it.
 #[derive(Debug)]
-pub struct Indent(usize, usize, usize, usize);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Indent(usize, usize, usize, usize, Option<IndentPosition>);
 
 impl Indent {
     /// Construct's an Indent object.
     pub fn new(left: usize, right: usize, top: usize, bottom: usize) -> Self {
-        Self(left, right, top, bottom)
+        Self(left, right, top, bottom, None)
+    }
+
+    /// Applies the indent outside of an alignment width (align-then-pad).
+    ///
+    /// It's a default behaiviour.
+    pub fn outside_alignment(mut self) -> Self {
+        self.4 = Some(IndentPosition::Outside);
+        self
+    }
+
+    /// Applies the indent inside of an alignment width (pad-then-align).
+    ///
+    /// The indent becomes a part of a content which is aligned.
+    pub fn inside_alignment(mut self) -> Self {
+        self.4 = Some(IndentPosition::Inside);
+        self
     }
 }
 
 impl CellOption for Indent {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
-        grid.set(
-            Entity::Cell(row, column),
-            Settings::new().indent(self.0, self.1, self.2, self.3),
-        )
+        let mut settings = Settings::new().indent(self.0, self.1, self.2, self.3);
+        if let Some(position) = self.4 {
+            settings = settings.indent_position(position);
+        }
+
+        grid.set(Entity::Cell(row, column), settings)
     }
 }
@@ -0,0 +1,86 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::{Entity, Grid, Settings};
+
+/// Index prepends a column which numbers rows of a [Table],
+/// so data doesn't need to be enumerated before a table is built.
+///
+/// The header row isn't numbered, it gets a name of the column which is empty by default.
+/// Numbers start from 0 by default.
+///
+/// ```rust
+/// use tabled::{Index, Style, Table};
+///
+/// let table = Table::new(["Grodno", "Minsk"])
+///     .with(Index::new().start(1).name("#").format(|i| format!("{}.", i)))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " #  |  &str  \n",
+///         "----+--------\n",
+///         " 1. | Grodno \n",
+///         " 2. | Minsk  \n",
+///     )
+/// );
+/// ```
+pub struct Index {
+    start: usize,
+    name: String,
+    format: Box<dyn Fn(usize) -> String>,
+}
+
+impl Index {
+    /// New creates an index column.
+    pub fn new() -> Self {
+        Self {
+            start: 0,
+            name: String::new(),
+            format: Box::new(|i| i.to_string()),
+        }
+    }
+
+    /// Start sets a number of the first row.
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Name sets a header of the column.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Format sets a function which makes a content of a cell from a number of its row.
+    pub fn format<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) -> String + 'static,
+    {
+        self.format = Box::new(f);
+        self
+    }
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for Index {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        grid.insert_column(0);
+        grid.set(Entity::Cell(0, 0), Settings::new().text(self.name.as_str()));
+        for row in 1..grid.count_rows() {
+            let content = (self.format)(self.start + row - 1);
+            grid.set(Entity::Cell(row, 0), Settings::new().text(content));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+
+pub use papergrid::{LayoutOptions, Remainder, SeparatorOwnership, Spread};
+
+/// LayoutOptions can be used to pin decisions which are made when a [Table] is laid out,
+/// like which columns are widened by a spanned cell.
+///
+/// ```rust
+/// use tabled::{LayoutOptions, Panel, Spread, Style, Table};
+///
+/// let table = Table::new([(1, 2)])
+///     .with(Panel("a much wider panel", 0))
+///     .with(Style::psql())
+///     .with(LayoutOptions::new().span_spread(Spread::Last));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "a much wider panel\n",
+///         "-----+------------\n",
+///         " i32 |    i32     \n",
+///         "  1  |     2      \n",
+///     )
+/// );
+/// ```
+impl TableOption for LayoutOptions {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_layout_options(*self);
+    }
+}
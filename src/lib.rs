@@ -142,23 +142,71 @@
 //!
 
 use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
-use std::fmt;
+use std::{cmp::min, fmt, ops::RangeBounds};
 
+mod abbreviate;
 mod alignment;
+mod builder;
+mod changes;
+mod collapse;
+#[cfg(feature = "color")]
+mod color;
+mod concat;
+#[cfg(feature = "datetime")]
+mod datetime;
 mod disable;
+mod export;
+mod filter;
 mod formating;
+mod height;
+mod highlight;
+#[cfg(feature = "color")]
+mod hyperlink;
+mod incremental;
 mod indent;
+mod index;
+mod layout;
+mod nested;
+mod newlines;
+mod number;
 mod object;
 mod panel;
+mod placeholder;
+mod ragged;
+mod raw;
 mod rotate;
+mod sanitize;
+mod semantic;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sort;
+mod span;
+mod split;
+mod stale;
 pub mod style;
+mod summary;
+mod tab;
+mod theme;
+mod trim;
 mod width;
 
+#[cfg(feature = "color")]
+pub use crate::color::*;
+#[cfg(feature = "datetime")]
+pub use crate::datetime::*;
+#[cfg(feature = "color")]
+pub use crate::hyperlink::*;
+#[cfg(feature = "serde")]
+pub use crate::serialize::SerializeError;
 pub use crate::{
-    alignment::*, disable::*, formating::*, indent::*, object::*, panel::*, rotate::*,
-    style::Style, width::*,
+    abbreviate::*, alignment::*, builder::*, changes::*, collapse::*, concat::*, disable::*,
+    filter::*, formating::*, height::*, highlight::*, incremental::*, indent::*, index::*,
+    layout::*, nested::*, newlines::*, number::*, object::*, panel::*, placeholder::*, ragged::*,
+    raw::*, rotate::*, sanitize::*, semantic::*, sort::*, span::*, split::*, stale::*,
+    style::Style, summary::*, tab::*, theme::*, trim::*, width::*,
 };
 pub use papergrid;
+pub use papergrid::{CacheStats, Measurement, ValidationError};
 pub use tabled_derive::Tabled;
 
 /// Tabled a trait responsible for providing a header fields and a row fields.
@@ -235,8 +283,9 @@ pub trait CellOption {
 ///                 .with(Modify::new(Full).with(Alignment::left()));
 /// println!("{}", table);
 /// ```
+#[derive(Clone)]
 pub struct Table {
-    grid: Grid,
+    grid: Grid<'static>,
 }
 
 impl Table {
@@ -257,6 +306,309 @@ impl Table {
         option.change(&mut self.grid);
         self
     }
+
+    /// With_records creates a [Table] of new data with settings of this one,
+    /// so a live updated table doesn't need to be set up on every refresh.
+    ///
+    /// Only settings are reused, options which change contents like [Panel] or [Format]
+    /// need to be applied again.
+    ///
+    /// ```rust
+    /// use tabled::{Alignment, Full, Modify, Style, Table};
+    ///
+    /// let template = Table::new([0])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Full).with(Alignment::right()));
+    ///
+    /// let table = template.with_records([1, 200]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "i32\n",
+    ///         "---\n",
+    ///         "  1\n",
+    ///         "200\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn with_records<T: Tabled>(&self, iter: impl IntoIterator<Item = T>) -> Self {
+        let (records, _) = build_grid(iter).into_parts();
+        let mut grid = self.grid.clone();
+        grid.set_records(records);
+
+        Self { grid }
+    }
+
+    /// From_map creates a [Table] with `key` and `value` columns from pairs,
+    /// which can be any map or a list of tuples.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use tabled::{Table, Style};
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert("version", "0.3.0");
+    /// map.insert("edition", "2018");
+    ///
+    /// let table = Table::from_map(&map).with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "   key   | value \n",
+    ///         "---------+-------\n",
+    ///         " edition | 2018  \n",
+    ///         " version | 0.3.0 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn from_map<I, K, V>(map: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: fmt::Display,
+        V: fmt::Display,
+    {
+        let pairs = map.into_iter().collect::<Vec<_>>();
+
+        let mut grid = new_grid(pairs.len() + 1, 2);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("key"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("value"));
+
+        for (row, (key, value)) in pairs.iter().enumerate() {
+            grid.set(
+                Entity::Cell(row + 1, 0),
+                Settings::new().text(key.to_string()),
+            );
+            grid.set(
+                Entity::Cell(row + 1, 1),
+                Settings::new().text(value.to_string()),
+            );
+        }
+
+        Self { grid }
+    }
+
+    /// Extract returns a new [Table] which contains only cells located by given ranges.
+    ///
+    /// Cell settings are preserved, a span is truncated to fit the window.
+    /// A [Style] is not copied so it must be applied to the new table.
+    ///
+    /// ```rust
+    /// use tabled::{Table, Style};
+    ///
+    /// let table = Table::new(&[(0, "Grodno", true), (1, "Minsk", true)]);
+    /// let table = table.extract(1.., 1..).with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " Grodno | true \n",
+    ///         "--------+------\n",
+    ///         " Minsk  | true \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn extract<R, C>(&self, rows: R, columns: C) -> Self
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (start_row, end_row) =
+            bounds_to_usize(rows.start_bound(), rows.end_bound(), self.grid.count_rows());
+        let (start_column, end_column) = bounds_to_usize(
+            columns.start_bound(),
+            columns.end_bound(),
+            self.grid.count_columns(),
+        );
+        let end_row = min(end_row, self.grid.count_rows());
+        let end_column = min(end_column, self.grid.count_columns());
+        let start_row = min(start_row, end_row);
+        let start_column = min(start_column, end_column);
+
+        let mut grid = Grid::new(end_row - start_row, end_column - start_column);
+        for row in start_row..end_row {
+            for column in start_column..end_column {
+                let mut settings = self.grid.get_cell_settings(row, column);
+                let mut span = self.grid.get_cell_span(row, column);
+
+                // a cell which spans over the left edge of the window is moved into the window
+                if column == start_column {
+                    let origin = (0..start_column)
+                        .rev()
+                        .find(|&c| c + self.grid.get_cell_span(row, c) > start_column);
+                    if let Some(origin) = origin {
+                        settings = self.grid.get_cell_settings(row, origin);
+                        span = origin + self.grid.get_cell_span(row, origin) - start_column;
+                    }
+                }
+
+                let settings = settings.set_span(min(span, end_column - column));
+                grid.set(
+                    Entity::Cell(row - start_row, column - start_column),
+                    settings,
+                );
+            }
+        }
+
+        Self { grid }
+    }
+
+    /// Measure calculates a size of the table without rendering it.
+    ///
+    /// It's useful for a layout pass of an embedder,
+    /// the table can be rendered later by [Table::draw] without calculating the size again.
+    /// To check a size at a given width apply width options to a copy of the table.
+    ///
+    /// ```rust
+    /// use tabled::{Table, Modify, Full, Wrap, Style};
+    ///
+    /// let table = Table::new(["Hello World"]).with(Style::psql());
+    ///
+    /// let narrow = table.clone().with(Modify::new(Full).with(Wrap(5)));
+    /// let measurement = narrow.measure();
+    /// assert_eq!((measurement.width(), measurement.height()), (7, 5));
+    ///
+    /// assert_eq!(
+    ///     narrow.draw(&measurement),
+    ///     concat!(
+    ///         " &str  \n",
+    ///         "-------\n",
+    ///         " Hello \n",
+    ///         " Worl  \n",
+    ///         "   d   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn measure(&self) -> Measurement {
+        self.grid.measure()
+    }
+
+    /// Draw renders the table using a [Measurement] which was made by [Table::measure].
+    ///
+    /// # Panics
+    ///
+    /// The measurement must be made for the same table.
+    pub fn draw(&self, measurement: &Measurement) -> String {
+        self.grid.draw(measurement)
+    }
+
+    /// Render_to_file streams the rendered table into a file without building it in memory.
+    ///
+    /// `progress` is called after each row with an amount of rendered rows and a total amount of rows.
+    pub fn render_to_file<P, F>(&self, path: P, progress: F) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+        F: FnMut(usize, usize),
+    {
+        self.grid.render_to_file(path, progress)
+    }
+
+    /// Lines returns an iterator over lines of the table, see [papergrid::Grid::lines].
+    ///
+    /// Rows are drawn only when their lines are requested, so a preview doesn't draw the whole table.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(0..1000).with(Style::psql());
+    ///
+    /// assert_eq!(table.lines().take(3).collect::<Vec<_>>(), [" i32 ", "-----", "  0  "]);
+    /// ```
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.grid.lines()
+    }
+
+    /// Validate checks settings of the table which can't be applied as they are,
+    /// like a span which goes beyond the table, see [papergrid::Grid::validate].
+    ///
+    /// ```rust
+    /// use tabled::{Cell, Modify, Span, Table, ValidationError};
+    ///
+    /// let table = Table::new([(1, 2)]).with(Modify::new(Cell(1, 1)).with(Span::column(2)));
+    ///
+    /// assert_eq!(
+    ///     table.validate(),
+    ///     Err(vec![ValidationError::SpanOutOfGrid { row: 1, column: 1, span: 2 }])
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        self.grid.validate()
+    }
+
+    /// Width_cache_stats returns a statistics of a width cache which is turned on by [WidthCache].
+    pub fn width_cache_stats(&self) -> CacheStats {
+        self.grid.width_cache_stats()
+    }
+
+    /// Render_deterministic renders the table in a form which is stable across platforms,
+    /// so its output can be checked by snapshot tests.
+    ///
+    /// Trailing whitespace is removed from each line and lines end with `\n`,
+    /// see [papergrid::Grid::render_deterministic].
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["a", "bb"]).with(Style::noborder());
+    ///
+    /// assert_eq!(table.render_deterministic(), " &str\n  a\n  bb\n");
+    /// ```
+    pub fn render_deterministic(&self) -> String {
+        self.grid.render_deterministic()
+    }
+
+    /// Paginate renders the table by pages of `rows_per_page` rows, each page repeats a header.
+    ///
+    /// All pages have the same column widths, so they are aligned with each other.
+    /// A table with only a header gives a single page.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `rows_per_page` is 0.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["a", "bb", "ccc"]).with(Style::psql());
+    /// let pages = table.paginate(2).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     pages,
+    ///     [
+    ///         concat!(" &str \n", "------\n", "  a   \n", "  bb  \n"),
+    ///         concat!(" &str \n", "------\n", " ccc  \n"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn paginate(&self, rows_per_page: usize) -> impl Iterator<Item = String> + '_ {
+        assert!(rows_per_page > 0, "rows_per_page must be greater than 0");
+
+        let count_rows = self.grid.count_rows();
+        let widths = self.grid.measure().column_widths().to_vec();
+        let body = (1..count_rows).collect::<Vec<_>>();
+        let mut chunks = body
+            .chunks(rows_per_page)
+            .map(<[usize]>::to_vec)
+            .collect::<Vec<_>>();
+        if count_rows == 1 {
+            chunks.push(Vec::new());
+        }
+
+        chunks.into_iter().map(move |chunk| {
+            let mut rows = vec![0];
+            rows.extend(chunk);
+
+            let mut page = self.grid.select_rows(&rows);
+            let last = rows.len() - 1;
+            *page.get_border_mut(last) = self.grid.get_border(count_rows - 1).clone();
+            for (column, &width) in widths.iter().enumerate() {
+                page.set_min_column_width(column, width);
+            }
+
+            page.to_string()
+        })
+    }
 }
 
 impl fmt::Display for Table {
@@ -314,20 +666,11 @@ where
 
 /// Building [Grid] from a data.
 /// You must prefer [Table] over this function.
-fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
+fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid<'static> {
     let headers = T::headers();
     let obj: Vec<Vec<String>> = iter.into_iter().map(|t| t.fields()).collect();
 
-    let mut grid = Grid::new(obj.len() + 1, headers.len());
-
-    // it's crusial to set a global setting rather than a setting for an each cell
-    // as it will be hard to override that since how Grid::style method works
-    grid.set(
-        Entity::Global,
-        Settings::new()
-            .indent(1, 1, 0, 0)
-            .alignment(AlignmentHorizontal::Center),
-    );
+    let mut grid = new_grid(obj.len() + 1, headers.len());
 
     for (i, h) in headers.iter().enumerate() {
         grid.set(Entity::Cell(0, i), Settings::new().text(h));
@@ -349,6 +692,22 @@ fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
     grid
 }
 
+/// Creates a [Grid] with default table settings.
+fn new_grid(count_rows: usize, count_columns: usize) -> Grid<'static> {
+    let mut grid = Grid::new(count_rows, count_columns);
+
+    // it's crusial to set a global setting rather than a setting for an each cell
+    // as it will be hard to override that since how Grid::style method works
+    grid.set(
+        Entity::Global,
+        Settings::new()
+            .indent(1, 1, 0, 0)
+            .alignment(AlignmentHorizontal::Center),
+    );
+
+    grid
+}
+
 macro_rules! tuple_table {
     ( $($name:ident)+ ) => {
         impl<$($name: Tabled),+> Tabled for ($($name,)+){
@@ -375,6 +734,12 @@ tuple_table! { A B C }
 tuple_table! { A B C D }
 tuple_table! { A B C D E }
 tuple_table! { A B C D E F }
+tuple_table! { A B C D E F G }
+tuple_table! { A B C D E F G H }
+tuple_table! { A B C D E F G H I }
+tuple_table! { A B C D E F G H I J }
+tuple_table! { A B C D E F G H I J K }
+tuple_table! { A B C D E F G H I J K L }
 
 macro_rules! default_table {
     ( $t:ty ) => {
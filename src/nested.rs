@@ -0,0 +1,46 @@
+use crate::{CellOption, Table};
+use papergrid::{Entity, Grid, Settings};
+
+/// Nested puts a [Table] inside of a cell.
+///
+/// Unlike a rendered table set as a text, its size takes part in a layout
+/// and it's stretched to fill the whole cell,
+/// so lines of a nested table without a frame meet borders of the cell.
+/// An indent and an alignment of the cell are not applied to it.
+///
+/// A rendered nested table is also set as a text of the cell,
+/// which is used by [Table::to_markdown] and [Table::to_html].
+///
+/// ```rust
+/// use tabled::{Cell, Modify, Nested, Style, Table};
+///
+/// let nested = Table::new([(1, 2)]).with(Style::psql());
+/// let table = Table::new(["key", "value"])
+///     .with(Modify::new(Cell(2, 0)).with(Nested(nested)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-----------+\n",
+///         "|   &str    |\n",
+///         "+-----------+\n",
+///         "|    key    |\n",
+///         "+-----------+\n",
+///         "| i32 | i32 |\n",
+///         "|-----+-----|\n",
+///         "|  1  |  2  |\n",
+///         "+-----------+\n",
+///     )
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Nested(pub Table);
+
+impl CellOption for Nested {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let settings = Settings::new()
+            .text(self.0.to_string())
+            .nested(self.0.grid.clone());
+        grid.set(Entity::Cell(row, column), settings)
+    }
+}
@@ -0,0 +1,74 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{CellOption, TableOption};
+use papergrid::{Entity, Grid, Settings};
+
+/// Newlines keeps cells single-line by escaping or replacing line breaks,
+/// so each record takes exactly one row of a [Table].
+///
+/// It's handy for data like log messages which may contain embedded newlines.
+/// A `\r\n` line ending is handled as a single line break.
+/// Raw cells are left as they are.
+///
+/// It can be applied to a whole table or to particular cells by [crate::Modify].
+///
+/// ```rust
+/// use tabled::{Newlines, Style, Table};
+///
+/// let table = Table::new(["panic\nat main.rs"])
+///     .with(Newlines::Escape)
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "       &str        \n",
+///         "-------------------\n",
+///         " panic\\nat main.rs \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Newlines {
+    /// Line breaks are replaced by a `\n` escape sequence.
+    Escape,
+    /// Line breaks are replaced by a given text, for example by `⏎` or a space.
+    Replace(String),
+}
+
+impl Newlines {
+    fn replacement(&self) -> &str {
+        match self {
+            Self::Escape => "\\n",
+            Self::Replace(text) => text,
+        }
+    }
+}
+
+impl CellOption for Newlines {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if grid.is_cell_raw(row, column) {
+            return;
+        }
+
+        let content = grid.get_cell_content(row, column);
+        if !content.contains('\n') {
+            return;
+        }
+
+        let content = content
+            .replace("\r\n", "\n")
+            .replace('\n', self.replacement());
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+impl TableOption for Newlines {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                self.change_cell(grid, row, column);
+            }
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use crate::{CellOption, Format};
+#[cfg(feature = "color")]
+use papergrid::Color;
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+
+impl Format<fn(&str) -> String> {
+    /// Percent formats fractions as percents with a given amount of decimal digits.
+    ///
+    /// A content which is not a number is left unchanged.
+    ///
+    /// ```
+    /// use tabled::{Table, Format, Row, Modify, Style};
+    ///
+    /// let data = [0.153, 1.0, -0.02];
+    ///
+    /// let table = Table::new(&data)
+    ///                .with(Modify::new(Row(1..)).with(Format::percent(1)))
+    ///                .with(Style::psql())
+    ///                .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "   f64   \n",
+    ///         "---------\n",
+    ///         " 15.3 %  \n",
+    ///         " 100.0 % \n",
+    ///         " -2.0 %  \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn percent(precision: usize) -> FormatPercent {
+        FormatPercent {
+            precision,
+            locale: Locale::en(),
+        }
+    }
+
+    /// Currency formats numbers as money with a given symbol.
+    ///
+    /// By default 2 decimal digits are used.
+    /// A content which is not a number is left unchanged.
+    ///
+    /// ```
+    /// use tabled::{Table, Format, Row, Modify, Style, Locale, SymbolPosition};
+    ///
+    /// let data = [1234.5, -3.0];
+    ///
+    /// let table = Table::new(&data)
+    ///                .with(Modify::new(Row(1..)).with(
+    ///                    Format::currency("€", SymbolPosition::Suffix).locale(Locale::de())
+    ///                ))
+    ///                .with(Style::psql())
+    ///                .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "    f64     \n",
+    ///         "------------\n",
+    ///         " 1.234,50 € \n",
+    ///         "  -3,00 €   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn currency<S: Into<String>>(symbol: S, position: SymbolPosition) -> FormatCurrency {
+        FormatCurrency {
+            symbol: symbol.into(),
+            position,
+            precision: 2,
+            locale: Locale::en(),
+        }
+    }
+
+    /// Delta formats numbers as signed changes with a given amount of decimal digits.
+    ///
+    /// While `color` feature is on a positive change is green and a negative one is red.
+    /// A content which is not a number is left unchanged.
+    ///
+    /// ```
+    /// use tabled::{Table, Format, Row, Modify, Style};
+    ///
+    /// let data = [1.5, -0.25, 0.0];
+    ///
+    /// let table = Table::new(&data)
+    ///                .with(Modify::new(Row(1..)).with(Format::delta(1)))
+    ///                .with(Style::psql())
+    ///                .to_string();
+    ///
+    /// # #[cfg(not(feature = "color"))]
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " f64  \n",
+    ///         "------\n",
+    ///         " +1.5 \n",
+    ///         " −0.2 \n",
+    ///         " 0.0  \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn delta(precision: usize) -> FormatDelta {
+        FormatDelta {
+            precision,
+            locale: Locale::en(),
+        }
+    }
+}
+
+/// Locale defines separators which are used to format numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    decimal: char,
+    grouping: Option<char>,
+}
+
+impl Locale {
+    /// New creates a locale with a decimal separator and an optional separator of thousands.
+    pub fn new(decimal: char, grouping: Option<char>) -> Self {
+        Self { decimal, grouping }
+    }
+
+    /// En is an english locale, `1,234.5`.
+    pub fn en() -> Self {
+        Self::new('.', Some(','))
+    }
+
+    /// De is a german locale, `1.234,5`.
+    pub fn de() -> Self {
+        Self::new(',', Some('.'))
+    }
+
+    /// Fr is a french locale, `1 234,5`.
+    pub fn fr() -> Self {
+        Self::new(',', Some('\u{a0}'))
+    }
+
+    fn format(&self, value: f64, precision: usize) -> String {
+        let number = format!("{:.*}", precision, value.abs());
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number.as_str(), None),
+        };
+
+        let mut out = String::new();
+        for (i, c) in integer.chars().enumerate() {
+            let left = integer.len() - i;
+            if i > 0 && left % 3 == 0 {
+                if let Some(grouping) = self.grouping {
+                    out.push(grouping);
+                }
+            }
+
+            out.push(c);
+        }
+
+        if let Some(fraction) = fraction {
+            out.push(self.decimal);
+            out.push_str(fraction);
+        }
+
+        out
+    }
+}
+
+/// SymbolPosition defines where a currency symbol is put.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolPosition {
+    /// A symbol is put before an amount, `$1.00`.
+    Prefix,
+    /// A symbol is put after an amount separated by a space, `1,00 €`.
+    Suffix,
+}
+
+/// FormatPercent renders fractions as percents.
+///
+/// It's constructed by [Format::percent].
+#[derive(Debug)]
+pub struct FormatPercent {
+    precision: usize,
+    locale: Locale,
+}
+
+impl FormatPercent {
+    /// Locale sets a locale which is used to format a number.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+}
+
+impl CellOption for FormatPercent {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if let Some(value) = parse_number(grid.get_cell_content(row, column)) {
+            let value = value * 100.0;
+            let content = format!(
+                "{}{} %",
+                sign(value, self.precision),
+                self.locale.format(value, self.precision)
+            );
+            grid.set(Entity::Cell(row, column), Settings::new().text(content))
+        }
+    }
+}
+
+/// FormatCurrency renders numbers as money.
+///
+/// It's constructed by [Format::currency].
+#[derive(Debug)]
+pub struct FormatCurrency {
+    symbol: String,
+    position: SymbolPosition,
+    precision: usize,
+    locale: Locale,
+}
+
+impl FormatCurrency {
+    /// Precision sets an amount of decimal digits.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Locale sets a locale which is used to format a number.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+}
+
+impl CellOption for FormatCurrency {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if let Some(value) = parse_number(grid.get_cell_content(row, column)) {
+            let sign = sign(value, self.precision);
+            let amount = self.locale.format(value, self.precision);
+            let content = match self.position {
+                SymbolPosition::Prefix => format!("{}{}{}", sign, self.symbol, amount),
+                SymbolPosition::Suffix => format!("{}{} {}", sign, amount, self.symbol),
+            };
+
+            grid.set(Entity::Cell(row, column), Settings::new().text(content))
+        }
+    }
+}
+
+/// FormatDelta renders numbers as signed changes.
+///
+/// It's constructed by [Format::delta].
+#[derive(Debug)]
+pub struct FormatDelta {
+    precision: usize,
+    locale: Locale,
+}
+
+impl FormatDelta {
+    /// Locale sets a locale which is used to format a number.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+}
+
+impl CellOption for FormatDelta {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if let Some(value) = parse_number(grid.get_cell_content(row, column)) {
+            let amount = self.locale.format(value, self.precision);
+            let is_zero = amount.chars().all(|c| !c.is_ascii_digit() || c == '0');
+            let (content, _is_positive) = if is_zero {
+                (amount, None)
+            } else if value > 0.0 {
+                (format!("+{}", amount), Some(true))
+            } else {
+                (format!("\u{2212}{}", amount), Some(false))
+            };
+
+            let settings = Settings::new().text(content);
+            #[cfg(feature = "color")]
+            let settings = match _is_positive {
+                Some(true) => settings.color(Color::green()),
+                Some(false) => settings.color(Color::red()),
+                None => settings,
+            };
+
+            grid.set(Entity::Cell(row, column), settings)
+        }
+    }
+}
+
+/// AlignNumeric lines up numbers of a column on their decimal separator.
+///
+/// Numbers are right aligned and a place of a missing fraction is left blank,
+/// so digits of the same order are put under each other.
+/// A number without a fraction is aligned by its last digit and a suffix like `%` is kept after it.
+/// Lines of a multiline cell are aligned by the longest fraction among them.
+///
+/// A content which doesn't look like a number, it has no digits or has letters, is left unchanged
+/// and it's not taken into account, like a header.
+///
+/// ```
+/// use tabled::{AlignNumeric, Column, Modify, Style, Table};
+///
+/// let data = [12.5, 1.25, 100.0, 3.0];
+///
+/// let table = Table::new(&data)
+///                .with(Modify::new(Column(..)).with(AlignNumeric::new()))
+///                .with(Style::psql())
+///                .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "  f64   \n",
+///         "--------\n",
+///         "  12.5  \n",
+///         "   1.25 \n",
+///         " 100    \n",
+///         "   3    \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct AlignNumeric {
+    locale: Locale,
+}
+
+impl AlignNumeric {
+    /// New creates an alignment by a `.` decimal separator.
+    pub fn new() -> Self {
+        Self {
+            locale: Locale::en(),
+        }
+    }
+
+    /// Locale sets a locale which decimal separator is used.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    // splits a number into an integer part and the rest of it starting from a decimal separator
+    fn split<'a>(&self, line: &'a str) -> Option<(&'a str, &'a str)> {
+        let is_number =
+            line.chars().any(|c| c.is_ascii_digit()) && !line.chars().any(char::is_alphabetic);
+        if !is_number {
+            return None;
+        }
+
+        let end = match line.find(self.locale.decimal) {
+            Some(end) => end,
+            None => line
+                .char_indices()
+                .rfind(|(_, c)| c.is_ascii_digit())
+                .map_or(0, |(i, c)| i + c.len_utf8()),
+        };
+
+        Some(line.split_at(end))
+    }
+}
+
+impl Default for AlignNumeric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CellOption for AlignNumeric {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let fraction = |content: &str| {
+            content
+                .lines()
+                .filter_map(|line| self.split(line.trim()))
+                .map(|(_, fraction)| fraction.chars().count())
+                .max()
+        };
+
+        let width = match fraction(grid.get_cell_content(row, column)) {
+            Some(width) => width,
+            None => return,
+        };
+
+        // a right indent takes place of a missing fraction, so separators end up in the same place
+        let max_width = grid.column(column).filter_map(fraction).max().unwrap_or(0);
+        let settings = grid.get_cell_settings(row, column);
+        let (left, right, top, bottom) = settings.get_indent().unwrap_or_default();
+
+        grid.set(
+            Entity::Cell(row, column),
+            Settings::new()
+                .indent(left, right + max_width - width, top, bottom)
+                .alignment(AlignmentHorizontal::Right),
+        )
+    }
+}
+
+fn parse_number(s: &str) -> Option<f64> {
+    s.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+// a minus is shown only if a rounded number is not zero
+fn sign(value: f64, precision: usize) -> &'static str {
+    let is_zero = format!("{:.*}", precision, value.abs())
+        .chars()
+        .all(|c| c == '0' || c == '.');
+    if value < 0.0 && !is_zero {
+        "-"
+    } else {
+        ""
+    }
+}
@@ -1,8 +1,10 @@
+use crate::EditCell;
 #[allow(unused)]
 use papergrid::Grid;
 use std::{
+    cmp::min,
     collections::BTreeSet,
-    ops::{Bound, RangeBounds},
+    ops::{Bound, Range, RangeBounds},
 };
 
 /// Object helps to locate a nessesary part of a [Grid].
@@ -28,6 +30,15 @@ pub trait Object: Sized {
             combinator: remove_cells,
         }
     }
+
+    /// Keeps only cells which are present in both sets.
+    fn intersect<O: Object>(self, rhs: O) -> Combination<Self, O> {
+        Combination {
+            lhs: self,
+            rhs,
+            combinator: intersect_cells,
+        }
+    }
 }
 
 /// Head represents the row at the top of a [Table].
@@ -92,6 +103,142 @@ impl Object for Cell {
     }
 }
 
+impl Cell {
+    /// Append adds a text to the end of a content of the cell.
+    pub fn append(self, text: impl Into<String>) -> EditCell {
+        let text = text.into();
+        self.map_content(move |s| format!("{}{}", s, text))
+    }
+
+    /// Prepend adds a text to the begining of a content of the cell.
+    pub fn prepend(self, text: impl Into<String>) -> EditCell {
+        let text = text.into();
+        self.map_content(move |s| format!("{}{}", text, s))
+    }
+
+    /// Map_content replaces a content of the cell by a result of a function.
+    pub fn map_content<F>(self, f: F) -> EditCell
+    where
+        F: Fn(&str) -> String + 'static,
+    {
+        EditCell::new(self.0, self.1, Box::new(f))
+    }
+}
+
+/// Rows denotes a set of cells on given rows on a [Grid].
+///
+/// Rows which are out of a [Grid] are ignored.
+///
+/// ```rust,no_run
+///   # use tabled::{Alignment, Modify, Rows, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data).with(Modify::new(Rows::new(1..)).with(Alignment::left()));
+/// ```
+pub struct Rows<R: RangeBounds<usize>> {
+    range: R,
+}
+
+impl<R: RangeBounds<usize>> Rows<R> {
+    /// New creates a set of rows by a range.
+    pub fn new(range: R) -> Self {
+        Self { range }
+    }
+}
+
+impl Rows<Range<usize>> {
+    /// Single creates a set with a single row.
+    pub fn single(index: usize) -> Self {
+        Self::new(index..index + 1)
+    }
+}
+
+impl<R: RangeBounds<usize>> Object for Rows<R> {
+    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
+        let (x, y) = bounds_to_usize(self.range.start_bound(), self.range.end_bound(), count_rows);
+
+        (x..min(y, count_rows))
+            .flat_map(|row| (0..count_columns).map(move |column| (row, column)))
+            .collect()
+    }
+}
+
+/// Columns denotes a set of cells on given columns on a [Grid].
+///
+/// Columns which are out of a [Grid] are ignored.
+///
+/// ```rust,no_run
+///   # use tabled::{Alignment, Columns, Modify, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data).with(Modify::new(Columns::single(2)).with(Alignment::left()));
+/// ```
+pub struct Columns<R: RangeBounds<usize>> {
+    range: R,
+}
+
+impl<R: RangeBounds<usize>> Columns<R> {
+    /// New creates a set of columns by a range.
+    pub fn new(range: R) -> Self {
+        Self { range }
+    }
+}
+
+impl Columns<Range<usize>> {
+    /// Single creates a set with a single column.
+    pub fn single(index: usize) -> Self {
+        Self::new(index..index + 1)
+    }
+}
+
+impl<R: RangeBounds<usize>> Object for Columns<R> {
+    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
+        let (x, y) = bounds_to_usize(
+            self.range.start_bound(),
+            self.range.end_bound(),
+            count_columns,
+        );
+
+        (x..min(y, count_columns))
+            .flat_map(|column| (0..count_rows).map(move |row| (row, column)))
+            .collect()
+    }
+}
+
+/// FirstRow represents the first row of a [Grid].
+pub struct FirstRow;
+
+impl Object for FirstRow {
+    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
+        Rows::new(..min(1, count_rows)).cells(count_rows, count_columns)
+    }
+}
+
+/// LastRow represents the last row of a [Grid].
+pub struct LastRow;
+
+impl Object for LastRow {
+    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
+        Rows::new(count_rows.saturating_sub(1)..).cells(count_rows, count_columns)
+    }
+}
+
+/// FirstColumn represents the first column of a [Grid].
+pub struct FirstColumn;
+
+impl Object for FirstColumn {
+    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
+        Columns::new(..min(1, count_columns)).cells(count_rows, count_columns)
+    }
+}
+
+/// LastColumn represents the last column of a [Grid].
+pub struct LastColumn;
+
+impl Object for LastColumn {
+    fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
+        Columns::new(count_columns.saturating_sub(1)..).cells(count_rows, count_columns)
+    }
+}
+
 /// Combinator is a transformation function
 type Combinator = fn(Vec<(usize, usize)>, Vec<(usize, usize)>) -> Vec<(usize, usize)>;
 
@@ -119,7 +266,7 @@ where
 /// Dublicates are removed from the output set.
 fn combine_cells(lhs: Vec<(usize, usize)>, rhs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
     lhs.into_iter()
-        .chain(rhs.into_iter())
+        .chain(rhs)
         .collect::<BTreeSet<_>>()
         .into_iter()
         .collect()
@@ -130,6 +277,11 @@ fn remove_cells(lhs: Vec<(usize, usize)>, rhs: Vec<(usize, usize)>) -> Vec<(usiz
     lhs.into_iter().filter(|l| !rhs.contains(l)).collect()
 }
 
+/// Keeps cells from fist set which are present in a second set.
+fn intersect_cells(lhs: Vec<(usize, usize)>, rhs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    lhs.into_iter().filter(|l| rhs.contains(l)).collect()
+}
+
 /// Converts a range bound to its indexes.
 pub(crate) fn bounds_to_usize(
     left: Bound<&usize>,
@@ -42,3 +42,64 @@ impl<S: AsRef<str>> TableOption for Footer<S> {
         Panel(self.0.as_ref(), grid.count_rows()).change(grid)
     }
 }
+
+/// EmptyState renders a text instead of data when a [Table] has no rows except a header,
+/// like `no data` in a single cell under the header.
+///
+/// A table without any cells becomes a table of a single cell.
+/// Like a [Panel] it must be applied before a [crate::Style].
+///
+/// ```rust
+/// use tabled::{EmptyState, Style, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Language {
+///     name: &'static str,
+///     designed_by: &'static str,
+/// }
+///
+/// let table = Table::new(Vec::<Language>::new())
+///     .with(EmptyState("no data"))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " name | designed_by \n",
+///         "------+-------------\n",
+///         "      no data       \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct EmptyState<S: AsRef<str>>(pub S);
+
+impl<S: AsRef<str>> TableOption for EmptyState<S> {
+    fn change(&mut self, grid: &mut Grid) {
+        let row = if grid.count_rows() == 0 || grid.count_columns() == 0 {
+            while grid.count_rows() > 1 {
+                grid.remove_row(grid.count_rows() - 1);
+            }
+            if grid.count_rows() == 0 {
+                grid.push_row();
+            }
+            if grid.count_columns() == 0 {
+                grid.push_column();
+            }
+
+            0
+        } else if grid.count_rows() == 1 {
+            grid.push_row();
+            1
+        } else {
+            return;
+        };
+
+        // a cell style is replaced as a whole, so the current one is taken as a base
+        let settings = grid
+            .get_cell_settings(row, 0)
+            .text(self.0.as_ref().to_owned())
+            .set_span(grid.count_columns());
+        grid.set(Entity::Cell(row, 0), settings)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+use papergrid::{Entity, Grid, Settings};
+
+/// Placeholder sets a text for empty cells,
+/// which makes missing values visible.
+///
+/// A cell which consists only of whitespaces is considered empty.
+/// Raw cells are left as they are.
+///
+/// `#[derive(Tabled)]` supports the same for `Option` fields by `#[tabled(placeholder = "-")]`
+/// which can be set either for a field or for a whole type.
+///
+/// ```rust
+/// use tabled::{Modify, Placeholder, Rows, Style, Table};
+///
+/// let data = [["1", "Debian"], ["", "Arch"], ["3", ""]];
+/// let table = Table::new(data)
+///     .with(Style::psql())
+///     .with(Modify::new(Rows::new(1..)).with(Placeholder::new("N/A")));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "  0  |   1    \n",
+///         "-----+--------\n",
+///         "  1  | Debian \n",
+///         " N/A |  Arch  \n",
+///         "  3  |  N/A   \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder(String);
+
+impl Placeholder {
+    /// New creates a placeholder with a given text.
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self(text.into())
+    }
+}
+
+impl CellOption for Placeholder {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if grid.is_cell_raw(row, column) || !grid.get_cell_content(row, column).trim().is_empty() {
+            return;
+        }
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(&self.0))
+    }
+}
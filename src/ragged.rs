@@ -0,0 +1,50 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+
+/// Ragged renders the last row of a [Table] only up to its last non empty cell.
+///
+/// It can be used for a gallery like layout where the last row is partially filled,
+/// the frame is drawn only around the occupied cells.
+///
+/// ```rust
+/// use tabled::{Builder, Ragged, Style};
+///
+/// let table = Builder::new()
+///     .add_row(["a", "b", "c"])
+///     .add_row(["d"])
+///     .build()
+///     .with(Style::pseudo())
+///     .with(Ragged);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "┌───┬───┬───┐\n",
+///         "│ a │ b │ c │\n",
+///         "├───┼───┴───┘\n",
+///         "│ d │\n",
+///         "└───┘\n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Ragged;
+
+impl TableOption for Ragged {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        if count_rows == 0 {
+            return;
+        }
+
+        let last_row = count_rows - 1;
+        let length = (0..grid.count_columns())
+            .rev()
+            .find(|&column| !grid.get_cell_content(last_row, column).is_empty())
+            .map_or(0, |column| column + 1);
+
+        grid.set_last_row_length(length);
+    }
+}
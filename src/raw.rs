@@ -0,0 +1,41 @@
+use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+use papergrid::{Entity, Grid, Settings};
+
+/// Raw makes a cell content be rendered as is.
+///
+/// A raw cell is not padded, aligned nor colored,
+/// and width options like [crate::Wrap] and [crate::Truncate] don't change it.
+/// It's an escape hatch for pre-rendered content like braille graphics or pre-colored text,
+/// so a caller is responsible for a content to have a width of a column.
+///
+/// ```rust
+/// use tabled::{Cell, Modify, Raw, Style, Table, Wrap, Full};
+///
+/// let data = ["⣿⣿⣿⣿⣿⣿", "abc"];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Cell(1, 0)).with(Raw))
+///     .with(Modify::new(Full).with(Wrap(3)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &st  \n",
+///         "  r   \n",
+///         "------\n",
+///         "⣿⣿⣿⣿⣿⣿\n",
+///         " abc  \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Raw;
+
+impl CellOption for Raw {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        grid.set(Entity::Cell(row, column), Settings::new().raw(true))
+    }
+}
@@ -1,15 +1,64 @@
 #[allow(unused)]
 use crate::Table;
-use crate::TableOption;
-use papergrid::{Entity, Grid};
+use crate::{CellOption, TableOption};
+use papergrid::{Entity, Grid, Settings};
 
 /// Rotate can be used to rotate a table by 90 degrees.
+///
+/// [Rotate::Transpose] swaps rows and columns,
+/// so a header becomes the first column and each record becomes a column.
+/// Settings of cells like colors and alignments are moved together with them
+/// and spans over columns become spans over rows.
+/// Borders, border overrides and minimal sizes are reset, so a [crate::Style] is applied after it.
+///
+/// ```rust
+/// use tabled::{Rotate, Style, Table};
+///
+/// let table = Table::new(&[(0, "Grodno"), (1, "Minsk")])
+///     .with(Rotate::Transpose)
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " i32  |   0    |   1   \n",
+///         "------+--------+-------\n",
+///         " &str | Grodno | Minsk \n",
+///     )
+/// );
+/// ```
+///
+/// Used with [crate::Modify] it rotates a content of cells instead, one char per line,
+/// which is handy for very narrow columns of matrix-like tables.
+/// A content is rotated clockwise by [Rotate::Right], counterclockwise by [Rotate::Left]
+/// and flipped upside down by [Rotate::Top] and [Rotate::Bottom].
+/// Settings of cells like an alignment are kept.
+///
+/// ```rust
+/// use tabled::{Modify, Rotate, Row, Style, Table};
+///
+/// let table = Table::new(&[(1, 0, 1)])
+///     .with(Modify::new(Row(..1)).with(Rotate::Left))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " 2 | 2 | 2 \n",
+///         " 3 | 3 | 3 \n",
+///         " i | i | i \n",
+///         "---+---+---\n",
+///         " 1 | 0 | 1 \n",
+///     )
+/// );
+/// ```
 #[derive(Debug)]
 pub enum Rotate {
     Left,
     Right,
     Top,
     Bottom,
+    Transpose,
 }
 
 impl TableOption for Rotate {
@@ -54,10 +103,68 @@ impl TableOption for Rotate {
                 *grid = new;
             }
             Self::Top => Self::Bottom.change(grid),
+            Self::Transpose => {
+                let mut new = Grid::new(grid.count_columns(), grid.count_rows());
+                for row in 0..grid.count_rows() {
+                    for column in 0..grid.count_columns() {
+                        // a span over columns becomes a span over rows and vice versa
+                        let settings = grid.get_cell_settings(row, column);
+                        let span = settings.get_span().unwrap_or(1);
+                        let row_span = settings.get_row_span().unwrap_or(1);
+                        let settings = settings.set_span(row_span).set_row_span(span);
+                        new.set(Entity::Cell(column, row), settings);
+                    }
+                }
+
+                *grid = new;
+            }
         }
     }
 }
 
+impl CellOption for Rotate {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        let lines = content
+            .lines()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        let char_at = |line: usize, i: usize| lines[line].get(i).copied().unwrap_or(' ');
+
+        let rotated: Vec<String> = match self {
+            Self::Right => (0..width)
+                .map(|i| {
+                    (0..lines.len())
+                        .rev()
+                        .map(|line| char_at(line, i))
+                        .collect()
+                })
+                .collect(),
+            Self::Left => (0..width)
+                .rev()
+                .map(|i| (0..lines.len()).map(|line| char_at(line, i)).collect())
+                .collect(),
+            Self::Transpose => (0..width)
+                .map(|i| (0..lines.len()).map(|line| char_at(line, i)).collect())
+                .collect(),
+            Self::Top | Self::Bottom => lines
+                .iter()
+                .rev()
+                .map(|line| line.iter().collect())
+                .collect(),
+        };
+
+        let content = rotated
+            .iter()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +214,13 @@ mod tests {
             table().with(Rotate::Top).with(Rotate::Top).to_string(),
             table().to_string()
         );
+        assert_eq!(
+            table()
+                .with(Rotate::Transpose)
+                .with(Rotate::Transpose)
+                .to_string(),
+            table().to_string()
+        );
     }
 
     #[test]
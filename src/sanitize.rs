@@ -0,0 +1,104 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{CellOption, TableOption};
+use papergrid::{Entity, Grid, Settings};
+
+/// Sanitize removes or makes visible control characters of cell contents,
+/// so an untrusted input can't break a frame of a [Table].
+///
+/// Line breaks and tabs are kept as they are handled by the layout,
+/// a `\r\n` line ending is turned into `\n`.
+/// An escape character is sanitized as well, so it must be applied before colors are set.
+/// Raw cells are left as they are.
+///
+/// It can be applied to a whole table or to particular cells by [crate::Modify].
+///
+/// ```rust
+/// use tabled::{Sanitize, Style, Table};
+///
+/// let table = Table::new(["ab\u{8}c\rd"])
+///     .with(Sanitize::Replace)
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "  &str  \n",
+///         "--------\n",
+///         " ab␈c␍d \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sanitize {
+    /// Control characters are removed.
+    Strip,
+    /// Control characters are replaced by their symbols like `␍`,
+    /// characters which have no symbol are replaced by `�`.
+    Replace,
+    /// Control characters are replaced by escape sequences like `\r` or `\u{8}`.
+    Escape,
+}
+
+impl Sanitize {
+    fn sanitize(&self, text: &str) -> String {
+        let mut sanitized = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                continue;
+            }
+
+            if c == '\n' || c == '\t' || !c.is_control() {
+                sanitized.push(c);
+                continue;
+            }
+
+            match self {
+                Self::Strip => {}
+                Self::Replace => sanitized.push(control_picture(c)),
+                Self::Escape => sanitized.extend(c.escape_default()),
+            }
+        }
+
+        sanitized
+    }
+}
+
+impl CellOption for Sanitize {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if grid.is_cell_raw(row, column) {
+            return;
+        }
+
+        let content = grid.get_cell_content(row, column);
+        if !content
+            .chars()
+            .any(|c| c.is_control() && c != '\n' && c != '\t')
+        {
+            return;
+        }
+
+        let content = self.sanitize(content);
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+impl TableOption for Sanitize {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                self.change_cell(grid, row, column);
+            }
+        }
+    }
+}
+
+// symbols of the Control Pictures block exist only for C0 characters and DEL
+fn control_picture(c: char) -> char {
+    match c {
+        '\u{0}'..='\u{1f}' => char::from_u32(0x2400 + c as u32).unwrap_or('\u{fffd}'),
+        '\u{7f}' => '\u{2421}',
+        _ => '\u{fffd}',
+    }
+}
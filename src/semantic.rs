@@ -0,0 +1,103 @@
+use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+#[cfg(feature = "color")]
+use papergrid::Color;
+use papergrid::{Entity, Grid, Settings};
+
+/// Semantic marks a cell by what its content means rather than by how it looks,
+/// so it's rendered appropriately by each output of a [Table].
+///
+/// | Semantic | Terminal (`color` feature) | HTML                  | Markdown   |
+/// |----------|----------------------------|-----------------------|------------|
+/// | Emphasis | bold                       | `class="emphasis"`    | `**text**` |
+/// | Warning  | yellow                     | `class="warning"`     | `**text**` |
+/// | Muted    | dim                        | `class="muted"`       | `_text_`   |
+///
+/// ```rust
+/// use tabled::{Cell, Modify, Semantic, Table};
+///
+/// let table = Table::new(["ok", "disk is full"])
+///     .with(Modify::new(Cell(2, 0)).with(Semantic::Warning));
+///
+/// assert_eq!(
+///     table.to_markdown(),
+///     concat!(
+///         "| &str |\n",
+///         "|---|\n",
+///         "| ok |\n",
+///         "| **disk is full** |\n",
+///     )
+/// );
+///
+/// assert_eq!(
+///     table.to_html(),
+///     concat!(
+///         "<table>\n",
+///         "<tr><th>&amp;str</th></tr>\n",
+///         "<tr><td>ok</td></tr>\n",
+///         "<tr><td class=\"warning\">disk is full</td></tr>\n",
+///         "</table>\n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Semantic {
+    /// An important content.
+    Emphasis,
+    /// A content which needs attention.
+    Warning,
+    /// A secondary content.
+    Muted,
+}
+
+impl Semantic {
+    /// Class returns a name which is used as a CSS class in HTML.
+    pub fn class(&self) -> &'static str {
+        match self {
+            Self::Emphasis => "emphasis",
+            Self::Warning => "warning",
+            Self::Muted => "muted",
+        }
+    }
+
+    /// From_class returns a semantic by its [Semantic::class] name.
+    pub fn from_class(class: &str) -> Option<Self> {
+        [Self::Emphasis, Self::Warning, Self::Muted]
+            .iter()
+            .copied()
+            .find(|semantic| semantic.class() == class)
+    }
+
+    /// Color returns a color which is used in a terminal.
+    #[cfg(feature = "color")]
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Emphasis => Color::bold(),
+            Self::Warning => Color::yellow(),
+            Self::Muted => Color::dim(),
+        }
+    }
+
+    /// Markdown wraps a text in Markdown markers.
+    pub fn markdown(&self, text: &str) -> String {
+        if text.is_empty() {
+            return String::new();
+        }
+
+        match self {
+            Self::Emphasis | Self::Warning => format!("**{}**", text),
+            Self::Muted => format!("_{}_", text),
+        }
+    }
+}
+
+impl CellOption for Semantic {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let settings = Settings::new().class(self.class());
+        #[cfg(feature = "color")]
+        let settings = settings.color(self.color());
+
+        grid.set(Entity::Cell(row, column), settings)
+    }
+}
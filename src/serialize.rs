@@ -0,0 +1,683 @@
+use crate::{new_grid, Table};
+use papergrid::{Entity, Settings};
+use serde::ser::{self, Serialize, Serializer};
+use std::fmt;
+
+impl Table {
+    /// From_serialize creates a [Table] from values which implement [Serialize],
+    /// so types which can't derive [crate::Tabled] can be shown as well.
+    ///
+    /// Fields of structs and keys of maps become columns in order of their first appearance,
+    /// a cell of a missing field is empty.
+    /// Tuples and sequences are split into columns by an index,
+    /// any other value takes a single column named by its type.
+    /// Nested values are written inline like `[1, 2]` or `{x: 1}`.
+    ///
+    /// ```rust
+    /// use serde::Serialize;
+    /// use tabled::{Style, Table};
+    ///
+    /// #[derive(Serialize)]
+    /// struct Language {
+    ///     name: &'static str,
+    ///     year: u16,
+    ///     typing: Option<&'static str>,
+    /// }
+    ///
+    /// let languages = [
+    ///     Language { name: "Rust", year: 2010, typing: Some("static") },
+    ///     Language { name: "Lisp", year: 1958, typing: None },
+    /// ];
+    ///
+    /// let table = Table::from_serialize(&languages).unwrap().with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " name | year | typing \n",
+    ///         "------+------+--------\n",
+    ///         " Rust | 2010 | static \n",
+    ///         " Lisp | 1958 |        \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn from_serialize<T: Serialize>(items: &[T]) -> Result<Self, SerializeError> {
+        let name = short_type_name(std::any::type_name::<T>());
+        let rows = items
+            .iter()
+            .map(|item| item.serialize(RowSerializer { name: &name }))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut headers: Vec<String> = Vec::new();
+        for (header, _) in rows.iter().flatten() {
+            if !headers.contains(header) {
+                headers.push(header.clone());
+            }
+        }
+
+        let mut grid = new_grid(rows.len() + 1, headers.len());
+        for (column, header) in headers.iter().enumerate() {
+            grid.set(
+                Entity::Cell(0, column),
+                Settings::new().text(header.as_str()),
+            );
+        }
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (header, value) in cells {
+                let column = headers.iter().position(|h| *h == header).unwrap();
+                grid.set(Entity::Cell(row + 1, column), Settings::new().text(value));
+            }
+        }
+
+        Ok(Self { grid })
+    }
+}
+
+/// SerializeError is returned by [Table::from_serialize] when a value fails to serialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeError(String);
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+// drops module paths, so `alloc::string::String` is shown as `String`
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment = 0;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            short.truncate(segment);
+            continue;
+        }
+
+        short.push(c);
+        if !(c.is_alphanumeric() || c == '_') {
+            segment = short.len();
+        }
+    }
+
+    short
+}
+
+type Row = Vec<(String, String)>;
+
+// RowSerializer splits a value into named cells of a row
+struct RowSerializer<'a> {
+    name: &'a str,
+}
+
+impl RowSerializer<'_> {
+    fn single(self, value: String) -> Result<Row, SerializeError> {
+        Ok(vec![(self.name.to_owned(), value)])
+    }
+}
+
+macro_rules! serialize_single {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Row, SerializeError> {
+                let value = ValueSerializer.$method(v)?;
+                self.single(value)
+            }
+        )*
+    };
+}
+
+impl<'a> Serializer for RowSerializer<'a> {
+    type Ok = Row;
+    type Error = SerializeError;
+    type SerializeSeq = RowCompound;
+    type SerializeTuple = RowCompound;
+    type SerializeTupleStruct = RowCompound;
+    type SerializeTupleVariant = RowCompound;
+    type SerializeMap = RowCompound;
+    type SerializeStruct = RowCompound;
+    type SerializeStructVariant = RowCompound;
+
+    serialize_single! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    }
+
+    fn serialize_none(self) -> Result<Row, SerializeError> {
+        self.single(String::new())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Row, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Row, SerializeError> {
+        self.single(String::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Row, SerializeError> {
+        self.single(variant.to_owned())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Row, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Row, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<RowCompound, SerializeError> {
+        Ok(RowCompound::default())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<RowCompound, SerializeError> {
+        Ok(RowCompound::default())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<RowCompound, SerializeError> {
+        Ok(RowCompound::default())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<RowCompound, SerializeError> {
+        Ok(RowCompound::default())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<RowCompound, SerializeError> {
+        Ok(RowCompound::default())
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<RowCompound, SerializeError> {
+        Ok(RowCompound::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<RowCompound, SerializeError> {
+        Ok(RowCompound::default())
+    }
+}
+
+// RowCompound collects cells of a row, elements of sequences are named by their index
+#[derive(Default)]
+struct RowCompound {
+    cells: Row,
+    key: Option<String>,
+}
+
+impl RowCompound {
+    fn push<T: ?Sized + Serialize>(
+        &mut self,
+        name: String,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        let value = value.serialize(ValueSerializer)?;
+        self.cells.push((name, value));
+        Ok(())
+    }
+
+    fn push_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let name = self.cells.len().to_string();
+        self.push(name, value)
+    }
+}
+
+impl ser::SerializeSeq for RowCompound {
+    type Ok = Row;
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Row, SerializeError> {
+        Ok(self.cells)
+    }
+}
+
+impl ser::SerializeTuple for RowCompound {
+    type Ok = Row;
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Row, SerializeError> {
+        Ok(self.cells)
+    }
+}
+
+impl ser::SerializeTupleStruct for RowCompound {
+    type Ok = Row;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Row, SerializeError> {
+        Ok(self.cells)
+    }
+}
+
+impl ser::SerializeTupleVariant for RowCompound {
+    type Ok = Row;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> Result<Row, SerializeError> {
+        Ok(self.cells)
+    }
+}
+
+impl ser::SerializeMap for RowCompound {
+    type Ok = Row;
+    type Error = SerializeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), SerializeError> {
+        self.key = Some(key.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let key = self.key.take().unwrap_or_default();
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Row, SerializeError> {
+        Ok(self.cells)
+    }
+}
+
+impl ser::SerializeStruct for RowCompound {
+    type Ok = Row;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Row, SerializeError> {
+        Ok(self.cells)
+    }
+}
+
+impl ser::SerializeStructVariant for RowCompound {
+    type Ok = Row;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Row, SerializeError> {
+        Ok(self.cells)
+    }
+}
+
+// ValueSerializer writes a value of a cell, compound values are written inline
+struct ValueSerializer;
+
+macro_rules! serialize_display {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<String, SerializeError> {
+                Ok(v.to_string())
+            }
+        )*
+    };
+}
+
+impl Serializer for ValueSerializer {
+    type Ok = String;
+    type Error = SerializeError;
+    type SerializeSeq = ValueCompound;
+    type SerializeTuple = ValueCompound;
+    type SerializeTupleStruct = ValueCompound;
+    type SerializeTupleVariant = ValueCompound;
+    type SerializeMap = ValueCompound;
+    type SerializeStruct = ValueCompound;
+    type SerializeStructVariant = ValueCompound;
+
+    serialize_display! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_unit_struct(&'static str),
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<String, SerializeError> {
+        Ok(format!("{:?}", v))
+    }
+
+    fn serialize_none(self) -> Result<String, SerializeError> {
+        Ok(String::new())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<String, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<String, SerializeError> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<String, SerializeError> {
+        Ok(variant.to_owned())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<String, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<String, SerializeError> {
+        Ok(format!("{}({})", variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<ValueCompound, SerializeError> {
+        Ok(ValueCompound::new("", '[', ']'))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<ValueCompound, SerializeError> {
+        Ok(ValueCompound::new("", '(', ')'))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _: usize,
+    ) -> Result<ValueCompound, SerializeError> {
+        Ok(ValueCompound::new(name, '(', ')'))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<ValueCompound, SerializeError> {
+        Ok(ValueCompound::new(variant, '(', ')'))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<ValueCompound, SerializeError> {
+        Ok(ValueCompound::new("", '{', '}'))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<ValueCompound, SerializeError> {
+        Ok(ValueCompound::new("", '{', '}'))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<ValueCompound, SerializeError> {
+        Ok(ValueCompound::new(variant, '{', '}'))
+    }
+}
+
+// ValueCompound writes elements of a compound value like `name[a, b]` or `{key: value}`
+struct ValueCompound {
+    text: String,
+    close: char,
+    is_empty: bool,
+}
+
+impl ValueCompound {
+    fn new(name: &str, open: char, close: char) -> Self {
+        let mut text = String::from(name);
+        text.push(open);
+
+        Self {
+            text,
+            close,
+            is_empty: true,
+        }
+    }
+
+    fn push<T: ?Sized + Serialize>(
+        &mut self,
+        key: Option<&str>,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        if !self.is_empty {
+            self.text.push_str(", ");
+        }
+        self.is_empty = false;
+
+        if let Some(key) = key {
+            self.text.push_str(key);
+            self.text.push_str(": ");
+        }
+
+        let value = value.serialize(ValueSerializer)?;
+        self.text.push_str(&value);
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<String, SerializeError> {
+        self.text.push(self.close);
+        Ok(self.text)
+    }
+}
+
+impl ser::SerializeSeq for ValueCompound {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(None, value)
+    }
+
+    fn end(self) -> Result<String, SerializeError> {
+        ValueCompound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for ValueCompound {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(None, value)
+    }
+
+    fn end(self) -> Result<String, SerializeError> {
+        ValueCompound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for ValueCompound {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.push(None, value)
+    }
+
+    fn end(self) -> Result<String, SerializeError> {
+        ValueCompound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for ValueCompound {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.push(None, value)
+    }
+
+    fn end(self) -> Result<String, SerializeError> {
+        ValueCompound::end(self)
+    }
+}
+
+impl ser::SerializeMap for ValueCompound {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), SerializeError> {
+        if !self.is_empty {
+            self.text.push_str(", ");
+        }
+        self.is_empty = false;
+
+        let key = key.serialize(ValueSerializer)?;
+        self.text.push_str(&key);
+        self.text.push_str(": ");
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let value = value.serialize(ValueSerializer)?;
+        self.text.push_str(&value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<String, SerializeError> {
+        ValueCompound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for ValueCompound {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(Some(key), value)
+    }
+
+    fn end(self) -> Result<String, SerializeError> {
+        ValueCompound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for ValueCompound {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(Some(key), value)
+    }
+
+    fn end(self) -> Result<String, SerializeError> {
+        ValueCompound::end(self)
+    }
+}
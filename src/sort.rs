@@ -0,0 +1,292 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+use std::{
+    cmp::{max, min, Ordering},
+    ops::Range,
+};
+
+/// Sort reorders rows of a [Table] by a content of a column.
+///
+/// A header row is kept in place.
+/// Rows joined by a row span are moved together and compared by their first row.
+/// By default a content is compared as a string,
+/// a custom comparator can be registered for any column
+/// so values like versions or sizes are sorted correctly.
+///
+/// Contents of columns without a comparator can be compared
+/// as numbers by [Sort::numeric] or in a natural order by [Sort::natural].
+///
+/// The registered comparators are kept when a sort column is changed,
+/// so the same [Sort] can be reused when a user picks another column.
+///
+/// ```rust
+/// use tabled::{Sort, Style, Table};
+///
+/// let data = [("1.10.0", 1), ("1.2.0", 2), ("1.9.1", 3)];
+///
+/// let mut sort = Sort::new("&str").comparator("&str", |a, b| {
+///     let version = |s: &str| s.split('.').map(|n| n.parse::<u32>().unwrap()).collect::<Vec<_>>();
+///     version(a).cmp(&version(b))
+/// });
+///
+/// let table = Table::new(data).with(&mut sort).with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "  &str  | i32 \n",
+///         "--------+-----\n",
+///         " 1.2.0  |  2  \n",
+///         " 1.9.1  |  3  \n",
+///         " 1.10.0 |  1  \n",
+///     )
+/// );
+/// ```
+pub struct Sort {
+    column: SortColumn,
+    order: SortOrder,
+    collation: Collation,
+    comparators: Vec<(SortColumn, Comparator)>,
+}
+
+type Comparator = Box<dyn Fn(&str, &str) -> Ordering>;
+
+impl Sort {
+    /// New creates a sort by a given column in an ascending order.
+    pub fn new<C: Into<SortColumn>>(column: C) -> Self {
+        Self {
+            column: column.into(),
+            order: SortOrder::Ascending,
+            collation: Collation::Lexical,
+            comparators: Vec::new(),
+        }
+    }
+
+    /// Column changes a column which is used for sorting.
+    pub fn column<C: Into<SortColumn>>(mut self, column: C) -> Self {
+        self.column = column.into();
+        self
+    }
+
+    /// Ascending sets an ascending order.
+    pub fn ascending(mut self) -> Self {
+        self.order = SortOrder::Ascending;
+        self
+    }
+
+    /// Descending sets a descending order.
+    pub fn descending(mut self) -> Self {
+        self.order = SortOrder::Descending;
+        self
+    }
+
+    /// Numeric compares contents as numbers, so `9` goes before `10` and `-1.5` before `0.25`.
+    ///
+    /// Contents which are not numbers go after numbers and are compared as strings.
+    /// It's used for columns without a registered comparator.
+    pub fn numeric(mut self) -> Self {
+        self.collation = Collation::Numeric;
+        self
+    }
+
+    /// Natural compares contents as strings where runs of digits are compared as numbers,
+    /// so `file2` goes before `file10`.
+    ///
+    /// It's used for columns without a registered comparator.
+    pub fn natural(mut self) -> Self {
+        self.collation = Collation::Natural;
+        self
+    }
+
+    /// Comparator registers a comparator for a column.
+    ///
+    /// A comparator registered later for the same column takes precedence.
+    pub fn comparator<C, F>(mut self, column: C, f: F) -> Self
+    where
+        C: Into<SortColumn>,
+        F: Fn(&str, &str) -> Ordering + 'static,
+    {
+        self.comparators.push((column.into(), Box::new(f)));
+        self
+    }
+}
+
+/// SortColumn references a column either by an index or by a header name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortColumn {
+    /// A column index.
+    Index(usize),
+    /// A column header, which is a content of a first row.
+    Name(String),
+}
+
+impl SortColumn {
+    fn index(&self, grid: &Grid) -> Option<usize> {
+        match self {
+            Self::Index(index) if *index < grid.count_columns() => Some(*index),
+            Self::Index(_) => None,
+            Self::Name(name) => {
+                (0..grid.count_columns()).find(|&column| grid.get_cell_content(0, column) == name)
+            }
+        }
+    }
+}
+
+impl From<usize> for SortColumn {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl From<&str> for SortColumn {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_owned())
+    }
+}
+
+impl From<String> for SortColumn {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Collation {
+    Lexical,
+    Numeric,
+    Natural,
+}
+
+impl Collation {
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::Lexical => a.cmp(b),
+            Self::Numeric => numeric_cmp(a, b),
+            Self::Natural => natural_cmp(a, b),
+        }
+    }
+}
+
+impl TableOption for Sort {
+    fn change(&mut self, grid: &mut Grid) {
+        let column = match self.column.index(grid) {
+            Some(column) => column,
+            None => return,
+        };
+
+        let comparator = self
+            .comparators
+            .iter()
+            .rev()
+            .find(|(c, _)| c.index(grid) == Some(column))
+            .map(|(_, f)| f);
+
+        // rows joined by a row span are moved together and compared by their first row
+        let blocks = row_blocks(grid);
+        let mut sorted = blocks.iter().skip(1).cloned().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| {
+            let a = grid.get_cell_content(a.start, column);
+            let b = grid.get_cell_content(b.start, column);
+            let ordering = match comparator {
+                Some(f) => f(a, b),
+                None => self.collation.compare(a, b),
+            };
+
+            match self.order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+
+        if let Some(header) = blocks.first() {
+            let order = std::iter::once(header.clone())
+                .chain(sorted)
+                .flatten()
+                .collect::<Vec<_>>();
+            grid.set_row_order(&order);
+        }
+    }
+}
+
+// splits rows into ranges which are not crossed by row spans,
+// the first range is a header
+fn row_blocks(grid: &Grid) -> Vec<Range<usize>> {
+    let count_rows = grid.count_rows();
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    while start < count_rows {
+        let mut end = start + 1;
+        let mut row = start;
+        while row < end {
+            for column in 0..grid.count_columns() {
+                let row_span = grid
+                    .get_cell_settings(row, column)
+                    .get_row_span()
+                    .unwrap_or(1);
+                end = max(end, min(row + row_span, count_rows));
+            }
+
+            row += 1;
+        }
+
+        blocks.push(start..end);
+        start = end;
+    }
+
+    blocks
+}
+
+fn numeric_cmp(a: &str, b: &str) -> Ordering {
+    let number = |s: &str| s.trim().parse::<f64>().ok().filter(|n| !n.is_nan());
+    match (number(a), number(b)) {
+        (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.cmp(b),
+    }
+}
+
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_number(&mut a);
+                let y = take_number(&mut b);
+                // leading zeros don't change a value
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+
+                a.next();
+                b.next();
+            }
+            (x, y) => return x.is_some().cmp(&y.is_some()),
+        }
+    }
+}
+
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut number = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+        number.push(c);
+    }
+
+    number
+}
@@ -0,0 +1,77 @@
+use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+use papergrid::{Entity, Grid};
+
+/// Span makes a cell cover several columns, several rows or a rectangular region of both.
+///
+/// Cells under a spanned cell are not rendered and borders are drawn only around it.
+/// A span can't go beyond the table.
+///
+/// ```rust
+/// use tabled::{Cell, Modify, Span, Table};
+///
+/// let data = [[1, 2, 3], [4, 5, 6]];
+///
+/// let table = Table::new(&data)
+///     .with(Modify::new(Cell(1, 1)).with(Span::new(2, 2)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+---+---+---+\n",
+///         "| 0 | 1 | 2 |\n",
+///         "+---+---+---+\n",
+///         "| 1 |   2   |\n",
+///         "+---+       |\n",
+///         "| 4 |       |\n",
+///         "+---+-------+\n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Span {
+    rows: Option<usize>,
+    columns: Option<usize>,
+}
+
+impl Span {
+    /// New creates a span over a region of `rows` rows and `columns` columns.
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows: Some(rows),
+            columns: Some(columns),
+        }
+    }
+
+    /// Column creates a span over `columns` columns, a row span is not changed.
+    pub fn column(columns: usize) -> Self {
+        Self {
+            rows: None,
+            columns: Some(columns),
+        }
+    }
+
+    /// Row creates a span over `rows` rows, a column span is not changed.
+    pub fn row(rows: usize) -> Self {
+        Self {
+            rows: Some(rows),
+            columns: None,
+        }
+    }
+}
+
+impl CellOption for Span {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        // a cell style is replaced as a whole, so the current one is taken as a base
+        let mut settings = grid.get_cell_settings(row, column);
+        if let Some(columns) = self.columns {
+            settings = settings.set_span(columns);
+        }
+        if let Some(rows) = self.rows {
+            settings = settings.set_row_span(rows);
+        }
+
+        grid.set(Entity::Cell(row, column), settings)
+    }
+}
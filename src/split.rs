@@ -0,0 +1,158 @@
+use crate::{Concat, Table};
+use std::ops::Range;
+
+/// Split breaks a too wide [Table] into several narrower ones by columns,
+/// so they can be printed one below another.
+///
+/// A key column can be repeated in each part, so rows of different parts can be matched.
+/// Parts are made by [Table::split].
+///
+/// ```rust
+/// use tabled::{Split, Style, Table};
+///
+/// let data = [("Grodno", 1, 2, 3), ("Minsk", 4, 5, 6)];
+///
+/// let parts = Table::new(&data)
+///     .split(&Split::columns(2).key(0))
+///     .into_iter()
+///     .map(|table| table.with(Style::psql()).to_string())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     parts,
+///     [
+///         concat!(
+///             "  &str  | i32 | i32 \n",
+///             "--------+-----+-----\n",
+///             " Grodno |  1  |  2  \n",
+///             " Minsk  |  4  |  5  \n",
+///         ),
+///         concat!(
+///             "  &str  | i32 \n",
+///             "--------+-----\n",
+///             " Grodno |  3  \n",
+///             " Minsk  |  6  \n",
+///         ),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Split {
+    limit: SplitLimit,
+    key: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+enum SplitLimit {
+    Columns(usize),
+    Width(usize),
+}
+
+impl Split {
+    /// Columns splits a table into parts of at most `count` columns, not counting a key column.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `count` is 0.
+    pub fn columns(count: usize) -> Self {
+        assert!(count > 0, "count must be greater than 0");
+
+        Self {
+            limit: SplitLimit::Columns(count),
+            key: None,
+        }
+    }
+
+    /// Width splits a table into parts which are at most `width` chars wide.
+    ///
+    /// A width of a part is estimated as if each column is surrounded by a border char.
+    /// A column which is wider than `width` on its own gets a separate part.
+    pub fn width(width: usize) -> Self {
+        Self {
+            limit: SplitLimit::Width(width),
+            key: None,
+        }
+    }
+
+    /// Key sets a column which is repeated as the first column of each part.
+    ///
+    /// A column out of a table is ignored.
+    pub fn key(mut self, column: usize) -> Self {
+        self.key = Some(column);
+        self
+    }
+}
+
+impl Table {
+    /// Split breaks the table into parts by columns according to a [Split].
+    ///
+    /// Cell settings are preserved, a span is truncated to fit a part.
+    /// A [crate::Style] is not copied so it must be applied to each part, like with [Table::extract].
+    pub fn split(&self, split: &Split) -> Vec<Table> {
+        let count_columns = self.grid.count_columns();
+        let key = split.key.filter(|&key| key < count_columns);
+        let columns = (0..count_columns)
+            .filter(|&column| Some(column) != key)
+            .collect::<Vec<_>>();
+
+        let chunks = match split.limit {
+            SplitLimit::Columns(count) => columns.chunks(count).map(<[usize]>::to_vec).collect(),
+            SplitLimit::Width(width) => {
+                let widths = self.measure().column_widths().to_vec();
+                let key_width = key.map_or(1, |key| widths[key] + 2);
+                chunk_by_width(&columns, &widths, width.saturating_sub(key_width))
+            }
+        };
+
+        if chunks.is_empty() {
+            return vec![self.clone()];
+        }
+
+        chunks
+            .into_iter()
+            .map(|chunk| {
+                let mut parts = key
+                    .map(|key| key..key + 1)
+                    .into_iter()
+                    .chain(adjacent_ranges(&chunk))
+                    .map(|range| self.extract(.., range));
+                let first = parts.next().expect("a chunk is never empty");
+                parts.fold(first, |table, part| table.with(Concat::horizontal(part)))
+            })
+            .collect()
+    }
+}
+
+// a key column may break a chunk into several ranges
+fn adjacent_ranges(columns: &[usize]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for &column in columns {
+        match ranges.last_mut() {
+            Some(range) if range.end == column => range.end += 1,
+            _ => ranges.push(column..column + 1),
+        }
+    }
+
+    ranges
+}
+
+// each column takes its width and a border on the right
+fn chunk_by_width(columns: &[usize], widths: &[usize], width: usize) -> Vec<Vec<usize>> {
+    let mut chunks: Vec<Vec<usize>> = Vec::new();
+    let mut chunk_width = 0;
+    for &column in columns {
+        let column_width = widths[column] + 1;
+        match chunks.last_mut() {
+            Some(chunk) if chunk_width + column_width <= width => {
+                chunk.push(column);
+                chunk_width += column_width;
+            }
+            _ => {
+                chunks.push(vec![column]);
+                chunk_width = column_width;
+            }
+        }
+    }
+
+    chunks
+}
@@ -0,0 +1,142 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+#[cfg(feature = "color")]
+use papergrid::Color;
+use papergrid::{Entity, Grid, Settings};
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+/// StaleRows marks rows which contents haven't changed for a given time.
+///
+/// It's meant for tables which are rendered again and again with fresh data,
+/// so the same [StaleRows] must be passed by a reference to each rendered [Table].
+/// Rows are matched between renders by a content of a key column, which is the first column by default.
+/// A header row is never marked, and rows which are missing in a rendered [Table] are forgotten.
+///
+/// A stale row is prefixed by a marker and, under the `color` feature, it's dimmed.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tabled::{StaleRows, Style, Table};
+///
+/// let mut stale = StaleRows::new(Duration::ZERO).marker("~");
+///
+/// Table::new([("api", 3), ("db", 1)]).with(&mut stale);
+/// let table = Table::new([("api", 4), ("db", 1)])
+///     .with(&mut stale)
+///     .with(Style::psql());
+///
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str | i32 \n",
+///         "------+-----\n",
+///         " api  |  4  \n",
+///         " ~db  |  1  \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct StaleRows {
+    window: Duration,
+    key_column: usize,
+    marker: String,
+    #[cfg(feature = "color")]
+    color: Option<Color>,
+    rows: HashMap<String, (Vec<String>, Instant)>,
+}
+
+impl StaleRows {
+    /// New creates a tracker which considers a row stale
+    /// when it hasn't changed for a `window` or longer.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            key_column: 0,
+            marker: String::new(),
+            #[cfg(feature = "color")]
+            color: Some(Color::dim()),
+            rows: HashMap::new(),
+        }
+    }
+
+    /// Key_column sets a column which identifies a row between renders.
+    pub fn key_column(mut self, column: usize) -> Self {
+        self.key_column = column;
+        self
+    }
+
+    /// Marker sets a text which is put before a content of a key cell of a stale row.
+    ///
+    /// By default there's no marker.
+    pub fn marker<S: Into<String>>(mut self, marker: S) -> Self {
+        self.marker = marker.into();
+        self
+    }
+
+    /// Color sets a color of stale rows.
+    ///
+    /// By default they are dimmed, `None` turns coloring off.
+    #[cfg(feature = "color")]
+    pub fn color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl TableOption for StaleRows {
+    fn change(&mut self, grid: &mut Grid) {
+        if self.key_column >= grid.count_columns() {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut stale = Vec::new();
+        let mut keys = HashSet::new();
+        for row in 1..grid.count_rows() {
+            let key = grid.get_cell_content(row, self.key_column).to_owned();
+            keys.insert(key.clone());
+            let contents = (0..grid.count_columns())
+                .map(|column| grid.get_cell_content(row, column).to_owned())
+                .collect::<Vec<_>>();
+
+            match self.rows.get_mut(&key) {
+                Some((previous, updated)) if *previous == contents => {
+                    if now.duration_since(*updated) >= self.window {
+                        stale.push(row);
+                    }
+                }
+                Some((previous, updated)) => {
+                    *previous = contents;
+                    *updated = now;
+                }
+                None => {
+                    self.rows.insert(key, (contents, now));
+                }
+            }
+        }
+
+        // rows which are gone are forgotten, so they start fresh if they come back
+        self.rows.retain(|key, _| keys.contains(key));
+
+        for row in stale {
+            if !self.marker.is_empty() {
+                let content = grid.get_cell_content(row, self.key_column);
+                let content = format!("{}{}", self.marker, content);
+                grid.set(
+                    Entity::Cell(row, self.key_column),
+                    Settings::new().text(content),
+                );
+            }
+
+            #[cfg(feature = "color")]
+            if let Some(color) = &self.color {
+                grid.set(Entity::Row(row), Settings::new().color(color.clone()));
+            }
+        }
+    }
+}
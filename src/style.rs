@@ -1,7 +1,5 @@
-#[allow(unused)]
-use crate::Table;
-use crate::TableOption;
-use papergrid::{Border, Grid};
+use crate::{Alignment, FormatFrom, Head, Modify, Table, TableOption};
+use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity, Grid, Settings};
 
 /// Style is responsible for a look of a [Table].
 ///
@@ -20,11 +18,17 @@ use papergrid::{Border, Grid};
 ///
 /// println!("{}", table);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     frame: Frame,
     header_split_line: Option<Line>,
     split: Option<Line>,
     inner_split_char: char,
+    padding: Option<(usize, usize)>,
+    alignment_h: Option<AlignmentHorizontal>,
+    alignment_v: Option<AlignmentVertical>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    trim: bool,
 }
 
 impl Style {
@@ -41,6 +45,7 @@ impl Style {
     ///     | 3  | Endeavouros  | https://endeavouros.com/  |
     ///     +----+--------------+---------------------------+
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
         let line = Line::bordered('-', '+', '+', '+');
 
@@ -147,6 +152,240 @@ impl Style {
         pseudo
     }
 
+    /// Extended style looks like the following table
+    ///
+    /// ```text
+    ///     ╔════╦══════════════╦═══════════════════════════╗
+    ///     ║ id ║ destribution ║           link            ║
+    ///     ╠════╬══════════════╬═══════════════════════════╣
+    ///     ║ 0  ║    Fedora    ║  https://getfedora.org/   ║
+    ///     ╠════╬══════════════╬═══════════════════════════╣
+    ///     ║ 2  ║   OpenSUSE   ║ https://www.opensuse.org/ ║
+    ///     ╠════╬══════════════╬═══════════════════════════╣
+    ///     ║ 3  ║ Endeavouros  ║ https://endeavouros.com/  ║
+    ///     ╚════╩══════════════╩═══════════════════════════╝
+    /// ```
+    pub fn extended() -> Self {
+        Self::new(
+            Frame {
+                left: Some('║'),
+                right: Some('║'),
+                bottom: Some(Line::bordered('═', '╩', '╚', '╝')),
+                top: Some(Line::bordered('═', '╦', '╔', '╗')),
+            },
+            Some(Line::bordered('═', '╬', '╠', '╣')),
+            Some(Line::bordered('═', '╬', '╠', '╣')),
+            '║',
+        )
+    }
+
+    /// Double_frame style has a double frame and single inner lines,
+    /// it looks like the following table
+    ///
+    /// ```text
+    ///     ╔════╤══════════════╤═══════════════════════════╗
+    ///     ║ id │ destribution │           link            ║
+    ///     ╟────┼──────────────┼───────────────────────────╢
+    ///     ║ 0  │    Fedora    │  https://getfedora.org/   ║
+    ///     ║ 2  │   OpenSUSE   │ https://www.opensuse.org/ ║
+    ///     ║ 3  │ Endeavouros  │ https://endeavouros.com/  ║
+    ///     ╚════╧══════════════╧═══════════════════════════╝
+    /// ```
+    ///
+    /// Junctions of single and double lines can be found by [resolve_stroke_intersection]
+    /// to mix the weights in a custom style.
+    pub fn double_frame() -> Self {
+        Self::new(
+            Frame {
+                left: Some('║'),
+                right: Some('║'),
+                bottom: Some(Line::bordered('═', '╧', '╚', '╝')),
+                top: Some(Line::bordered('═', '╤', '╔', '╗')),
+            },
+            Some(Line::bordered('─', '┼', '╟', '╢')),
+            None,
+            '│',
+        )
+    }
+
+    /// Dots style looks like the following table
+    ///
+    /// ```text
+    ///     ·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·
+    ///     ┆ id ┆ destribution ┆           link            ┆
+    ///     ·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·
+    ///     ┆ 0  ┆    Fedora    ┆  https://getfedora.org/   ┆
+    ///     ·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·
+    ///     ┆ 2  ┆   OpenSUSE   ┆ https://www.opensuse.org/ ┆
+    ///     ·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·
+    ///     ┆ 3  ┆ Endeavouros  ┆ https://endeavouros.com/  ┆
+    ///     ·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·
+    /// ```
+    ///
+    /// Characters of lines can be changed by [Self::horizontal] and [Self::vertical].
+    pub fn dots() -> Self {
+        let line = Line::bordered('┄', '·', '·', '·');
+
+        Self::new(
+            Frame {
+                bottom: Some(line.clone()),
+                top: Some(line.clone()),
+                left: Some('┆'),
+                right: Some('┆'),
+            },
+            Some(line.clone()),
+            Some(line),
+            '┆',
+        )
+    }
+
+    /// Rounded style looks like the following table
+    ///
+    /// ```text
+    ///     ╭────┬──────────────┬───────────────────────────╮
+    ///     │ id │ destribution │           link            │
+    ///     ├────┼──────────────┼───────────────────────────┤
+    ///     │ 0  │    Fedora    │  https://getfedora.org/   │
+    ///     │ 2  │   OpenSUSE   │ https://www.opensuse.org/ │
+    ///     │ 3  │ Endeavouros  │ https://endeavouros.com/  │
+    ///     ╰────┴──────────────┴───────────────────────────╯
+    /// ```
+    pub fn rounded() -> Self {
+        Self::new(
+            Frame {
+                left: Some('│'),
+                right: Some('│'),
+                bottom: Some(Line::bordered('─', '┴', '╰', '╯')),
+                top: Some(Line::bordered('─', '┬', '╭', '╮')),
+            },
+            Some(Line::bordered('─', '┼', '├', '┤')),
+            None,
+            '│',
+        )
+    }
+
+    /// Sharp style looks like the following table
+    ///
+    /// ```text
+    ///      id │ destribution │           link
+    ///     ────┼──────────────┼───────────────────────────
+    ///      0  │    Fedora    │  https://getfedora.org/
+    ///      2  │   OpenSUSE   │ https://www.opensuse.org/
+    ///      3  │ Endeavouros  │ https://endeavouros.com/
+    /// ```
+    ///
+    /// It draws exactly one horizontal line, the one under the header.
+    pub fn sharp() -> Self {
+        Self::new(Frame::default(), Some(Line::short('─', '┼')), None, '│')
+    }
+
+    /// Frame style draws only a rectangle around a table
+    /// and separates columns by spaces, it looks like the following table
+    ///
+    /// ```text
+    ///     ┌───────────────────────────────────────────────┐
+    ///     │ id   destribution             link            │
+    ///     │ 0       Fedora       https://getfedora.org/   │
+    ///     │ 2      OpenSUSE     https://www.opensuse.org/ │
+    ///     │ 3    Endeavouros    https://endeavouros.com/  │
+    ///     └───────────────────────────────────────────────┘
+    /// ```
+    pub fn frame() -> Self {
+        Self::new(
+            Frame {
+                left: Some('│'),
+                right: Some('│'),
+                bottom: Some(Line::bordered('─', '─', '└', '┘')),
+                top: Some(Line::bordered('─', '─', '┌', '┐')),
+            },
+            None,
+            None,
+            ' ',
+        )
+    }
+
+    /// Columns_only style draws only a separator between columns,
+    /// without a frame and horizontal lines, like an output of `ls -l` or `ps`.
+    ///
+    /// A separator is a single visible character surrounded by spaces, like `" │ "`,
+    /// or just spaces, like `"  "`.
+    /// The spaces are turned into a padding of cells, so a [Self::padding] set later replaces them.
+    /// Trailing whitespace of lines is trimmed.
+    ///
+    /// ```rust
+    /// use tabled::{Alignment, Style, Table};
+    ///
+    /// let data = [("Rust", 2010), ("Go", 2009)];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::columns_only("  ").alignment(Alignment::left()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(table, "&str  i32\nRust  2010\nGo    2009\n");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a separator has more than one visible character.
+    pub fn columns_only(separator: &str) -> Self {
+        let visible = separator.trim_matches(' ');
+        let mut chars = visible.chars();
+        let (inner, left, right) = match (chars.next(), chars.next()) {
+            (None, _) => (' ', 0, separator.chars().count().saturating_sub(1)),
+            (Some(c), None) => {
+                let left = separator.chars().take_while(|&c| c == ' ').count();
+                let right = separator.chars().rev().take_while(|&c| c == ' ').count();
+                (c, right, left)
+            }
+            _ => panic!(
+                "a column separator {:?} must have at most one visible character",
+                separator
+            ),
+        };
+
+        let mut style = Self::new(Frame::default(), None, None, inner).padding(left, right);
+        style.trim = true;
+        style
+    }
+
+    /// Preview_all renders a small sample table in every built-in style.
+    ///
+    /// It returns pairs of a style name and a rendered table,
+    /// which can be used to let a user pick a style.
+    ///
+    /// ```rust
+    /// use tabled::Style;
+    ///
+    /// let previews = Style::preview_all();
+    /// let (name, table) = &previews[2];
+    ///
+    /// assert_eq!(*name, "psql");
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " name | year \n",
+    ///         "------+------\n",
+    ///         " Rust | 2010 \n",
+    ///         "  Go  | 2009 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn preview_all() -> Vec<(&'static str, String)> {
+        let data = [("Rust", 2010), ("Go", 2009)];
+
+        Self::presets()
+            .into_iter()
+            .map(|(name, style)| {
+                let table = Table::new(data)
+                    .with(Modify::new(Head).with(FormatFrom(vec!["name", "year"])))
+                    .with(style)
+                    .to_string();
+
+                (name, table)
+            })
+            .collect()
+    }
+
     /// Left frame character.
     pub fn frame_left(mut self, frame: Option<char>) -> Self {
         self.frame.left = frame;
@@ -196,18 +435,128 @@ impl Style {
         self
     }
 
+    /// Horizontal sets a character of all horizontal lines, keeping their intersections and corners.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["a"])
+    ///     .with(Style::dots().horizontal('╌'))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "·╌╌╌╌╌╌·\n",
+    ///         "┆ &str ┆\n",
+    ///         "·╌╌╌╌╌╌·\n",
+    ///         "┆  a   ┆\n",
+    ///         "·╌╌╌╌╌╌·\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn horizontal(mut self, c: char) -> Self {
+        let lines = [
+            &mut self.frame.top,
+            &mut self.frame.bottom,
+            &mut self.header_split_line,
+            &mut self.split,
+        ];
+
+        for line in IntoIterator::into_iter(lines).flatten() {
+            line.main = c;
+        }
+
+        self
+    }
+
+    /// Vertical sets a character of all vertical lines, including a left and right frame.
+    pub fn vertical(mut self, c: char) -> Self {
+        self.inner_split_char = c;
+        for frame in [&mut self.frame.left, &mut self.frame.right] {
+            if frame.is_some() {
+                *frame = Some(c);
+            }
+        }
+
+        self
+    }
+
+    /// Padding sets a default left and right indent of cells which is applied together with the style.
+    ///
+    /// It's set for the whole table so options applied later to cells, rows or columns override it.
+    /// Presets pad cells by a space on each side, so switching to a preset brings its look back.
+    ///
+    /// ```rust
+    /// use tabled::{Alignment, Style, Table};
+    ///
+    /// let table = Table::new(["a", "bcd"])
+    ///     .with(Style::psql().padding(0, 2).alignment(Alignment::left()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "&str  \n",
+    ///         "------\n",
+    ///         "a     \n",
+    ///         "bcd   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn padding(mut self, left: usize, right: usize) -> Self {
+        self.padding = Some((left, right));
+        self
+    }
+
+    /// Alignment sets a default alignment of cells which is applied together with the style.
+    ///
+    /// It's set for the whole table so options applied later to cells, rows or columns override it.
+    /// Presets center cells horizontally and put them at the top.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Horizontal(alignment) => self.alignment_h = Some(alignment),
+            Alignment::Vertical(alignment) => self.alignment_v = Some(alignment),
+        }
+
+        self
+    }
+
+    fn presets() -> Vec<(&'static str, Self)> {
+        vec![
+            ("default", Self::default()),
+            ("noborder", Self::noborder()),
+            ("psql", Self::psql()),
+            ("github_markdown", Self::github_markdown()),
+            ("pseudo", Self::pseudo()),
+            ("pseudo_clean", Self::pseudo_clean()),
+            ("columns_only", Self::columns_only(" │ ")),
+            ("sharp", Self::sharp()),
+            ("rounded", Self::rounded()),
+            ("extended", Self::extended()),
+            ("double_frame", Self::double_frame()),
+            ("dots", Self::dots()),
+            ("frame", Self::frame()),
+        ]
+    }
+
     fn new(frame: Frame, header: Option<Line>, split: Option<Line>, inner: char) -> Self {
         Self {
             frame,
             split,
             header_split_line: header,
             inner_split_char: inner,
+            padding: Some((1, 1)),
+            alignment_h: Some(AlignmentHorizontal::Center),
+            alignment_v: Some(AlignmentVertical::Top),
+            trim: false,
         }
     }
 }
 
 /// Line represents a horizontal line on a [Table].
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
     main: char,
     intersection: char,
@@ -236,7 +585,185 @@ impl Line {
     }
 }
 
+/// Border represents characters of a frame around a set of cells.
+///
+/// A [None] character means that the original character is kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Border {
+    pub(crate) top: Option<char>,
+    pub(crate) bottom: Option<char>,
+    pub(crate) left: Option<char>,
+    pub(crate) right: Option<char>,
+    pub(crate) top_left_corner: Option<char>,
+    pub(crate) top_right_corner: Option<char>,
+    pub(crate) bottom_left_corner: Option<char>,
+    pub(crate) bottom_right_corner: Option<char>,
+}
+
+impl Border {
+    /// New constructs a border which keeps all characters,
+    /// they can be set by the other methods.
+    ///
+    /// A border is a [crate::CellOption], so it can be set for particular cells by [crate::Modify]
+    /// to box them separately; [crate::Highlight] draws it around a region of cells.
+    ///
+    /// ```rust
+    /// use tabled::{style::Border, Cell, Modify, Style, Table};
+    ///
+    /// let table = Table::new([("apples", 3), ("pears", 5), ("total", 8)])
+    ///     .with(Style::pseudo())
+    ///     .with(
+    ///         Modify::new(Cell(3, 1)).with(
+    ///             Border::new()
+    ///                 .top('━')
+    ///                 .bottom('━')
+    ///                 .left('┃')
+    ///                 .right('┃')
+    ///                 .top_left_corner('┏')
+    ///                 .top_right_corner('┓')
+    ///                 .bottom_left_corner('┗')
+    ///                 .bottom_right_corner('┛'),
+    ///         ),
+    ///     )
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "┌────────┬─────┐\n",
+    ///         "│  &str  │ i32 │\n",
+    ///         "├────────┼─────┤\n",
+    ///         "│ apples │  3  │\n",
+    ///         "├────────┼─────┤\n",
+    ///         "│ pears  │  5  │\n",
+    ///         "├────────┏━━━━━┓\n",
+    ///         "│ total  ┃  8  ┃\n",
+    ///         "└────────┗━━━━━┛\n",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// Like [crate::Highlight] it changes characters only where a style has a line.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Full constructs a border with all characters set.
+    #[allow(clippy::too_many_arguments)]
+    pub fn full(
+        top: char,
+        bottom: char,
+        left: char,
+        right: char,
+        top_left: char,
+        top_right: char,
+        bottom_left: char,
+        bottom_right: char,
+    ) -> Self {
+        Self {
+            top: Some(top),
+            bottom: Some(bottom),
+            left: Some(left),
+            right: Some(right),
+            top_left_corner: Some(top_left),
+            top_right_corner: Some(top_right),
+            bottom_left_corner: Some(bottom_left),
+            bottom_right_corner: Some(bottom_right),
+        }
+    }
+
+    /// Intersection returns a character of a border at a point where 4 cells meet.
+    ///
+    /// Arguments tell which of the cells around the point are inside of the bordered region.
+    /// [None] is returned if the point is not on the border.
+    ///
+    /// ```rust
+    /// use tabled::style::Border;
+    ///
+    /// let border = Border::full('-', '-', '|', '|', '1', '2', '3', '4');
+    ///
+    /// assert_eq!(border.intersection(false, false, false, true), Some('1'));
+    /// assert_eq!(border.intersection(false, false, true, true), Some('-'));
+    /// assert_eq!(border.intersection(true, true, true, true), None);
+    /// ```
+    pub fn intersection(
+        &self,
+        top_left: bool,
+        top_right: bool,
+        bottom_left: bool,
+        bottom_right: bool,
+    ) -> Option<char> {
+        match (top_left, top_right, bottom_left, bottom_right) {
+            (false, false, false, true) | (true, true, true, false) => self.top_left_corner,
+            (false, false, true, false) | (true, true, false, true) => self.top_right_corner,
+            (false, true, false, false) | (true, false, true, true) => self.bottom_left_corner,
+            (true, false, false, false) | (false, true, true, true) => self.bottom_right_corner,
+            (false, false, true, true) => self.top,
+            (true, true, false, false) => self.bottom,
+            (true, false, true, false) => self.right,
+            (false, true, false, true) => self.left,
+            (true, false, false, true) | (false, true, true, false) => self.top_left_corner,
+            (true, true, true, true) | (false, false, false, false) => None,
+        }
+    }
+
+    /// Filled constructs a border where all characters are the same.
+    pub fn filled(c: char) -> Self {
+        Self::full(c, c, c, c, c, c, c, c)
+    }
+
+    /// Top sets a top line character.
+    pub fn top(mut self, c: char) -> Self {
+        self.top = Some(c);
+        self
+    }
+
+    /// Bottom sets a bottom line character.
+    pub fn bottom(mut self, c: char) -> Self {
+        self.bottom = Some(c);
+        self
+    }
+
+    /// Left sets a left line character.
+    pub fn left(mut self, c: char) -> Self {
+        self.left = Some(c);
+        self
+    }
+
+    /// Right sets a right line character.
+    pub fn right(mut self, c: char) -> Self {
+        self.right = Some(c);
+        self
+    }
+
+    /// Top_left_corner sets a top left corner character.
+    pub fn top_left_corner(mut self, c: char) -> Self {
+        self.top_left_corner = Some(c);
+        self
+    }
+
+    /// Top_right_corner sets a top right corner character.
+    pub fn top_right_corner(mut self, c: char) -> Self {
+        self.top_right_corner = Some(c);
+        self
+    }
+
+    /// Bottom_left_corner sets a bottom left corner character.
+    pub fn bottom_left_corner(mut self, c: char) -> Self {
+        self.bottom_left_corner = Some(c);
+        self
+    }
+
+    /// Bottom_right_corner sets a bottom right corner character.
+    pub fn bottom_right_corner(mut self, c: char) -> Self {
+        self.bottom_right_corner = Some(c);
+        self
+    }
+}
+
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Frame {
     top: Option<Line>,
     bottom: Option<Line>,
@@ -251,10 +778,33 @@ impl TableOption for Style {
             let border = grid.get_border_mut(row);
             make_style(self, border, row == 0, row == count_rows - 1);
         }
+
+        // defaults are set globally so they don't reset settings of other cells
+        let mut settings = Settings::new();
+        if let Some((left, right)) = self.padding {
+            settings = settings.indent(left, right, 0, 0);
+        }
+        if let Some(alignment) = self.alignment_h {
+            settings = settings.alignment(alignment);
+        }
+        if let Some(alignment) = self.alignment_v {
+            settings = settings.vertical_alignment(alignment);
+        }
+
+        grid.set(Entity::Global, settings);
+
+        if self.trim {
+            grid.set_trim_trailing_whitespace(true);
+        }
     }
 }
 
-fn make_style(style: &Style, border: &mut Border, is_first_row: bool, is_last_row: bool) {
+fn make_style(
+    style: &Style,
+    border: &mut papergrid::Border,
+    is_first_row: bool,
+    is_last_row: bool,
+) {
     let border = border.empty();
 
     if is_first_row {
@@ -267,7 +817,13 @@ fn make_style(style: &Style, border: &mut Border, is_first_row: bool, is_last_ro
             );
         }
 
-        if let Some(line) = &style.header_split_line {
+        // a single row closes the frame if there's one
+        let bottom = match &style.frame.bottom {
+            Some(line) if is_last_row => Some(line),
+            _ => style.header_split_line.as_ref(),
+        };
+
+        if let Some(line) = bottom {
             border.bottom(
                 line.main,
                 line.intersection,
@@ -299,3 +855,108 @@ fn make_style(style: &Style, border: &mut Border, is_first_row: bool, is_last_ro
         style.frame.right,
     );
 }
+
+/// Resolve_intersection returns a box drawing character which joins lines
+/// going from a point to the given directions.
+///
+/// It can be used by custom exporters to draw correct junctions.
+///
+/// ```rust
+/// use tabled::style::resolve_intersection;
+///
+/// assert_eq!(resolve_intersection(true, true, true, true), '┼');
+/// assert_eq!(resolve_intersection(false, true, false, true), '┌');
+/// assert_eq!(resolve_intersection(true, true, false, true), '├');
+/// assert_eq!(resolve_intersection(false, false, true, true), '─');
+/// ```
+pub fn resolve_intersection(up: bool, down: bool, left: bool, right: bool) -> char {
+    match (up, down, left, right) {
+        (true, true, true, true) => '┼',
+        (true, true, true, false) => '┤',
+        (true, true, false, true) => '├',
+        (false, true, true, true) => '┬',
+        (true, false, true, true) => '┴',
+        (true, true, false, false) => '│',
+        (false, false, true, true) => '─',
+        (false, true, false, true) => '┌',
+        (false, true, true, false) => '┐',
+        (true, false, false, true) => '└',
+        (true, false, true, false) => '┘',
+        (true, false, false, false) => '╵',
+        (false, true, false, false) => '╷',
+        (false, false, true, false) => '╴',
+        (false, false, false, true) => '╶',
+        (false, false, false, false) => ' ',
+    }
+}
+
+/// Stroke is a weight of a line going from a junction, see [resolve_stroke_intersection].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stroke {
+    /// There's no line.
+    Empty,
+    /// A single line like `─`.
+    Single,
+    /// A double line like `═`.
+    Double,
+}
+
+/// Resolve_stroke_intersection returns a box drawing character which joins single
+/// and double lines going from a point to the given directions.
+///
+/// Box drawing characters have a single weight for a vertical and for a horizontal part,
+/// so if lines of different weights go up and down (or left and right) the double one is used.
+/// Ends of lines have no double form, a single one is returned for them.
+///
+/// ```rust
+/// use tabled::style::{resolve_stroke_intersection, Stroke};
+///
+/// assert_eq!(
+///     resolve_stroke_intersection(Stroke::Empty, Stroke::Double, Stroke::Double, Stroke::Double),
+///     '╦'
+/// );
+/// assert_eq!(
+///     resolve_stroke_intersection(Stroke::Empty, Stroke::Single, Stroke::Double, Stroke::Double),
+///     '╤'
+/// );
+/// assert_eq!(
+///     resolve_stroke_intersection(Stroke::Double, Stroke::Double, Stroke::Empty, Stroke::Single),
+///     '╟'
+/// );
+/// ```
+pub fn resolve_stroke_intersection(up: Stroke, down: Stroke, left: Stroke, right: Stroke) -> char {
+    let is_double = |a: Stroke, b: Stroke| a == Stroke::Double || b == Stroke::Double;
+    let vertical_double = is_double(up, down);
+    let horizontal_double = is_double(left, right);
+
+    // single, double vertical, double horizontal, double both
+    let chars = match (
+        up != Stroke::Empty,
+        down != Stroke::Empty,
+        left != Stroke::Empty,
+        right != Stroke::Empty,
+    ) {
+        (true, true, true, true) => ['┼', '╫', '╪', '╬'],
+        (true, true, true, false) => ['┤', '╢', '╡', '╣'],
+        (true, true, false, true) => ['├', '╟', '╞', '╠'],
+        (false, true, true, true) => ['┬', '╥', '╤', '╦'],
+        (true, false, true, true) => ['┴', '╨', '╧', '╩'],
+        (false, true, false, true) => ['┌', '╓', '╒', '╔'],
+        (false, true, true, false) => ['┐', '╖', '╕', '╗'],
+        (true, false, false, true) => ['└', '╙', '╘', '╚'],
+        (true, false, true, false) => ['┘', '╜', '╛', '╝'],
+        (true, true, false, false) => ['│', '║', '│', '║'],
+        (false, false, true, true) => ['─', '─', '═', '═'],
+        (up, down, left, right) => {
+            let c = resolve_intersection(up, down, left, right);
+            [c, c, c, c]
+        }
+    };
+
+    match (vertical_double, horizontal_double) {
+        (false, false) => chars[0],
+        (true, false) => chars[1],
+        (false, true) => chars[2],
+        (true, true) => chars[3],
+    }
+}
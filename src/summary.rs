@@ -0,0 +1,204 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::{Entity, Grid};
+
+type Aggregate = Box<dyn FnMut(&[&str]) -> String>;
+
+/// Summary appends a row with values computed over columns of a [Table],
+/// like totals of a report.
+///
+/// The header row is not taken into account.
+/// Built-in aggregates parse contents as numbers and skip cells which are not numbers,
+/// a custom one gets raw contents of a column.
+///
+/// The row takes settings of the last row, so it must be added before a [crate::Style].
+///
+/// ```rust
+/// use tabled::{Style, Summary, Table};
+///
+/// let data = [("rent", 1200, 2.5), ("food", 310, 1.0), ("fun", 95, 0.25)];
+///
+/// let table = Table::new(&data)
+///     .with(
+///         Summary::new("total")
+///             .sum(1)
+///             .aggregate(2, |values| format!("{} items", values.len())),
+///     )
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str  | i32  |   f64   \n",
+///         "-------+------+---------\n",
+///         " rent  | 1200 |   2.5   \n",
+///         " food  | 310  |    1    \n",
+///         "  fun  |  95  |  0.25   \n",
+///         " total | 1605 | 3 items \n",
+///     )
+/// );
+/// ```
+pub struct Summary {
+    label: String,
+    label_column: usize,
+    aggregates: Vec<(usize, Aggregate)>,
+}
+
+impl Summary {
+    /// New creates a summary row with a label in the first column.
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Self {
+            label: label.into(),
+            label_column: 0,
+            aggregates: Vec::new(),
+        }
+    }
+
+    /// Label_column sets a column where a label is put.
+    pub fn label_column(mut self, column: usize) -> Self {
+        self.label_column = column;
+        self
+    }
+
+    /// Sum adds up numbers of a column.
+    pub fn sum(self, column: usize) -> Self {
+        self.numbers(column, |numbers, precision| {
+            format_number(numbers.iter().fold(0.0, |sum, n| sum + n), precision)
+        })
+    }
+
+    /// Mean calculates an average of numbers of a column.
+    pub fn mean(self, column: usize) -> Self {
+        self.numbers(column, |numbers, precision| {
+            if numbers.is_empty() {
+                return String::new();
+            }
+
+            let mean = numbers.iter().sum::<f64>() / numbers.len() as f64;
+            let mean = format_number(mean, precision + 2);
+            // extra digits are kept only if they are not zeros
+            let min_length = mean.len() - 2 - if precision == 0 { 1 } else { 0 };
+            let trimmed = mean.trim_end_matches('0').trim_end_matches('.');
+            mean[..std::cmp::max(trimmed.len(), min_length)].to_owned()
+        })
+    }
+
+    /// Min finds the least number of a column.
+    pub fn min(self, column: usize) -> Self {
+        self.numbers(column, |numbers, precision| {
+            numbers
+                .iter()
+                .copied()
+                .reduce(f64::min)
+                .map_or_else(String::new, |n| format_number(n, precision))
+        })
+    }
+
+    /// Max finds the greatest number of a column.
+    pub fn max(self, column: usize) -> Self {
+        self.numbers(column, |numbers, precision| {
+            numbers
+                .iter()
+                .copied()
+                .reduce(f64::max)
+                .map_or_else(String::new, |n| format_number(n, precision))
+        })
+    }
+
+    /// Count counts not empty cells of a column.
+    pub fn count(self, column: usize) -> Self {
+        self.aggregate(column, |values| {
+            values
+                .iter()
+                .filter(|value| !value.trim().is_empty())
+                .count()
+                .to_string()
+        })
+    }
+
+    /// Aggregate sets a function which computes a summary of a column from its contents.
+    pub fn aggregate<F>(mut self, column: usize, f: F) -> Self
+    where
+        F: FnMut(&[&str]) -> String + 'static,
+    {
+        self.aggregates.push((column, Box::new(f)));
+        self
+    }
+
+    // numbers are passed together with the biggest amount of decimal digits among them
+    fn numbers<F>(self, column: usize, f: F) -> Self
+    where
+        F: Fn(&[f64], usize) -> String + 'static,
+    {
+        self.aggregate(column, move |values| {
+            let values = values
+                .iter()
+                .map(|value| value.trim())
+                .filter_map(|value| value.parse::<f64>().ok().map(|n| (n, value)))
+                .filter(|(n, _)| n.is_finite())
+                .collect::<Vec<_>>();
+            let precision = values
+                .iter()
+                .map(|(_, value)| {
+                    value
+                        .split_once('.')
+                        .map_or(0, |(_, fraction)| fraction.len())
+                })
+                .max()
+                .unwrap_or(0);
+            let numbers = values.into_iter().map(|(n, _)| n).collect::<Vec<_>>();
+
+            f(&numbers, precision)
+        })
+    }
+}
+
+impl std::fmt::Debug for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Summary")
+            .field("label", &self.label)
+            .field("label_column", &self.label_column)
+            .field(
+                "columns",
+                &self.aggregates.iter().map(|(c, _)| c).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl TableOption for Summary {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let mut contents = vec![String::new(); count_columns];
+        if self.label_column < count_columns {
+            contents[self.label_column] = self.label.clone();
+        }
+
+        for (column, aggregate) in &mut self.aggregates {
+            if *column < count_columns {
+                let values = grid.column(*column).skip(1).collect::<Vec<_>>();
+                contents[*column] = aggregate(&values);
+            }
+        }
+
+        let last = count_rows - 1;
+        grid.insert_row(count_rows);
+        for (column, content) in contents.into_iter().enumerate() {
+            let settings = grid
+                .get_cell_settings(last, column)
+                .text(content)
+                .set_span(1);
+            grid.set(Entity::Cell(count_rows, column), settings);
+        }
+    }
+}
+
+fn format_number(n: f64, precision: usize) -> String {
+    format!("{:.*}", precision, n)
+}
@@ -0,0 +1,35 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+
+/// TabWidth sets a distance between tab stops which `\t` characters of a [Table] are expanded to.
+///
+/// Tabs are expanded to spaces by default with a width of 4.
+/// A width of 0 removes tabs.
+///
+/// ```rust
+/// use tabled::{Style, TabWidth, Table};
+///
+/// let table = Table::new(["a\tb", "abc\td"])
+///     .with(Style::psql())
+///     .with(TabWidth(2));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str  \n",
+///         "-------\n",
+///         "  a b  \n",
+///         " abc d \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TabWidth(pub usize);
+
+impl TableOption for TabWidth {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_tab_width(self.0);
+    }
+}
@@ -0,0 +1,184 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{style::Line, Style, TableOption};
+#[cfg(feature = "color")]
+use crate::{BorderColor, Color};
+use papergrid::{Entity, Grid, Settings};
+
+/// Theme bundles a [Style], a padding and, under the `color` feature,
+/// a header emphasis, a border color and zebra striping.
+///
+/// A theme is applied by a single call and it can be followed by any other options
+/// which override its parts.
+///
+/// ```rust
+/// use tabled::{Theme, Table};
+///
+/// let table = Table::new([("Rust", 2010), ("Go", 2009)])
+///     .with(Theme::rounded())
+///     .to_string();
+///
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "╭──────┬──────╮\n",
+///         "│ &str │ i32  │\n",
+///         "├──────┼──────┤\n",
+///         "│ Rust │ 2010 │\n",
+///         "│  Go  │ 2009 │\n",
+///         "╰──────┴──────╯\n",
+///     )
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    style: Style,
+    padding: (usize, usize),
+    #[cfg(feature = "color")]
+    header: Option<Color>,
+    #[cfg(feature = "color")]
+    border: Option<Color>,
+    #[cfg(feature = "color")]
+    zebra: Option<Color>,
+}
+
+impl Theme {
+    /// New creates a theme with a given style and a default padding.
+    pub fn new(style: Style) -> Self {
+        Self {
+            style,
+            padding: (1, 1),
+            #[cfg(feature = "color")]
+            header: None,
+            #[cfg(feature = "color")]
+            border: None,
+            #[cfg(feature = "color")]
+            zebra: None,
+        }
+    }
+
+    /// Rounded theme uses a frame with rounded corners and a bold header.
+    ///
+    /// ```text
+    ///     ╭──────┬──────╮
+    ///     │ name │ year │
+    ///     ├──────┼──────┤
+    ///     │ Rust │ 2010 │
+    ///     │  Go  │ 2009 │
+    ///     ╰──────┴──────╯
+    /// ```
+    pub fn rounded() -> Self {
+        let style = Style::pseudo_clean()
+            .frame_top(Some(Line::bordered('─', '┬', '╭', '╮')))
+            .frame_bottom(Some(Line::bordered('─', '┴', '╰', '╯')));
+
+        let theme = Self::new(style);
+        #[cfg(feature = "color")]
+        let theme = theme.header_color(Color::bold());
+
+        theme
+    }
+
+    /// Dark theme uses a box drawing frame with dimmed borders, a bold header and dimmed even rows.
+    ///
+    /// ```text
+    ///     ┌──────┬──────┐
+    ///     │ name │ year │
+    ///     ├──────┼──────┤
+    ///     │ Rust │ 2010 │
+    ///     │  Go  │ 2009 │
+    ///     └──────┴──────┘
+    /// ```
+    pub fn dark() -> Self {
+        let theme = Self::new(Style::pseudo_clean());
+        #[cfg(feature = "color")]
+        let theme = theme
+            .header_color(Color::bold())
+            .border_color(Color::dim())
+            .zebra(Color::dim());
+
+        theme
+    }
+
+    /// Minimal theme has no frame, only a header line, and a bold header.
+    ///
+    /// ```text
+    ///      name   year
+    ///     ───────────────
+    ///      Rust   2010
+    ///       Go    2009
+    /// ```
+    pub fn minimal() -> Self {
+        let style = Style::noborder()
+            .header(Some(Line::short('─', '─')))
+            .inner(' ');
+
+        let theme = Self::new(style);
+        #[cfg(feature = "color")]
+        let theme = theme.header_color(Color::bold());
+
+        theme
+    }
+
+    /// Style sets a style of the theme.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Padding sets a left and right indent of all cells.
+    pub fn padding(mut self, left: usize, right: usize) -> Self {
+        self.padding = (left, right);
+        self
+    }
+
+    /// Header_color sets a color of a header content.
+    #[cfg(feature = "color")]
+    pub fn header_color(mut self, color: Color) -> Self {
+        self.header = Some(color);
+        self
+    }
+
+    /// Border_color sets a color of all borders.
+    #[cfg(feature = "color")]
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border = Some(color);
+        self
+    }
+
+    /// Zebra sets a color of every second row after a header.
+    #[cfg(feature = "color")]
+    pub fn zebra(mut self, color: Color) -> Self {
+        self.zebra = Some(color);
+        self
+    }
+}
+
+impl TableOption for Theme {
+    fn change(&mut self, grid: &mut Grid) {
+        self.style.change(grid);
+
+        // an indent is set globally so it doesn't reset settings of other cells,
+        // and it's set after a style so it replaces a default padding of the style
+        let (left, right) = self.padding;
+        grid.set(Entity::Global, Settings::new().indent(left, right, 0, 0));
+
+        #[cfg(feature = "color")]
+        {
+            if let Some(color) = &self.border {
+                BorderColor(color.clone()).change(grid);
+            }
+
+            if let Some(color) = &self.zebra {
+                for row in (2..grid.count_rows()).step_by(2) {
+                    grid.set(Entity::Row(row), Settings::new().color(color.clone()));
+                }
+            }
+
+            if let Some(color) = &self.header {
+                grid.set(Entity::Row(0), Settings::new().color(color.clone()));
+            }
+        }
+    }
+}
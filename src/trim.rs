@@ -0,0 +1,27 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::Grid;
+
+/// TrimTrailingWhitespace removes whitespace at the end of each line of a [Table].
+///
+/// Cells are padded to a width of their column, so a table without a right border
+/// has lines which end with spaces. They are dropped when lines are written.
+///
+/// ```rust
+/// use tabled::{Style, Table, TrimTrailingWhitespace};
+///
+/// let table = Table::new(["a", "bb"])
+///     .with(Style::noborder())
+///     .with(TrimTrailingWhitespace);
+///
+/// assert_eq!(table.to_string(), " &str\n  a\n  bb\n");
+/// ```
+#[derive(Debug)]
+pub struct TrimTrailingWhitespace;
+
+impl TableOption for TrimTrailingWhitespace {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_trim_trailing_whitespace(true);
+    }
+}
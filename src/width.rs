@@ -1,5 +1,9 @@
-use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+use crate::{CellOption, Object, TableOption};
 use papergrid::{Entity, Grid, Settings};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Using MaxWidth you can set a max width of an object on a [Grid].
 ///
@@ -25,31 +29,551 @@ where
     S: AsRef<str>;
 
 impl<S: AsRef<str>> CellOption for MaxWidth<S> {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        Truncate::right(self.0, self.1.as_ref()).change_cell(grid, row, column)
+    }
+}
+
+/// Truncate cuts a content of a cell which is longer than a given width.
+///
+/// Unlike [MaxWidth] it can keep a different part of a content,
+/// so it can be set per column, e.g. keep a file name of a path or a begining and an end of a hash.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Column, Modify, Object, Row, Style, Table, Truncate};
+///
+/// let data = [
+///     ("/home/user/projects/tabled/README.md", "53c0eb8e2dbb9f7b1e"),
+/// ];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Column(..1).not(Row(..1))).with(Truncate::left(9, "...")))
+///     .with(Modify::new(Column(1..).not(Row(..1))).with(Truncate::middle(6, "..")))
+///     .to_string();
+///
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "     &str     |   &str   \n",
+///         "--------------+----------\n",
+///         " ...README.md | 53c..b1e \n",
+///     )
+/// );
+/// ```
+///
+/// While working with colors you must setup `colors` feature.
+#[derive(Debug)]
+pub struct Truncate<S>
+where
+    S: AsRef<str>,
+{
+    width: usize,
+    filler: S,
+    position: TruncatePosition,
+}
+
+#[derive(Debug)]
+enum TruncatePosition {
+    Left,
+    Right,
+    Middle,
+}
+
+impl<S: AsRef<str>> Truncate<S> {
+    /// Left cuts a begining of a content, so its end is kept.
+    pub fn left(width: usize, filler: S) -> Self {
+        Self::new(width, filler, TruncatePosition::Left)
+    }
+
+    /// Right cuts an end of a content, so its begining is kept.
+    ///
+    /// It's the same as [MaxWidth].
+    pub fn right(width: usize, filler: S) -> Self {
+        Self::new(width, filler, TruncatePosition::Right)
+    }
+
+    /// Middle cuts a middle of a content, so its begining and end are kept.
+    pub fn middle(width: usize, filler: S) -> Self {
+        Self::new(width, filler, TruncatePosition::Middle)
+    }
+
+    fn new(width: usize, filler: S, position: TruncatePosition) -> Self {
+        Self {
+            width,
+            filler,
+            position,
+        }
+    }
+}
+
+impl<S: AsRef<str>> CellOption for Truncate<S> {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if grid.is_cell_raw(row, column) {
+            return;
+        }
+
+        let width = self.width;
+        let filler = self.filler.as_ref();
+
+        let content = grid.get_cell_content(row, column);
+        let length = graphemes_count(content);
+        if length <= width {
+            return;
+        }
+
+        let content = match self.position {
+            TruncatePosition::Right => format!("{}{}", cut(content, 0, width), filler),
+            TruncatePosition::Left => {
+                format!("{}{}", filler, cut(content, length - width, length))
+            }
+            TruncatePosition::Middle => {
+                let left = width - width / 2;
+                let right = width / 2;
+                format!(
+                    "{}{}{}",
+                    cut(content, 0, left),
+                    filler,
+                    cut(content, length - right, length)
+                )
+            }
+        };
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+/// Wrap splits a content of a cell into lines which are not longer than a given width.
+///
+/// Combined with an [crate::Object] it allows to wrap a header
+/// while a body of the same column is truncated.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Head, Modify, Object, Row, Full, Style, Table, Truncate, Wrap};
+///
+/// let data = [("Hello World", "2021")];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Head).with(Wrap(2)))
+///     .with(Modify::new(Full.not(Row(..1))).with(Truncate::right(3, "")))
+///     .to_string();
+///
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &s  | &s  \n",
+///         " tr  | tr  \n",
+///         "-----+-----\n",
+///         " Hel | 202 \n",
+///     )
+/// );
+/// ```
+///
+/// While working with colors you must setup `colors` feature.
+#[derive(Debug)]
+pub struct Wrap(pub usize);
+
+impl CellOption for Wrap {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
         let width = self.0;
-        let filler = self.1.as_ref();
+        if width == 0 || grid.is_cell_raw(row, column) {
+            return;
+        }
 
         let content = grid.get_cell_content(row, column);
-        let striped_content = strip(content, width);
+        let content = content
+            .lines()
+            .map(|line| {
+                let length = graphemes_count(line);
+                (0..std::cmp::max(length, 1))
+                    .step_by(width)
+                    .map(|start| cut(line, start, std::cmp::min(start + width, length)))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
 
-        let old_content_length = content.len();
-        let new_content_length = striped_content.len();
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
 
-        if new_content_length != old_content_length {
-            let content = format!("{}{}", striped_content, filler);
-            grid.set(Entity::Cell(row, column), Settings::new().text(content))
+/// PercentileWidth limits a width of cells located by an [Object] by a percentile of widths of their column,
+/// so a few outliers don't make the whole column wide.
+///
+/// A percentile is computed once per column before any cell is changed.
+/// Cells which are wider than the percentile are wrapped or truncated.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Full, PercentileWidth, Style, Table};
+///
+/// let data = ["a", "bb", "cc", "dd", "a very long value"];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(PercentileWidth::truncate(Full, 80, "~"))
+///     .to_string();
+///
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str  \n",
+///         "-------\n",
+///         "   a   \n",
+///         "  bb   \n",
+///         "  cc   \n",
+///         "  dd   \n",
+///         " a ve~ \n",
+///     )
+/// );
+/// ```
+///
+/// While working with colors you must setup `colors` feature.
+#[derive(Debug)]
+pub struct PercentileWidth<O, S = &'static str>
+where
+    S: AsRef<str>,
+{
+    target: O,
+    percentile: usize,
+    // a truncation filler, a content is wrapped if it's not set
+    filler: Option<S>,
+}
+
+impl<O> PercentileWidth<O>
+where
+    O: Object,
+{
+    /// Wrap splits contents of `target` cells which are wider than a given percentile of their column into lines.
+    ///
+    /// A percentile is a value in a range `0..=100`.
+    pub fn wrap(target: O, percentile: usize) -> Self {
+        Self {
+            target,
+            percentile,
+            filler: None,
+        }
+    }
+}
+
+impl<O, S> PercentileWidth<O, S>
+where
+    O: Object,
+    S: AsRef<str>,
+{
+    /// Truncate cuts contents of `target` cells which are wider than a given percentile of their column.
+    ///
+    /// A percentile is a value in a range `0..=100`.
+    pub fn truncate(target: O, percentile: usize, filler: S) -> Self {
+        Self {
+            target,
+            percentile,
+            filler: Some(filler),
+        }
+    }
+}
+
+impl<O, S> TableOption for PercentileWidth<O, S>
+where
+    O: Object,
+    S: AsRef<str>,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_rows == 0 {
+            return;
+        }
+
+        // a nearest rank method
+        let percentile = std::cmp::min(self.percentile, 100);
+        let rank = (percentile * count_rows).div_ceil(100).saturating_sub(1);
+        let limits = (0..count_columns)
+            .map(|column| {
+                let mut widths = (0..count_rows)
+                    .map(|row| content_width(grid.get_cell_content(row, column)))
+                    .collect::<Vec<_>>();
+                widths.sort_unstable();
+                widths[rank]
+            })
+            .collect::<Vec<_>>();
+
+        for (row, column) in self.target.cells(count_rows, count_columns) {
+            let width = limits[column];
+            if content_width(grid.get_cell_content(row, column)) <= width {
+                continue;
+            }
+
+            match &self.filler {
+                Some(filler) => {
+                    Truncate::right(width, filler.as_ref()).change_cell(grid, row, column)
+                }
+                None => Wrap(width).change_cell(grid, row, column),
+            }
         }
     }
 }
 
-fn strip(s: &str, width: usize) -> String {
+// a width of the longest line
+fn content_width(s: &str) -> usize {
+    s.lines().map(graphemes_count).max().unwrap_or(0)
+}
+
+/// Width is a set of options which change a width of a whole [Table].
+#[derive(Debug)]
+pub struct Width;
+
+impl Width {
+    /// Exact constructs an [ExactWidth] which makes a table exactly `width` characters wide.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::{Style, Table, Width};
+    ///
+    /// let data = [("Hello World", "2021")];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Width::exact(16))
+    ///     .to_string();
+    ///
+    /// # #[cfg(not(feature = "color"))]
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "   &str   | &st \n",
+    ///         "          |  r  \n",
+    ///         "----------+-----\n",
+    ///         " Hello Wo | 202 \n",
+    ///         "   rld    |  1  \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn exact(width: usize) -> ExactWidth {
+        ExactWidth { width }
+    }
+}
+
+/// ExactWidth expands or shrinks columns in proportion to their widths
+/// so a rendered table is exactly a given amount of characters wide.
+///
+/// Contents of shrunk columns are wrapped by their display width,
+/// and a spanned cell is wrapped by a width of all columns it spans.
+/// A column is never made narrower than its indent plus 1 character,
+/// so a table may stay wider than requested if there's no room for all columns.
+///
+/// It's constructed by [Width::exact].
+#[derive(Debug)]
+pub struct ExactWidth {
+    width: usize,
+}
+
+impl TableOption for ExactWidth {
+    fn change(&mut self, grid: &mut Grid) {
+        let measurement = grid.measure();
+        let widths = measurement.column_widths().to_vec();
+        let total = widths.iter().sum::<usize>();
+        if total == 0 || measurement.width() == self.width {
+            return;
+        }
+
+        // borders are kept as they are
+        let borders = measurement.width() - total;
+        let target = self.width.saturating_sub(borders);
+
+        let indents = (0..grid.count_columns())
+            .map(|column| {
+                (0..grid.count_rows())
+                    .map(|row| cell_indent(grid, row, column))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        // a column which gets less than its minimum is fixed on the minimum
+        // and the rest of the width is distributed again between other columns
+        let mut new_widths = vec![None; widths.len()];
+        loop {
+            let free = (0..widths.len())
+                .filter(|&i| new_widths[i].is_none())
+                .collect::<Vec<_>>();
+            let used = new_widths.iter().flatten().sum::<usize>();
+            let free_widths = free.iter().map(|&i| widths[i]).collect::<Vec<_>>();
+            let shares = distribute(&free_widths, target.saturating_sub(used));
+
+            let narrow = free
+                .iter()
+                .zip(&shares)
+                .filter(|(&i, &share)| share < indents[i] + 1)
+                .map(|(&i, _)| i)
+                .collect::<Vec<_>>();
+            if narrow.is_empty() {
+                for (i, share) in free.into_iter().zip(shares) {
+                    new_widths[i] = Some(share);
+                }
+
+                break;
+            }
+
+            for i in narrow {
+                new_widths[i] = Some(indents[i] + 1);
+            }
+        }
+
+        let new_widths = new_widths.into_iter().flatten().collect::<Vec<_>>();
+
+        // separators inside of a span are a part of a spanned cell
+        let offsets = measurement.column_offsets();
+        let span_width = |column: usize, span: usize| {
+            let last = column + span - 1;
+            let separators =
+                offsets[last] - offsets[column] - widths[column..last].iter().sum::<usize>();
+            new_widths[column..=last].iter().sum::<usize>() + separators
+        };
+
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        let mut covered = vec![vec![false; count_columns]; count_rows];
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                if covered[row][column] {
+                    continue;
+                }
+
+                let span = grid.get_cell_span(row, column);
+                let row_span = grid
+                    .get_cell_settings(row, column)
+                    .get_row_span()
+                    .unwrap_or(1)
+                    .clamp(1, count_rows - row);
+                for covered in &mut covered[row..row + row_span] {
+                    for covered in &mut covered[column..column + span] {
+                        *covered = true;
+                    }
+                }
+
+                let is_shrunk = (column..column + span).any(|c| new_widths[c] < widths[c]);
+                if is_shrunk && !grid.is_cell_raw(row, column) {
+                    let width =
+                        span_width(column, span).saturating_sub(cell_indent(grid, row, column));
+                    let content = wrap_by_width(grid.get_cell_content(row, column), width);
+                    grid.set(Entity::Cell(row, column), Settings::new().text(content));
+                }
+            }
+        }
+
+        for (column, &width) in new_widths.iter().enumerate() {
+            grid.set_min_column_width(column, width);
+        }
+    }
+}
+
+// a left and right indent of a cell
+fn cell_indent(grid: &Grid, row: usize, column: usize) -> usize {
+    let settings = grid.get_cell_settings(row, column);
+    let (left, right, _, _) = settings.get_indent().unwrap_or_default();
+    left + right
+}
+
+// splits a total in proportion to given widths by a largest remainder method
+fn distribute(widths: &[usize], total: usize) -> Vec<usize> {
+    let sum = widths.iter().sum::<usize>();
+    if sum == 0 {
+        return vec![0; widths.len()];
+    }
+
+    let mut result = widths
+        .iter()
+        .map(|&width| width * total / sum)
+        .collect::<Vec<_>>();
+
+    let mut remainders = (0..widths.len()).collect::<Vec<_>>();
+    remainders.sort_by_key(|&i| std::cmp::Reverse(widths[i] * total % sum));
+
+    let left = total - result.iter().sum::<usize>();
+    for &i in remainders.iter().take(left) {
+        result[i] += 1;
+    }
+
+    result
+}
+
+// contents are cut by grapheme clusters,
+// so a multi-byte character or an emoji sequence is never split in halves
+fn cut(s: &str, start: usize, end: usize) -> String {
     #[cfg(not(feature = "color"))]
     {
-        s.chars().take(width).collect::<String>()
+        s.graphemes(true)
+            .skip(start)
+            .take(end - start)
+            .collect::<String>()
     }
     #[cfg(feature = "color")]
     {
-        let max_width = std::cmp::min(s.chars().count(), width);
-        ansi_cut::AnsiCut::cut(&s, ..max_width).to_string()
+        // ansi-cut counts chars of a text without escapes
+        let b = strip_ansi_escapes::strip(s.as_bytes()).unwrap();
+        let text = String::from_utf8_lossy(&b);
+        let chars = |n: usize| {
+            text.graphemes(true)
+                .take(n)
+                .map(|g| g.chars().count())
+                .sum::<usize>()
+        };
+        ansi_cut::AnsiCut::cut(&s, chars(start)..chars(end))
     }
 }
+
+// splits lines so they are not wider than `width` columns of a terminal,
+// a grapheme which is wider than `width` takes a line on its own
+fn wrap_by_width(content: &str, width: usize) -> String {
+    let width = std::cmp::max(width, 1);
+    content
+        .lines()
+        .map(|line| {
+            let mut lines = Vec::new();
+            let (mut start, mut line_width) = (0, 0);
+            for (i, grapheme) in visible_text(line).graphemes(true).enumerate() {
+                let grapheme_width = grapheme.width();
+                if line_width > 0 && line_width + grapheme_width > width {
+                    lines.push(cut(line, start, i));
+                    start = i;
+                    line_width = 0;
+                }
+
+                line_width += grapheme_width;
+            }
+
+            lines.push(cut(line, start, graphemes_count(line)));
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// a text without color escapes
+fn visible_text(s: &str) -> std::borrow::Cow<'_, str> {
+    #[cfg(not(feature = "color"))]
+    {
+        std::borrow::Cow::Borrowed(s)
+    }
+    #[cfg(feature = "color")]
+    {
+        let b = strip_ansi_escapes::strip(s.as_bytes()).unwrap();
+        std::borrow::Cow::Owned(String::from_utf8_lossy(&b).into_owned())
+    }
+}
+
+fn graphemes_count(s: &str) -> usize {
+    visible_text(s).graphemes(true).count()
+}
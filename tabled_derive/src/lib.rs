@@ -8,7 +8,7 @@ use syn::{
     Ident, Index, Lit, Meta, NestedMeta, Type, Variant,
 };
 
-#[proc_macro_derive(Tabled, attributes(header, field))]
+#[proc_macro_derive(Tabled, attributes(header, field, tabled))]
 pub fn tabled(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     impl_tabled(&input)
@@ -16,8 +16,18 @@ pub fn tabled(input: TokenStream) -> TokenStream {
 
 fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let attributes = data_attributes(&ast.data);
-    let headers = get_headers(&ast.data, &attributes);
-    let fields = get_fields(&ast.data, &attributes);
+    let placeholder = placeholder(&ast.attrs);
+    let placeholder = placeholder.as_deref();
+    let (headers, fields) = match (&ast.data, variant_column(&ast.attrs)) {
+        (Data::Enum(e), Some(header)) => (
+            quote!(vec![String::from(#header)]),
+            get_enum_variant_name(e, &attributes),
+        ),
+        _ => (
+            get_headers(&ast.data, &attributes),
+            get_fields(&ast.data, &attributes, placeholder),
+        ),
+    };
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -60,18 +70,49 @@ fn data_attributes(d: &Data) -> Vec<Attr> {
         Data::Union(_) => todo!("it's not clear how to handle union type"),
     };
 
-    attrs_of_fields
+    attrs_of_fields.into_iter().map(Attr::parse).collect()
+}
+
+fn get_st_headers(st: &DataStruct, attrs: &[Attr]) -> Vec<proc_macro2::TokenStream> {
+    let fields = st.fields.iter().collect::<Vec<_>>();
+    st_fields_order(attrs)
         .into_iter()
-        .map(|attrs| Attr::parse(attrs))
+        .map(|i| field_headers(fields[i], &attrs[i], i, ""))
         .collect()
 }
 
-fn get_st_headers(st: &DataStruct, attrs: &[Attr]) -> Vec<proc_macro2::TokenStream> {
-    st.fields
-        .iter()
-        .enumerate()
-        .filter(|(i, _)| !attrs[*i].is_ignored())
-        .map(|(i, f)| field_headers(f, &attrs[i], i, ""))
+// returns indexes of not hidden fields in an order they must be shown,
+// a field with an `order` attribute is put on a given position
+// and the rest fill free positions as they are declared
+fn st_fields_order(attrs: &[Attr]) -> Vec<usize> {
+    let visible = (0..attrs.len())
+        .filter(|&i| !attrs[i].is_ignored())
+        .collect::<Vec<_>>();
+
+    let mut positions = vec![None; visible.len()];
+    for &i in &visible {
+        if let Some(order) = attrs[i].order {
+            if order >= positions.len() {
+                panic!(
+                    "An order {} is out of a range of {} fields",
+                    order,
+                    positions.len()
+                );
+            }
+
+            if positions[order].is_some() {
+                panic!("An order {} is used more then once", order);
+            }
+
+            positions[order] = Some(i);
+        }
+    }
+
+    let mut rest = visible.into_iter().filter(|&i| attrs[i].order.is_none());
+    positions
+        .into_iter()
+        .map(|position| position.or_else(|| rest.next()))
+        .map(|position| position.expect("all positions are filled"))
         .collect()
 }
 
@@ -138,10 +179,10 @@ fn variant_headers(variant: &Variant, attr: &Attr) -> Vec<proc_macro2::TokenStre
     }
 }
 
-fn get_fields(d: &Data, attrs: &[Attr]) -> proc_macro2::TokenStream {
+fn get_fields(d: &Data, attrs: &[Attr], placeholder: Option<&str>) -> proc_macro2::TokenStream {
     match d {
         Data::Struct(st) => {
-            let fields = get_st_fields(st, attrs);
+            let fields = get_st_fields(st, attrs, placeholder);
             quote! {
                 {
                     let v: Vec<Vec<String>> = vec![
@@ -152,20 +193,21 @@ fn get_fields(d: &Data, attrs: &[Attr]) -> proc_macro2::TokenStream {
                 }
             }
         }
-        Data::Enum(e) => get_enum_fields(e, attrs),
+        Data::Enum(e) => get_enum_fields(e, attrs, placeholder),
         Data::Union(_) => todo!("it's not clear how to handle union type"),
     }
 }
 
-fn get_st_fields(st: &DataStruct, attrs: &[Attr]) -> Vec<proc_macro2::TokenStream> {
+fn get_st_fields(
+    st: &DataStruct,
+    attrs: &[Attr],
+    placeholder: Option<&str>,
+) -> Vec<proc_macro2::TokenStream> {
+    let fields = st.fields.iter().collect::<Vec<_>>();
     let mut v = Vec::new();
-    for (i, field) in st.fields.iter().enumerate() {
-        if attrs[i].is_ignored() {
-            continue;
-        }
-
-        let field_var = field_var_name(field, i);
-        let fields = get_field_fields(field_var, &attrs[i]);
+    for i in st_fields_order(attrs) {
+        let field_var = field_var_name(fields[i], i);
+        let fields = get_field_fields(field_var, &fields[i].ty, &attrs[i], placeholder);
 
         v.push(fields);
     }
@@ -173,17 +215,56 @@ fn get_st_fields(st: &DataStruct, attrs: &[Attr]) -> Vec<proc_macro2::TokenStrea
     v
 }
 
-fn get_field_fields(field: proc_macro2::TokenStream, attr: &Attr) -> proc_macro2::TokenStream {
+fn get_field_fields(
+    field: proc_macro2::TokenStream,
+    ty: &Type,
+    attr: &Attr,
+    placeholder: Option<&str>,
+) -> proc_macro2::TokenStream {
     if attr.inline {
         return quote! { #field.fields() };
     }
 
-    if let Some(func) = &attr.display_with {
-        let func_call = use_function_for(field, func);
-        return quote!(vec![#func_call]);
+    let value = if let Some(func) = &attr.display_with {
+        use_function_for(field, func)
+    } else if is_option(ty) {
+        quote! {
+            match &#field {
+                Some(value) => format!("{}", value),
+                None => String::new(),
+            }
+        }
+    } else {
+        quote!(format!("{}", #field))
+    };
+
+    // a field's placeholder takes precedence over the one of a type
+    match attr.placeholder.as_deref().or(placeholder) {
+        Some(placeholder) => quote! {
+            vec![{
+                let value: String = #value;
+                if value.is_empty() {
+                    String::from(#placeholder)
+                } else {
+                    value
+                }
+            }]
+        },
+        None => quote!(vec![#value]),
     }
+}
 
-    quote!(vec![format!("{}", #field)])
+// checks whether a type is an `Option`,
+// the check is done by a name as we don't have an access to types
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
 }
 
 fn use_function_for(field: proc_macro2::TokenStream, function: &str) -> proc_macro2::TokenStream {
@@ -210,13 +291,17 @@ fn field_var_name(field: &Field, index: usize) -> proc_macro2::TokenStream {
     )
 }
 
-fn get_enum_fields(e: &DataEnum, attrs: &[Attr]) -> proc_macro2::TokenStream {
+fn get_enum_fields(
+    e: &DataEnum,
+    attrs: &[Attr],
+    placeholder: Option<&str>,
+) -> proc_macro2::TokenStream {
     let fields = e
         .variants
         .iter()
         .enumerate()
         .filter(|(i, _)| !attrs[*i].is_ignored())
-        .map(|(i, v)| variant_fields(v, &attrs[i]))
+        .map(|(i, v)| variant_fields(v, &attrs[i], placeholder))
         .collect::<Vec<_>>();
 
     let branches = e
@@ -290,11 +375,40 @@ fn get_enum_fields(e: &DataEnum, attrs: &[Attr]) -> proc_macro2::TokenStream {
     }
 }
 
-fn variant_fields(v: &Variant, attr: &Attr) -> Vec<proc_macro2::TokenStream> {
+// renders an enum as a single column with a name of a variant
+fn get_enum_variant_name(e: &DataEnum, attrs: &[Attr]) -> proc_macro2::TokenStream {
+    let branches = e
+        .variants
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !attrs[*i].is_ignored())
+        .map(|(i, v)| {
+            let branch = variant_match_branches(v, &Attr::default());
+            let name = attrs[i].name.clone().unwrap_or_else(|| v.ident.to_string());
+            quote!(Self::#branch => vec![String::from(#name)],)
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[allow(unreachable_patterns)]
+        match &self {
+            #(#branches)*
+            _ => vec![String::new()], // variant is hidden so its name is not shown
+        }
+    }
+}
+
+fn variant_fields(
+    v: &Variant,
+    attr: &Attr,
+    placeholder: Option<&str>,
+) -> Vec<proc_macro2::TokenStream> {
     if !attr.inline {
         return vec![quote!(vec!["+".to_string()])];
     }
 
+    let placeholder = attr.placeholder.as_deref().or(placeholder);
+
     let branch_idents = variant_idents(v);
     if branch_idents.is_empty() {
         return vec![quote!(vec!["+".to_string()])];
@@ -302,8 +416,11 @@ fn variant_fields(v: &Variant, attr: &Attr) -> Vec<proc_macro2::TokenStream> {
 
     branch_idents
         .into_iter()
-        .zip(v.fields.iter().map(|field| Attr::parse(&field.attrs)))
-        .map(|(ident, attr)| get_field_fields(ident.to_token_stream(), &attr))
+        .zip(&v.fields)
+        .map(|(ident, field)| {
+            let attr = Attr::parse(&field.attrs);
+            get_field_fields(ident.to_token_stream(), &field.ty, &attr, placeholder)
+        })
         .collect()
 }
 
@@ -365,13 +482,15 @@ fn field_header_name(f: &Field, attr: &Attr, index: usize) -> String {
 }
 
 // It would be cool to create a library for a parsing attributes
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct Attr {
     hidden: bool,
     inline: bool,
     inline_prefix: String,
     name: Option<String>,
     display_with: Option<String>,
+    order: Option<usize>,
+    placeholder: Option<String>,
 }
 
 impl Attr {
@@ -381,6 +500,8 @@ impl Attr {
         let inline_prefix = look_for_inline_prefix(attrs);
         let display_with = check_display_with_func(attrs);
         let override_header_name = override_header_name(attrs);
+        let order = find_name_attribute(attrs, "tabled", "order", look_up_nested_meta_usize);
+        let placeholder = placeholder(attrs);
 
         Attr {
             display_with,
@@ -388,6 +509,8 @@ impl Attr {
             inline: should_be_inlined,
             inline_prefix,
             name: override_header_name,
+            order,
+            placeholder,
         }
     }
 
@@ -399,15 +522,26 @@ impl Attr {
 fn override_header_name(attrs: &[Attribute]) -> Option<String> {
     find_name_attribute(attrs, "header", "name", look_up_nested_meta_str)
         .or_else(|| find_name_attribute(attrs, "header", "name", look_up_nested_meta_flag_str))
+        .or_else(|| find_name_attribute(attrs, "tabled", "rename", look_up_nested_meta_str))
+}
+
+fn variant_column(attrs: &[Attribute]) -> Option<String> {
+    find_name_attribute(attrs, "tabled", "variant_column", look_up_nested_meta_str)
+}
+
+fn placeholder(attrs: &[Attribute]) -> Option<String> {
+    find_name_attribute(attrs, "tabled", "placeholder", look_up_nested_meta_str)
 }
 
 fn check_display_with_func(attrs: &[Attribute]) -> Option<String> {
     find_name_attribute(attrs, "field", "display_with", look_up_nested_meta_str)
+        .or_else(|| find_name_attribute(attrs, "tabled", "display_with", look_up_nested_meta_str))
 }
 
 fn should_be_inlined(attrs: &[Attribute]) -> bool {
     let inline_attr = find_name_attribute(attrs, "header", "inline", look_up_nested_meta_bool)
         .or_else(|| find_name_attribute(attrs, "field", "inline", look_up_nested_meta_bool))
+        .or_else(|| find_name_attribute(attrs, "tabled", "inline", look_up_nested_meta_bool))
         .or_else(|| {
             find_name_attribute(attrs, "header", "inline", look_up_nested_flag_str_in_attr)
                 .map(|_| true)
@@ -415,6 +549,10 @@ fn should_be_inlined(attrs: &[Attribute]) -> bool {
         .or_else(|| {
             find_name_attribute(attrs, "field", "inline", look_up_nested_flag_str_in_attr)
                 .map(|_| true)
+        })
+        .or_else(|| {
+            find_name_attribute(attrs, "tabled", "inline", look_up_nested_flag_str_in_attr)
+                .map(|_| true)
         });
     inline_attr == Some(true)
 }
@@ -422,11 +560,13 @@ fn should_be_inlined(attrs: &[Attribute]) -> bool {
 fn look_for_inline_prefix(attrs: &[Attribute]) -> String {
     find_name_attribute(attrs, "header", "inline", look_up_nested_flag_str_in_attr)
         .or_else(|| find_name_attribute(attrs, "field", "inline", look_up_nested_flag_str_in_attr))
+        .or_else(|| find_name_attribute(attrs, "tabled", "inline", look_up_nested_flag_str_in_attr))
         .unwrap_or_else(|| "".to_owned())
 }
 
 fn attrs_has_ignore_sign(attrs: &[Attribute]) -> bool {
-    let is_ignored = find_name_attribute(attrs, "header", "hidden", look_up_nested_meta_bool);
+    let is_ignored = find_name_attribute(attrs, "header", "hidden", look_up_nested_meta_bool)
+        .or_else(|| find_name_attribute(attrs, "tabled", "skip", look_up_nested_meta_bool));
     is_ignored == Some(true)
 }
 
@@ -513,6 +653,19 @@ fn look_up_nested_meta_bool(meta: &NestedMeta, name: &str) -> Result<Option<bool
     }
 }
 
+fn look_up_nested_meta_usize(meta: &NestedMeta, name: &str) -> Result<Option<usize>, String> {
+    match meta {
+        NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident(name) => match &value.lit {
+            Lit::Int(value) => value
+                .base10_parse::<usize>()
+                .map(Some)
+                .map_err(|_| "A parameter should be a positive integer".to_string()),
+            _ => Err("A parameter should be an integer value".to_string()),
+        },
+        _ => Ok(None),
+    }
+}
+
 fn look_up_nested_flag_str_in_attr(
     meta: &NestedMeta,
     name: &str,
@@ -0,0 +1,70 @@
+use tabled::{Abbreviate, Cell, Full, Head, Modify, Raw, Rotate, Style, Table};
+
+fn abbreviate(text: &str, width: usize) -> String {
+    let table = Table::new([text])
+        .with(Modify::new(Cell(1, 0)).with(Abbreviate(width)))
+        .with(Style::noborder());
+    table.to_string().lines().nth(1).unwrap().trim().to_owned()
+}
+
+#[test]
+fn abbreviate_fitting_text() {
+    assert_eq!(abbreviate("Net Income", 10), "Net Income");
+}
+
+#[test]
+fn abbreviate_words() {
+    assert_eq!(abbreviate("Net Income", 9), "Net Inco.");
+    assert_eq!(abbreviate("Net Income", 6), "Ne.In.");
+    assert_eq!(abbreviate("Net Income", 4), "N.I.");
+    assert_eq!(abbreviate("Temperature", 4), "Tem.");
+    assert_eq!(abbreviate("Share of Revenue", 9), "Sh.of Re.");
+}
+
+#[test]
+fn abbreviate_truncates_initials() {
+    assert_eq!(abbreviate("Net Income per Year", 3), "N.I");
+}
+
+#[test]
+fn abbreviate_keeps_lines() {
+    let table = Table::new(["Net Income\nper Year"])
+        .with(Modify::new(Cell(1, 0)).with(Abbreviate(6)))
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!("  &str  \n", "--------\n", " Ne.In. \n", " pe.Ye. \n",)
+    );
+}
+
+#[test]
+fn abbreviate_skips_raw_cells() {
+    let table = Table::new(["Net Income"])
+        .with(Modify::new(Cell(1, 0)).with(Raw))
+        .with(Modify::new(Full).with(Abbreviate(3)))
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!("   &s.    \n", "----------\n", "Net Income\n")
+    );
+}
+
+#[test]
+fn vertical_header() {
+    let table = Table::new([(1, 0, 1)])
+        .with(Modify::new(Head).with(Rotate::Transpose))
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " i | i | i \n",
+            " 3 | 3 | 3 \n",
+            " 2 | 2 | 2 \n",
+            "---+---+---\n",
+            " 1 | 0 | 1 \n",
+        )
+    );
+}
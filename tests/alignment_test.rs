@@ -1,4 +1,7 @@
-use tabled::{Alignment, Column, Full, Head, Modify, Row, Style, Table, Tabled};
+use tabled::{
+    AlignNumeric, Alignment, Column, Fill, Full, Head, Indent, Locale, Modify, Object, Row, Style,
+    Table, Tabled,
+};
 
 #[derive(Tabled)]
 struct Linux {
@@ -184,3 +187,142 @@ fn vertical_alignment_test() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn align_numeric_test() {
+    let data = [("1,234.5", "a"), ("-0.125", "b"), ("7", "c"), ("n/a", "d")];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Column(..1)).with(AlignNumeric::new()))
+        .to_string();
+
+    let expected = concat!(
+        "   &str    | &str \n",
+        "-----------+------\n",
+        " 1,234.5   |  a   \n",
+        "    -0.125 |  b   \n",
+        "     7     |  c   \n",
+        "    n/a    |  d   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn align_numeric_suffix_and_locale_test() {
+    let data = ["12,5 %", "3 %", "100,25 %"];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(AlignNumeric::new().locale(Locale::de())))
+        .to_string();
+
+    let expected = concat!(
+        "   &str   \n",
+        "----------\n",
+        "  12,5 %  \n",
+        "   3 %    \n",
+        " 100,25 % \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn align_numeric_keeps_indent_test() {
+    let data = [1.5, 10.25];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Indent::new(2, 0, 0, 0)))
+        .with(Modify::new(Full).with(AlignNumeric::new()))
+        .to_string();
+
+    let expected = concat!("  f64  \n", "-------\n", "   1.5 \n", "  10.25\n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn fill_keeps_indent_test() {
+    let table = Table::new([("Tea", 3), ("Cinnamon bun", 105)])
+        .with(Style::psql())
+        .with(Modify::new(Column(..1).not(Row(..1))).with(Fill('.')))
+        .to_string();
+
+    let expected = concat!(
+        "     &str     | i32 \n",
+        "--------------+-----\n",
+        " ....Tea..... |  3  \n",
+        " Cinnamon bun | 105 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn fill_inside_indent_test() {
+    let table = Table::new([("Tea", 3), ("Cinnamon bun", 105)])
+        .with(Style::psql())
+        .with(
+            Modify::new(Column(1..))
+                .with(Alignment::right())
+                .with(Indent::new(1, 1, 0, 0).inside_alignment())
+                .with(Fill('0')),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "     &str     | i32 \n",
+        "--------------+-----\n",
+        "     Tea      | 003 \n",
+        " Cinnamon bun | 105 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn justify_multiline_content_test() {
+    let table = Table::new(["Justified text\nfills a whole\nwidth of a\ncolumn"])
+        .with(Style::psql())
+        .with(
+            Modify::new(Full)
+                .with(Alignment::justify())
+                .with(Indent::new(1, 1, 0, 0)),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " &str           \n",
+        "----------------\n",
+        " Justified text \n",
+        " fills  a whole \n",
+        " width   of   a \n",
+        " column         \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn justify_inside_indent_test() {
+    let table = Table::new(["a b c", "long word"])
+        .with(Style::psql())
+        .with(
+            Modify::new(Full)
+                .with(Alignment::justify())
+                .with(Indent::new(1, 1, 0, 0).inside_alignment()),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " &str      \n",
+        "-----------\n",
+        " a   b   c \n",
+        " long word \n",
+    );
+
+    assert_eq!(table, expected);
+}
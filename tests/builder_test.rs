@@ -0,0 +1,83 @@
+use tabled::{BuildReport, Builder, Style};
+
+#[test]
+fn builder_jagged_rows() {
+    let table = Builder::new()
+        .header(["a", "b", "c"])
+        .add_row([1])
+        .add_row([1, 2, 3, 4])
+        .set_default_text("-")
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " a | b | c | - \n",
+        "---+---+---+---\n",
+        " 1 | - | - | - \n",
+        " 1 | 2 | 3 | 4 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_without_header() {
+    let table = Builder::new()
+        .add_row(["1", "2"])
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, concat!(" 1 | 2 \n", "---+---\n"));
+}
+
+#[test]
+fn builder_empty() {
+    let (table, report) = Builder::new().build_checked();
+
+    assert_eq!(table.to_string(), "");
+    assert_eq!(report, BuildReport::default());
+}
+
+#[test]
+fn build_checked_report() {
+    let (table, report) = Builder::new()
+        .header(["name", "note"])
+        .add_row(["a\tb", "x\r\ny"])
+        .add_row(["c\u{7}"])
+        .build_checked();
+
+    assert_eq!(
+        report,
+        BuildReport {
+            count_rows: 3,
+            count_columns: 2,
+            padded_rows: 1,
+            replaced_control_chars: 1,
+        }
+    );
+
+    let expected = concat!(
+        " name  | note \n",
+        "-------+------\n",
+        " a   b |  x   \n",
+        "       |  y   \n",
+        "  c\u{fffd}   |      \n",
+    );
+
+    assert_eq!(table.with(Style::psql()).to_string(), expected);
+}
+
+#[test]
+fn build_keeps_tabs() {
+    let table = Builder::new()
+        .header(["name"])
+        .add_row(["x\ty"])
+        .build()
+        .with(Style::psql());
+
+    let expected = concat!(" name  \n", "-------\n", " x   y \n",);
+
+    assert_eq!(table.to_string(), expected);
+}
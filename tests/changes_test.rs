@@ -0,0 +1,52 @@
+use tabled::{Changes, Style, Table};
+
+#[test]
+fn changes_marker_test() {
+    let before = Table::new([("api", 3), ("db", 1)]);
+    let table = Table::new([("api", 3), ("db", 2), ("cache", 5)])
+        .with(Changes::new(&before))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "  &str  | i32 \n",
+        "--------+-----\n",
+        "  api   |  3  \n",
+        "   db   | *2  \n",
+        " *cache | *5  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn changes_custom_marker_test() {
+    let before = Table::new(["a"]);
+    let table = Table::new(["b"])
+        .with(Changes::new(&before).marker("> "))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " &str \n------\n > b  \n");
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn changes_color_test() {
+    use tabled::Color;
+
+    let before = Table::new(["a", "b"]);
+    let table = Table::new(["a", "c"])
+        .with(Changes::new(&before).marker("").color(Color::red()))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str \n",
+        "------\n",
+        "  a   \n",
+        "  \u{1b}[31mc\u{1b}[0m   \n",
+    );
+
+    assert_eq!(table, expected);
+}
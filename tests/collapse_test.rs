@@ -0,0 +1,88 @@
+use tabled::{Collapse, Style, Table, Tabled};
+
+#[derive(Tabled)]
+struct Package {
+    name: &'static str,
+    license: &'static str,
+    description: &'static str,
+}
+
+fn data() -> Vec<Package> {
+    vec![
+        Package {
+            name: "tabled",
+            license: "",
+            description: "",
+        },
+        Package {
+            name: " ",
+            license: "",
+            description: "",
+        },
+        Package {
+            name: "papergrid",
+            license: "",
+            description: "a grid",
+        },
+    ]
+}
+
+#[test]
+fn collapse_rows() {
+    let table = Table::new(data())
+        .with(Collapse::rows())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   name    | license | description \n",
+        "-----------+---------+-------------\n",
+        "  tabled   |         |             \n",
+        " papergrid |         |   a grid    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn collapse_columns() {
+    let table = Table::new(data())
+        .with(Collapse::columns())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   name    | description \n",
+        "-----------+-------------\n",
+        "  tabled   |             \n",
+        "           |             \n",
+        " papergrid |   a grid    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn collapse_with_header() {
+    let table = Table::new(data())
+        .with(Collapse::columns().with_header())
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, Table::new(data()).with(Style::psql()).to_string());
+}
+
+#[test]
+fn collapse_table_without_data() {
+    let table = Table::new(Vec::<Package>::new())
+        .with(Collapse::all())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " name | license | description \n",
+        "------+---------+-------------\n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "color")]
+
+use tabled::{BorderColor, Cell, Color, Full, Head, Modify, RowColoring, Semantic, Style, Table};
+
+#[test]
+fn color_header() {
+    let table = Table::new([(1, "a")])
+        .with(Style::psql())
+        .with(Modify::new(Head).with(Color::bold()))
+        .to_string();
+
+    let expected = concat!(
+        " \u{1b}[1mi32\u{1b}[0m | \u{1b}[1m&str\u{1b}[0m \n",
+        "-----+------\n",
+        "  1  |  a   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn color_multiline_cell_is_reset_on_each_line() {
+    let table = Table::new(["a\nbc"])
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Color::red()))
+        .to_string();
+
+    let expected = concat!(
+        " \u{1b}[31m&str\u{1b}[0m \n",
+        "------\n",
+        "  \u{1b}[31ma\u{1b}[0m   \n",
+        "  \u{1b}[31mbc\u{1b}[0m  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn color_border() {
+    let table = Table::new(["a"])
+        .with(BorderColor(Color::dim()))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str \n", "\u{1b}[2m------\u{1b}[0m\n", "  a   \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn color_frame() {
+    let table = Table::new(["a"])
+        .with(Style::default())
+        .with(BorderColor(Color::red()))
+        .to_string();
+
+    let expected = concat!(
+        "\u{1b}[31m+\u{1b}[0m\u{1b}[31m------\u{1b}[0m\u{1b}[31m+\u{1b}[0m\n",
+        "\u{1b}[31m|\u{1b}[0m &str \u{1b}[31m|\u{1b}[0m\n",
+        "\u{1b}[31m+\u{1b}[0m\u{1b}[31m------\u{1b}[0m\u{1b}[31m+\u{1b}[0m\n",
+        "\u{1b}[31m|\u{1b}[0m  a   \u{1b}[31m|\u{1b}[0m\n",
+        "\u{1b}[31m+\u{1b}[0m\u{1b}[31m------\u{1b}[0m\u{1b}[31m+\u{1b}[0m\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn color_combined() {
+    let color = Color::bold().and(Color::red());
+    let table = Table::new(["a"])
+        .with(Style::noborder())
+        .with(Modify::new(Head).with(color))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" \u{1b}[1m\u{1b}[31m&str\u{1b}[0m\u{1b}[0m \n", "  a   \n"),
+    );
+}
+
+#[test]
+fn row_coloring_alternating() {
+    let table = Table::new(["a", "b", "c"])
+        .with(Style::psql())
+        .with(RowColoring::alternating(
+            Color::on_black(),
+            Color::on_white(),
+        ))
+        .to_string();
+
+    let expected = concat!(
+        " &str \n",
+        "------\n",
+        "\u{1b}[40m  a   \u{1b}[0m\n",
+        "\u{1b}[47m  b   \u{1b}[0m\n",
+        "\u{1b}[40m  c   \u{1b}[0m\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn row_coloring_is_layered_under_cell_color() {
+    let table = Table::new(["a", "b"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(2, 0)).with(Color::red()))
+        .with(RowColoring::alternating(
+            Color::on_black(),
+            Color::on_white(),
+        ))
+        .to_string();
+
+    let expected = concat!(
+        " &str \n",
+        "------\n",
+        "\u{1b}[40m  a   \u{1b}[0m\n",
+        "\u{1b}[47m  \u{1b}[31mb\u{1b}[0m\u{1b}[47m   \u{1b}[0m\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn semantic_color() {
+    let table = Table::new(["a"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Semantic::Warning))
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  \u{1b}[33ma\u{1b}[0m   \n");
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,83 @@
+use tabled::{Concat, Style, Table};
+
+#[test]
+fn concat_vertical() {
+    let table1 = Table::new([(0, "Grodno", true), (1, "Minsk", true)]);
+    let table2 = Table::new([("Hamburg", false)]);
+
+    let table = table1
+        .with(Concat::vertical(table2))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   i32   |  &str  | bool \n",
+        "---------+--------+------\n",
+        "    0    | Grodno | true \n",
+        "    1    | Minsk  | true \n",
+        "  &str   |  bool  |      \n",
+        " Hamburg | false  |      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_horizontal() {
+    let table1 = Table::new([(0, "Grodno"), (1, "Minsk")]);
+    let table2 = Table::new([(true,)]);
+
+    let table = table1
+        .with(Concat::horizontal(table2))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " i32 |  &str  | bool \n",
+        "-----+--------+------\n",
+        "  0  | Grodno | true \n",
+        "  1  | Minsk  |      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_vertical_with_default_cell() {
+    let table1 = Table::new([(0, "Grodno", true)]);
+    let table2 = Table::new([("Hamburg", false)]);
+
+    let table = table1
+        .with(Concat::vertical(table2).default_cell("?"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   i32   |  &str  | bool \n",
+        "---------+--------+------\n",
+        "    0    | Grodno | true \n",
+        "  &str   |  bool  |  ?   \n",
+        " Hamburg | false  |  ?   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn concat_with_empty_table() {
+    let table1 = Table::new([(0, "Grodno")]);
+    let table2 = Table::new(Vec::<(bool,)>::new());
+
+    let table = table1
+        .with(Concat::horizontal(table2).default_cell("?"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " i32 |  &str  | bool \n",
+        "-----+--------+------\n",
+        "  0  | Grodno |  ?   \n",
+    );
+
+    assert_eq!(table, expected);
+}
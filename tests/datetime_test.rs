@@ -0,0 +1,117 @@
+#![cfg(feature = "datetime")]
+
+use tabled::{Column, Format, Modify, Object, Row, Style, Table, TimeZone};
+
+#[test]
+fn datetime_utc_and_offset() {
+    let data = [
+        ("2021-09-06T10:00:00Z", "2021-09-06T10:00:00Z"),
+        (
+            "2021-09-06T23:30:15.250+02:00",
+            "2021-09-06T23:30:15.250+02:00",
+        ),
+        ("2000-02-29T00:00:00Z", "2000-02-29T00:00:00Z"),
+    ];
+
+    let table = Table::new(data)
+        .with(
+            Modify::new(Column(..1).not(Row(..1)))
+                .with(Format::datetime("%Y-%m-%d %H:%M:%S %z", TimeZone::utc())),
+        )
+        .with(
+            Modify::new(Column(1..).not(Row(..1))).with(Format::datetime(
+                "%Y-%m-%d %H:%M:%S %z",
+                TimeZone::offset(-5, -30),
+            )),
+        )
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "            &str            |            &str            \n",
+        "----------------------------+----------------------------\n",
+        " 2021-09-06 10:00:00 +00:00 | 2021-09-06 04:30:00 -05:30 \n",
+        " 2021-09-06 21:30:15 +00:00 | 2021-09-06 16:00:15 -05:30 \n",
+        " 2000-02-29 00:00:00 +00:00 | 2000-02-28 18:30:00 -05:30 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn datetime_leaves_other_content() {
+    let data = [
+        "2021-13-01T00:00:00Z",
+        "2021-02-31T00:00:00Z",
+        "2100-02-29T00:00:00Z",
+        "2021-01-01",
+        "2010",
+        "yesterday",
+        "100%",
+    ];
+
+    let table = Table::new(data)
+        .with(Modify::new(Row(1..)).with(Format::datetime("%d/%m/%Y", TimeZone::utc())))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "         &str         \n",
+        "----------------------\n",
+        " 2021-13-01T00:00:00Z \n",
+        " 2021-02-31T00:00:00Z \n",
+        " 2100-02-29T00:00:00Z \n",
+        "      2021-01-01      \n",
+        "         2010         \n",
+        "      yesterday       \n",
+        "         100%         \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn datetime_epoch_and_leap_year() {
+    let table = Table::new(["951782400", "-86400"])
+        .with(
+            Modify::new(Row(1..))
+                .with(Format::datetime("%d.%m.%Y %%", TimeZone::utc()).epoch_seconds()),
+        )
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "     &str     \n",
+        "--------------\n",
+        " 29.02.2000 % \n",
+        " 31.12.1969 % \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn datetime_leaves_overflowed_epoch() {
+    let data = ["9223372036854775807", "-9223372036854775808"];
+
+    let table = Table::new(data)
+        .with(
+            Modify::new(Row(1..2))
+                .with(Format::datetime("%Y", TimeZone::offset(1, 0)).epoch_seconds()),
+        )
+        .with(
+            Modify::new(Row(2..))
+                .with(Format::datetime("%Y", TimeZone::offset(-1, 0)).epoch_seconds()),
+        )
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "         &str         \n",
+        "----------------------\n",
+        " 9223372036854775807  \n",
+        " -9223372036854775808 \n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -14,6 +14,7 @@ mod tupple_structure {
         assert_eq!(vec!["0".to_owned(), "field 2".to_owned()], St::headers());
     }
 
+    #[allow(dead_code)]
     #[test]
     fn hide_field() {
         #[derive(Tabled)]
@@ -361,6 +362,50 @@ mod enum_ {
         );
         assert_eq!(vec!["".to_owned(), "+".to_owned(),], Fact::Unknown.fields());
     }
+
+    #[test]
+    fn variant_column() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        #[tabled(variant_column = "Kind")]
+        enum Shape {
+            Circle {
+                radius: f64,
+            },
+            #[tabled(rename = "Rect")]
+            Rectangle(f64, f64),
+            #[tabled(skip)]
+            Unknown,
+        }
+
+        assert_eq!(vec!["Kind".to_owned()], Shape::headers());
+        assert_eq!(
+            vec!["Circle".to_owned()],
+            Shape::Circle { radius: 1.0 }.fields()
+        );
+        assert_eq!(vec!["Rect".to_owned()], Shape::Rectangle(1.0, 2.0).fields());
+        assert_eq!(vec!["".to_owned()], Shape::Unknown.fields());
+    }
+}
+
+mod enum_placeholder {
+    use super::*;
+
+    #[test]
+    fn placeholder() {
+        #[derive(Tabled)]
+        #[tabled(placeholder = "-")]
+        enum Domain {
+            #[tabled(inline)]
+            Security(Option<&'static str>),
+            #[tabled(inline, placeholder = "none")]
+            Embedded(Option<u8>),
+        }
+
+        assert_eq!(vec!["-", ""], Domain::Security(None).fields());
+        assert_eq!(vec!["", "none"], Domain::Embedded(None).fields());
+        assert_eq!(vec!["", "1"], Domain::Embedded(Some(1)).fields());
+    }
 }
 
 mod unit {
@@ -443,6 +488,123 @@ mod structure {
         assert_eq!(vec!["f3".to_owned()], St::headers());
     }
 
+    #[allow(dead_code)]
+    #[test]
+    fn tabled_rename_skip_and_order() {
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(rename = "Name")]
+            f1: &'static str,
+            #[tabled(skip)]
+            f2: u8,
+            f3: u8,
+            #[tabled(order = 0, rename = "Id")]
+            f4: u8,
+        }
+
+        let st = St {
+            f1: "v1",
+            f2: 2,
+            f3: 3,
+            f4: 4,
+        };
+
+        assert_eq!(
+            vec!["4".to_owned(), "v1".to_owned(), "3".to_owned()],
+            st.fields()
+        );
+        assert_eq!(
+            vec!["Id".to_owned(), "Name".to_owned(), "f3".to_owned()],
+            St::headers()
+        );
+    }
+
+    #[test]
+    fn tabled_inline() {
+        #[derive(Tabled)]
+        struct Person {
+            name: &'static str,
+            #[tabled(inline("address::"))]
+            address: Address,
+            #[tabled(inline)]
+            status: Status,
+        }
+
+        #[derive(Tabled)]
+        struct Address {
+            city: &'static str,
+            #[tabled(rename = "zip")]
+            postal_code: &'static str,
+        }
+
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        enum Status {
+            Active,
+            Blocked,
+        }
+
+        let p = Person {
+            name: "Maxim",
+            address: Address {
+                city: "Minsk",
+                postal_code: "220000",
+            },
+            status: Status::Blocked,
+        };
+
+        assert_eq!(
+            vec![
+                "Maxim".to_owned(),
+                "Minsk".to_owned(),
+                "220000".to_owned(),
+                "".to_owned(),
+                "+".to_owned()
+            ],
+            p.fields()
+        );
+        assert_eq!(
+            vec![
+                "name".to_owned(),
+                "address::city".to_owned(),
+                "address::zip".to_owned(),
+                "Active".to_owned(),
+                "Blocked".to_owned()
+            ],
+            Person::headers()
+        );
+    }
+
+    #[test]
+    fn tabled_order_last_with_inline() {
+        #[derive(Tabled)]
+        struct Person {
+            #[tabled(order = 2)]
+            id: u8,
+            #[header(inline)]
+            ed: Education,
+            name: &'static str,
+        }
+
+        #[derive(Tabled)]
+        struct Education {
+            uni: &'static str,
+            graduated: bool,
+        }
+
+        let p = Person {
+            id: 0,
+            name: "Maxim",
+            ed: Education {
+                uni: "BNTU",
+                graduated: true,
+            },
+        };
+
+        assert_eq!(vec!["BNTU", "true", "Maxim", "0"], p.fields());
+        assert_eq!(vec!["uni", "graduated", "name", "id"], Person::headers());
+    }
+
     #[test]
     fn inline() {
         #[derive(Tabled)]
@@ -561,6 +723,47 @@ mod structure {
         assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
     }
 
+    #[test]
+    fn tabled_display_with() {
+        fn display_bytes(bytes: &u64) -> String {
+            match *bytes {
+                b if b >= 1024 * 1024 => format!("{:.1} MiB", b as f64 / (1024.0 * 1024.0)),
+                b if b >= 1024 => format!("{:.1} KiB", b as f64 / 1024.0),
+                b => format!("{} B", b),
+            }
+        }
+
+        #[derive(Tabled)]
+        struct File {
+            name: &'static str,
+            #[tabled(display_with = "display_bytes", rename = "Size")]
+            size: u64,
+            #[tabled(display_with = "Self::display_duration")]
+            elapsed: std::time::Duration,
+        }
+
+        impl File {
+            fn display_duration(d: &std::time::Duration) -> String {
+                format!("{}ms", d.as_millis())
+            }
+        }
+
+        let file = File {
+            name: "a.txt",
+            size: 2048,
+            elapsed: std::time::Duration::from_millis(15),
+        };
+
+        assert_eq!(
+            vec!["a.txt".to_owned(), "2.0 KiB".to_owned(), "15ms".to_owned()],
+            file.fields()
+        );
+        assert_eq!(
+            vec!["name".to_owned(), "Size".to_owned(), "elapsed".to_owned()],
+            File::headers()
+        );
+    }
+
     #[allow(dead_code)]
     #[test]
     fn display_with_self_static_method() {
@@ -588,4 +791,71 @@ mod structure {
         assert_eq!(vec!["0".to_owned(), "some v2".to_owned()], st.fields());
         assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
     }
+
+    #[test]
+    fn option_field() {
+        #[derive(Tabled)]
+        struct Package {
+            name: &'static str,
+            version: Option<&'static str>,
+        }
+
+        let p = Package {
+            name: "tabled",
+            version: None,
+        };
+
+        assert_eq!(vec!["tabled", ""], p.fields());
+
+        let p = Package {
+            name: "tabled",
+            version: Some("0.5"),
+        };
+
+        assert_eq!(vec!["tabled", "0.5"], p.fields());
+    }
+
+    #[test]
+    fn placeholder() {
+        #[derive(Tabled)]
+        #[tabled(placeholder = "-")]
+        struct Package {
+            name: &'static str,
+            version: Option<&'static str>,
+            #[tabled(placeholder = "N/A")]
+            license: std::option::Option<String>,
+        }
+
+        let p = Package {
+            name: "",
+            version: None,
+            license: None,
+        };
+
+        assert_eq!(vec!["-", "-", "N/A"], p.fields());
+
+        let p = Package {
+            name: "tabled",
+            version: Some("0.5"),
+            license: Some(String::from("MIT")),
+        };
+
+        assert_eq!(vec!["tabled", "0.5", "MIT"], p.fields());
+    }
+
+    #[test]
+    fn placeholder_with_display_with() {
+        fn display_version(v: &Option<u8>) -> String {
+            v.map(|v| format!("v{}", v)).unwrap_or_default()
+        }
+
+        #[derive(Tabled)]
+        struct Package {
+            #[tabled(display_with = "display_version", placeholder = "?")]
+            version: Option<u8>,
+        }
+
+        assert_eq!(vec!["v1"], Package { version: Some(1) }.fields());
+        assert_eq!(vec!["?"], Package { version: None }.fields());
+    }
 }
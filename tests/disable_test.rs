@@ -213,3 +213,67 @@ fn disable_all_table_via_columns() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn disable_via_constructors() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        "destribution|link                  \n",
+        "------------+----------------------\n",
+        "Fedora      |https://getfedora.org/\n",
+    );
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Alignment::left()))
+        .with(Disable::column(..1))
+        .with(Disable::row(2..))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_range_out_of_table() {
+    let data = vec![Linux {
+        id: 0,
+        destribution: "Fedora",
+        link: "https://getfedora.org/",
+    }];
+
+    let expected = concat!(
+        "id|destribution|link                  \n",
+        "--+------------+----------------------\n",
+        "0 |Fedora      |https://getfedora.org/\n",
+    );
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Alignment::left()))
+        .with(Disable::row(2..10))
+        .with(Disable::column(3..=5))
+        .to_string();
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(&data).with(Disable::row(..10)).to_string();
+
+    assert_eq!(table, "");
+}
@@ -0,0 +1,106 @@
+use tabled::{
+    Cell, Column, Full, MaxWidth, Modify, Object, Row, Semantic, Span, Table, Truncate, Wrap,
+};
+
+#[test]
+fn markdown_honors_truncate() {
+    let table = Table::new([("https://getfedora.org/", 1), ("a|b", 2)])
+        .with(Modify::new(Full.not(Row(..1))).with(MaxWidth(5, "...")));
+
+    let expected = concat!(
+        "| &str | i32 |\n",
+        "|---|---|\n",
+        "| https... | 1 |\n",
+        "| a\\|b | 2 |\n",
+    );
+
+    assert_eq!(table.to_markdown(), expected);
+}
+
+#[test]
+fn html_honors_wrap() {
+    let table = Table::new(["123456\n12"])
+        .with(Modify::new(Row(1..)).with(Wrap(4)))
+        .with(Modify::new(Row(..1)).with(Truncate::left(2, "")));
+
+    let expected = concat!(
+        "<table>\n",
+        "<tr><th>tr</th></tr>\n",
+        "<tr><td>1234<br>56<br>12</td></tr>\n",
+        "</table>\n",
+    );
+
+    assert_eq!(table.to_html(), expected);
+}
+
+#[test]
+fn export_empty_table() {
+    let table = Table::new(Vec::<(u8, u8)>::new());
+
+    assert_eq!(table.to_markdown(), "| u8 | u8 |\n|---|---|\n");
+    assert_eq!(
+        table.to_html(),
+        "<table>\n<tr><th>u8</th><th>u8</th></tr>\n</table>\n"
+    );
+}
+
+#[test]
+fn export_spans() {
+    let table = Table::new([[1, 2, 3], [4, 5, 6]])
+        .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+        .with(Modify::new(Cell(1, 1)).with(Span::new(2, 2)));
+
+    let markdown = concat!(
+        "| 0 |  |  |\n",
+        "|---|---|---|\n",
+        "| 1 | 2 |  |\n",
+        "| 4 |  |  |\n",
+    );
+
+    let html = concat!(
+        "<table>\n",
+        "<tr><th colspan=\"3\">0</th></tr>\n",
+        "<tr><td>1</td><td colspan=\"2\" rowspan=\"2\">2</td></tr>\n",
+        "<tr><td>4</td></tr>\n",
+        "</table>\n",
+    );
+
+    assert_eq!(table.to_markdown(), markdown);
+    assert_eq!(table.to_html(), html);
+}
+
+#[test]
+fn semantic_export() {
+    let table = Table::new([("a", ""), ("b", "c")])
+        .with(Modify::new(Column(1..)).with(Semantic::Muted))
+        .with(Modify::new(Row(..1)).with(Semantic::Emphasis));
+
+    let markdown = concat!(
+        "| **&str** | **&str** |\n",
+        "|---|---|\n",
+        "| a |  |\n",
+        "| b | _c_ |\n",
+    );
+
+    let html = concat!(
+        "<table>\n",
+        "<tr><th class=\"emphasis\">&amp;str</th><th class=\"emphasis\">&amp;str</th></tr>\n",
+        "<tr><td>a</td><td class=\"muted\"></td></tr>\n",
+        "<tr><td>b</td><td class=\"muted\">c</td></tr>\n",
+        "</table>\n",
+    );
+
+    assert_eq!(table.to_markdown(), markdown);
+    assert_eq!(table.to_html(), html);
+}
+
+#[test]
+#[cfg(not(feature = "color"))]
+fn semantic_doesnt_change_terminal_output_without_colors() {
+    let table = Table::new(["a"]).with(tabled::Style::psql());
+    let expected = table.to_string();
+
+    let table = table.with(Modify::new(Full).with(Semantic::Warning));
+
+    assert_eq!(table.to_string(), expected);
+}
@@ -0,0 +1,46 @@
+use tabled::{Alignment, Full, Header, Modify, Style, Table};
+
+#[test]
+fn extract_window() {
+    let data = vec![
+        (0, "Grodno", true),
+        (1, "Minsk", true),
+        (2, "Hamburg", false),
+        (3, "Brest", true),
+    ];
+
+    let table = Table::new(&data)
+        .with(Modify::new(Full).with(Alignment::left()))
+        .extract(2..4, ..2)
+        .with(Style::psql());
+
+    let expected = concat!("1|Minsk  \n", "-+-------\n", "2|Hamburg\n",);
+
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn extract_truncates_span() {
+    let data = vec![(0, "Grodno", true), (1, "Minsk", true)];
+
+    let table = Table::new(&data)
+        .with(Header("Cities"))
+        .extract(..2, 1..)
+        .with(Style::psql());
+
+    let expected = concat!("Cities       \n", "------+------\n", " &str | bool \n",);
+
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn extract_out_of_table() {
+    let data = vec![(0, "Grodno", true), (1, "Minsk", true)];
+    let table = Table::new(&data);
+
+    assert_eq!(table.extract(5.., ..).to_string(), "");
+    assert_eq!(
+        table.extract(.., 2..10).to_string(),
+        table.extract(.., 2..).to_string()
+    );
+}
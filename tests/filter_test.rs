@@ -0,0 +1,55 @@
+use tabled::{Alignment, Cell, Filter, Modify, Row, Span, Style, Table};
+
+#[test]
+fn filter_keeps_settings_of_rows() {
+    let data = [("a", 0), ("b", 1), ("c", 0), ("d", 2)];
+
+    let table = Table::new(data)
+        .with(Modify::new(Row(4..)).with(Alignment::right()))
+        .with(Filter::rows(|record| record[1] != "0"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  b   |  1  \n",
+        "     d|    2\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn filter_all_rows() {
+    let table = Table::new([1, 2])
+        .with(Filter::rows(|_| false))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, concat!(" i32 \n", "-----\n"));
+}
+
+#[test]
+fn filter_shrinks_row_spans() {
+    let data = [("a", 0), ("b", 1), ("c", 2), ("d", 3)];
+
+    let table = Table::new(data)
+        .with(Modify::new(Cell(1, 1)).with(Span::row(3)))
+        .with(Filter::rows(|record| record[0] != "b"))
+        .to_string();
+
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  a   |  0  |\n",
+        "+------+     |\n",
+        "|  c   |     |\n",
+        "+------+-----+\n",
+        "|  d   |  3  |\n",
+        "+------+-----+\n",
+    );
+
+    assert_eq!(table, expected);
+}
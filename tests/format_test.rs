@@ -1,6 +1,6 @@
 use tabled::{
-    multiline, Cell, Column, Format, FormatFrom, FormatWithIndex, Full, Head, Modify, Object, Row,
-    Style, Table, Tabled,
+    multiline, Cell, Column, Format, FormatError, FormatFrom, FormatWithIndex, Full, Head, Locale,
+    Modify, Object, OnError, Row, Style, SymbolPosition, Table, Tabled,
 };
 
 #[derive(Tabled)]
@@ -564,3 +564,281 @@ mod color {
         assert_eq!(table, expected);
     }
 }
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn format_diff() {
+    let data = vec![("replicas", 1, 3), ("memory", 512, 512), ("cpu", 2, 1)];
+
+    let table = Table::new(&data)
+        .with(Modify::new(Head).with(FormatFrom(vec!["field", "old", "change"])))
+        .with(Modify::new(Column(2..).not(Row(..1))).with(Format::diff(1, 2)))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "  field   | old | change \n",
+        "----------+-----+--------\n",
+        " replicas |  1  | 1 → 3  \n",
+        "  memory  | 512 |  512   \n",
+        "   cpu    |  2  | 2 → 1  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn format_diff_with_a_column_out_of_table() {
+    let data = vec![("replicas", 1, 3)];
+
+    let table = Table::new(&data)
+        .with(Modify::new(Column(2..).not(Row(..1))).with(Format::diff(0, 9)))
+        .with(Modify::new(Column(1..2).not(Row(..1))).with(Format::diff(9, 2)))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   &str   | i32 | i32 \n",
+        "----------+-----+-----\n",
+        " replicas |  1  |  3  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn format_with_position() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Column(..2)).with(Format::with_position(|s, (row, column)| {
+                if row == 0 {
+                    s.to_uppercase()
+                } else {
+                    format!("{}-{}", s, row + column)
+                }
+            })),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " ID  | DESTRIBUTION |           link            \n",
+        "-----+--------------+---------------------------\n",
+        " 0-1 |   Fedora-2   |  https://getfedora.org/   \n",
+        " 2-2 |  OpenSUSE-3  | https://www.opensuse.org/ \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn try_format_placeholder_test() {
+    let data = [("1", "x"), ("y", "2")];
+
+    let format = Format::try_with(|s: &str| s.parse::<u8>().map(|n| (n * 10).to_string()));
+    let report = format.report();
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Full.not(Row(..1))).with(format))
+        .to_string();
+
+    let expected = concat!(
+        "   &str    |   &str    \n",
+        "-----------+-----------\n",
+        "    10     | ⚠ invalid \n",
+        " ⚠ invalid |    20     \n",
+    );
+
+    assert_eq!(table, expected);
+    assert_eq!(
+        report.errors(),
+        vec![
+            FormatError {
+                row: 1,
+                column: 1,
+                content: "x".to_owned(),
+                message: "invalid digit found in string".to_owned(),
+            },
+            FormatError {
+                row: 2,
+                column: 0,
+                content: "y".to_owned(),
+                message: "invalid digit found in string".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn try_format_raw_test() {
+    let data = ["1", "x"];
+
+    let format = Format::try_with(|s: &str| s.parse::<u8>().map(|n| (n * 10).to_string()))
+        .on_error(OnError::Raw);
+    let report = format.report();
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(format))
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  10  \n", "  x   \n");
+
+    assert_eq!(table, expected);
+    assert!(!report.is_empty());
+    assert_eq!(report.errors().len(), 1);
+}
+
+#[test]
+fn try_format_without_errors_test() {
+    let format = Format::try_with(|s: &str| Ok::<_, String>(s.to_uppercase()));
+    let report = format.report();
+
+    let table = Table::new(["a"])
+        .with(Style::psql())
+        .with(Modify::new(Full).with(format))
+        .to_string();
+
+    assert_eq!(table, concat!(" &STR \n", "------\n", "  A   \n"));
+    assert!(report.is_empty());
+}
+
+#[test]
+fn format_list_per_column_test() {
+    let data = [("a;b;c;d", "x, y, z"), ("a", "x, y")];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Column(..1).not(Row(..1))).with(Format::list(";", 1)))
+        .with(Modify::new(Column(1..).not(Row(..1))).with(Format::list(", ", 2)))
+        .to_string();
+
+    let expected = concat!(
+        "   &str    |     &str     \n",
+        "-----------+--------------\n",
+        " a +3 more | x, y +1 more \n",
+        "     a     |     x, y     \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn format_list_zero_limit_test() {
+    let table = Table::new(["a,b"])
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Format::list(",", 0)))
+        .to_string();
+
+    assert_eq!(table, concat!("  &str   \n", "---------\n", " +2 more \n"));
+}
+
+#[test]
+fn format_percent_test() {
+    let data = [("0.153", "a"), ("-0.0001", "b"), ("x", "c")];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Column(..1).not(Row(..1))).with(Format::percent(1)))
+        .to_string();
+
+    let expected = concat!(
+        "  &str  | &str \n",
+        "--------+------\n",
+        " 15.3 % |  a   \n",
+        " 0.0 %  |  b   \n",
+        "   x    |  c   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn format_currency_test() {
+    let data = [1234567.891, -5.0];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Format::currency("$", SymbolPosition::Prefix)))
+        .to_string();
+
+    let expected = concat!(
+        "      f64      \n",
+        "---------------\n",
+        " $1,234,567.89 \n",
+        "    -$5.00     \n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Row(1..)).with(
+                Format::currency("€", SymbolPosition::Suffix)
+                    .precision(0)
+                    .locale(Locale::new(',', None)),
+            ),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "    f64    \n",
+        "-----------\n",
+        " 1234568 € \n",
+        "   -5 €    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+#[cfg(not(feature = "color"))]
+fn format_delta_test() {
+    let data = [12.346, -0.5, -0.001, 0.0];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Format::delta(2)))
+        .to_string();
+
+    let expected = concat!(
+        "  f64   \n",
+        "--------\n",
+        " +12.35 \n",
+        " −0.50  \n",
+        "  0.00  \n",
+        "  0.00  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn cell_append_prepend_test() {
+    let table = Table::new([("a", "b")])
+        .with(Cell(1, 0).prepend("["))
+        .with(Cell(1, 0).append("]"))
+        .with(Cell(1, 1).map_content(|s| s.repeat(3)))
+        .with(Cell(5, 5).append("!"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str | &str \n", "------+------\n", " [a]  | bbb  \n",);
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,142 @@
+use tabled::{Cell, Column, Height, Modify, Object, Row, Span, Style, Table};
+
+#[test]
+fn height_limit() {
+    let table = Table::new([("a\nb\nc", "d")])
+        .with(Style::psql())
+        .with(Height::limit(2))
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  a   |  d   \n",
+        "  b   |      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_per_column_with_ellipsis() {
+    let table = Table::new([("a\nb\nc", "d\ne\nf")])
+        .with(Style::psql())
+        .with(Modify::new(Column(1..).not(Row(..1))).with(Height::limit(1).ellipsis("~")))
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  a   |  ~   \n",
+        "  b   |      \n",
+        "  c   |      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_zero() {
+    let table = Table::new(["a"])
+        .with(Style::psql())
+        .with(Height::limit(0))
+        .to_string();
+
+    assert_eq!(table, concat!("  \n", "--\n", "  \n"));
+}
+
+#[test]
+fn height_increase_per_row() {
+    let table = Table::new(["a", "b"])
+        .with(Style::psql())
+        .with(Modify::new(Row(1..2)).with(Height::increase(3)))
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  a   \n", "      \n", "      \n", "  b   \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_increase_doesnt_shrink_rows() {
+    let table = Table::new(["a\nb\nc"])
+        .with(Style::psql())
+        .with(Height::increase(2))
+        .to_string();
+
+    let expected = concat!(" &str \n", "      \n", "------\n", "  a   \n", "  b   \n", "  c   \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_table() {
+    let table = Table::new([("a", 1), ("b\nb", 2), ("c", 3)])
+        .with(Style::default())
+        .with(Height::limit_table(8))
+        .to_string();
+
+    let expected = concat!(
+        "+-------+-----+\n",
+        "| &str  | i32 |\n",
+        "+-------+-----+\n",
+        "|   a   |  1  |\n",
+        "+-------+-----+\n",
+        "|▼ 2 more rows|\n",
+        "+-------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_table_fits() {
+    let table = Table::new([("a", 1), ("b", 2)])
+        .with(Style::psql())
+        .with(Height::limit_table(4))
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   |  1  \n",
+        "  b   |  2  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_table_keeps_header() {
+    let table = Table::new([("a", 1), ("b", 2)])
+        .with(Style::psql())
+        .with(Height::limit_table(1).indicator(|count| format!("+{}", count)))
+        .to_string();
+
+    let expected = concat!(" &str | i32 \n", "------+-----\n", "+2          \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_table_cuts_row_spans() {
+    let data = (0..6).map(|i| (i, i * 2)).collect::<Vec<_>>();
+
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(1, 1)).with(Span::row(2)))
+        .with(Height::limit_table(7));
+
+    assert_eq!(table.validate(), Ok(()));
+
+    let expected = concat!(
+        "+------+------+\n",
+        "| i32  | i32  |\n",
+        "+------+------+\n",
+        "|  0   |  0   |\n",
+        "+------+------+\n",
+        "|▼ 5 more rows|\n",
+        "+-------------+\n",
+    );
+
+    assert_eq!(table.to_string(), expected);
+}
@@ -0,0 +1,203 @@
+use tabled::{
+    style::Border, Cell, Column, Disable, Header, Highlight, Modify, Object, Row, Style, Table,
+};
+
+#[test]
+fn highlight_column() {
+    let data = vec![(0, "Grodno", true), (1, "Minsk", true)];
+
+    let table = Table::new(&data)
+        .with(Style::pseudo())
+        .with(Highlight::new(
+            Column(1..2),
+            Border::full('━', '━', '┃', '┃', '┏', '┓', '┗', '┛'),
+        ))
+        .to_string();
+
+    let expected = concat!(
+        "┌─────┏━━━━━━━━┓──────┐\n",
+        "│ i32 ┃  &str  ┃ bool │\n",
+        "├─────┃────────┃──────┤\n",
+        "│  0  ┃ Grodno ┃ true │\n",
+        "├─────┃────────┃──────┤\n",
+        "│  1  ┃ Minsk  ┃ true │\n",
+        "└─────┗━━━━━━━━┛──────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_not_rectangular_region() {
+    let data = vec![(0, "Grodno", true), (1, "Minsk", true)];
+
+    let table = Table::new(&data)
+        .with(Highlight::new(
+            Cell(1, 1).and(Cell(2, 1)).and(Cell(2, 2)),
+            Border::filled('#'),
+        ))
+        .to_string();
+
+    let expected = concat!(
+        "+-----+--------+------+\n",
+        "| i32 |  &str  | bool |\n",
+        "+-----##########------+\n",
+        "|  0  # Grodno # true |\n",
+        "+-----#--------########\n",
+        "|  1  # Minsk  | true #\n",
+        "+-----#################\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_is_ignored_where_there_is_no_line() {
+    let data = vec![(0, "Grodno"), (1, "Minsk")];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Highlight::new(Cell(1, 0), Border::filled('#')))
+        .to_string();
+
+    let expected = concat!(
+        " i32 |  &str  \n",
+        "######--------\n",
+        "  0  # Grodno \n",
+        "  1  | Minsk  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn border_of_a_cell() {
+    let data = vec![(0, "Grodno", true), (1, "Minsk", true)];
+
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(1, 1)).with(Border::filled('#')))
+        .to_string();
+
+    let expected = concat!(
+        "+-----+--------+------+\n",
+        "| i32 |  &str  | bool |\n",
+        "+-----##########------+\n",
+        "|  0  # Grodno # true |\n",
+        "+-----##########------+\n",
+        "|  1  | Minsk  | true |\n",
+        "+-----+--------+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn border_of_a_cell_keeps_not_set_characters() {
+    let data = vec![(0, "Grodno", true), (1, "Minsk", true)];
+
+    let table = Table::new(&data)
+        .with(Style::pseudo())
+        .with(Modify::new(Cell(2, 2)).with(Border::new().top('═').bottom('═')))
+        .to_string();
+
+    let expected = concat!(
+        "┌─────┬────────┬──────┐\n",
+        "│ i32 │  &str  │ bool │\n",
+        "├─────┼────────┼──────┤\n",
+        "│  0  │ Grodno │ true │\n",
+        "├─────┼────────┼══════┤\n",
+        "│  1  │ Minsk  │ true │\n",
+        "└─────┴────────┴══════┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn border_of_each_cell_of_a_row() {
+    let data = vec![(0, "Grodno"), (1, "Minsk")];
+
+    let table = Table::new(&data)
+        .with(Modify::new(Row(2..)).with(Border::new().left('*').right('*')))
+        .to_string();
+
+    let expected = concat!(
+        "+-----+--------+\n",
+        "| i32 |  &str  |\n",
+        "+-----+--------+\n",
+        "|  0  | Grodno |\n",
+        "+-----+--------+\n",
+        "*  1  * Minsk  *\n",
+        "+-----+--------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_is_moved_by_removed_rows() {
+    let data = vec![(0, "a"), (2, "b"), (4, "c")];
+
+    let table = Table::new(&data)
+        .with(Highlight::new(Cell(2, 1), Border::filled('*')))
+        .with(Disable::row(..1))
+        .to_string();
+
+    let expected = concat!(
+        "+---+---+\n",
+        "| 0 | a |\n",
+        "+---*****\n",
+        "| 2 * b *\n",
+        "+---*****\n",
+        "| 4 | c |\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_is_moved_by_removed_columns() {
+    let data = vec![(0, "a"), (2, "b")];
+
+    let table = Table::new(&data)
+        .with(Highlight::new(Cell(2, 1), Border::filled('*')))
+        .with(Disable::column(..1))
+        .to_string();
+
+    let expected = concat!(
+        "+------+\n",
+        "| &str |\n",
+        "+------+\n",
+        "|  a   |\n",
+        "********\n",
+        "*  b   *\n",
+        "********\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_is_moved_by_inserted_rows() {
+    let data = vec![(0, "a"), (2, "b")];
+
+    let table = Table::new(&data)
+        .with(Highlight::new(Cell(1, 1), Border::filled('*')))
+        .with(Header("header"))
+        .to_string();
+
+    let expected = concat!(
+        "+------------+\n",
+        "|header      |\n",
+        "+-----+------+\n",
+        "| i32 | &str |\n",
+        "+-----********\n",
+        "|  0  *  a   *\n",
+        "+-----********\n",
+        "|  2  |  b   |\n",
+        "+-----+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "color")]
+
+use tabled::{Cell, Full, Hyperlink, Modify, Style, Table};
+
+#[test]
+fn hyperlink_content_test() {
+    let table = Table::new(["https://a.io", "b"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Hyperlink::content()))
+        .to_string();
+
+    let expected = concat!(
+        "     &str     \n",
+        "--------------\n",
+        " \u{1b}]8;;https://a.io\u{1b}\\https://a.io\u{1b}]8;;\u{1b}\\ \n",
+        "      b       \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn hyperlink_multiline_test() {
+    let table = Table::new(["a\n\nbc"])
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Hyperlink::new("x")))
+        .to_string();
+
+    let expected = concat!(
+        " \u{1b}]8;;x\u{1b}\\&str\u{1b}]8;;\u{1b}\\ \n",
+        "------\n",
+        "  \u{1b}]8;;x\u{1b}\\a\u{1b}]8;;\u{1b}\\   \n",
+        "      \n",
+        "  \u{1b}]8;;x\u{1b}\\bc\u{1b}]8;;\u{1b}\\  \n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,49 @@
+use tabled::{Alignment, Cell, Full, Incremental, Modify, Style, Table, WidthCache};
+
+#[test]
+fn incremental_matches_full_render() {
+    let data = [("a", 1), ("bb", 22), ("ccc", 333)];
+    let mut incremental = Table::new(data).with(Style::psql()).with(Incremental);
+    let mut table = Table::new(data).with(Style::psql());
+
+    for (row, text) in [(1, "a long name"), (3, "c"), (1, "")] {
+        incremental = incremental.with(Cell(row, 0).map_content(move |_| text.to_owned()));
+        table = table.with(Cell(row, 0).map_content(move |_| text.to_owned()));
+
+        assert_eq!(incremental.to_string(), table.to_string());
+    }
+}
+
+#[test]
+fn incremental_measures_again_after_style_change() {
+    let table = Table::new(["a", "bb"])
+        .with(Style::psql())
+        .with(Incremental);
+    assert_eq!(table.to_string(), " &str \n------\n  a   \n  bb  \n");
+
+    let table = table.with(Modify::new(Full).with(Alignment::left()));
+    assert_eq!(table.to_string(), "&str\n----\na   \nbb  \n");
+}
+
+#[test]
+fn width_cache_matches_full_render() {
+    let data = [("a", 1), ("bb", 22), ("ccc", 333)];
+    let cached = Table::new(data).with(Style::psql()).with(WidthCache);
+    let table = Table::new(data).with(Style::psql());
+    assert_eq!(cached.to_string(), table.to_string());
+
+    let data = [("ccc", 1), ("a long name", 22), ("bb", 4444)];
+    let cached = cached
+        .with_records(data)
+        .with(Modify::new(Full).with(Alignment::left()));
+    let table = table
+        .with_records(data)
+        .with(Modify::new(Full).with(Alignment::left()));
+    assert_eq!(cached.to_string(), table.to_string());
+
+    let stats = cached.width_cache_stats();
+    assert_eq!(stats.misses, 8 + 2);
+    assert_eq!(stats.hits, 6);
+    // widths of "a" and "333" are dropped as they are not in the table anymore
+    assert_eq!(stats.entries, 8);
+}
@@ -144,3 +144,55 @@ fn indent_multiline_with_vertical_alignment() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn indent_inside_alignment() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Alignment::center_horizontal()))
+        .with(Modify::new(Row(1..)).with(Indent::new(4, 0, 0, 0).inside_alignment()))
+        .to_string();
+
+    let expected = concat!(
+        " id  |destribution|            link             \n",
+        "-----+------------+-----------------------------\n",
+        "    0|    Fedora  |    https://getfedora.org/   \n",
+        "    2|    OpenSUSE|    https://www.opensuse.org/\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn indent_inside_alignment_keeps_spaces_of_right_aligned_numbers() {
+    let data = ["1.5 ", "10.25", "100  "];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Alignment::right()))
+        .with(Modify::new(Full).with(Indent::new(2, 1, 0, 0).inside_alignment()))
+        .to_string();
+
+    let expected = concat!(
+        "   &str \n",
+        "--------\n",
+        "   1.5  \n",
+        "  10.25 \n",
+        "  100   \n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,36 @@
+use tabled::{Alignment, Column, Index, Modify, Style, Table};
+
+#[test]
+fn index_default_test() {
+    let table = Table::new([("a", 1), ("b", 2)])
+        .with(Index::new())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   | &str | i32 \n",
+        "---+------+-----\n",
+        " 0 |  a   |  1  \n",
+        " 1 |  b   |  2  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn index_keeps_settings_of_columns_test() {
+    let table = Table::new(["a", "bb"])
+        .with(Modify::new(Column(..1)).with(Alignment::right()))
+        .with(Index::default().name("row"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " row |&str\n",
+        "-----+----\n",
+        "  0  |   a\n",
+        "  1  |  bb\n",
+    );
+
+    assert_eq!(table, expected);
+}
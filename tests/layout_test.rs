@@ -0,0 +1,94 @@
+use tabled::{
+    Alignment, Full, LayoutOptions, Modify, Panel, Remainder, SeparatorOwnership, Spread, Style,
+    Table,
+};
+
+fn table() -> Table {
+    Table::new([(1, 2, 3)])
+        .with(Panel("a much wider panel", 0))
+        .with(Style::psql())
+}
+
+#[test]
+fn layout_default() {
+    assert_eq!(
+        table().with(LayoutOptions::new()).to_string(),
+        table().to_string()
+    );
+}
+
+#[test]
+fn layout_span_spread_trailing() {
+    let table = table().with(LayoutOptions::new().span_spread(Spread::Trailing));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "a much wider panel\n",
+            "-----+-----+------\n",
+            " i32 | i32 | i32  \n",
+            "  1  |  2  |  3   \n",
+        )
+    );
+}
+
+#[test]
+fn layout_span_spread_leading() {
+    let table = table().with(LayoutOptions::new().span_spread(Spread::Leading));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "a much wider panel\n",
+            "------+-----+-----\n",
+            " i32  | i32 | i32 \n",
+            "  1   |  2  |  3  \n",
+        )
+    );
+}
+
+#[test]
+fn layout_span_separators_columns() {
+    let table = table().with(LayoutOptions::new().span_separators(SeparatorOwnership::Columns));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "a much wider panel  \n",
+            "------+------+------\n",
+            " i32  | i32  | i32  \n",
+            "  1   |  2   |  3   \n",
+        )
+    );
+}
+
+#[test]
+fn layout_center_remainder() {
+    let table = Table::new(["a", "abcd"])
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Alignment::center_horizontal()))
+        .with(LayoutOptions::new().center_remainder(Remainder::Start));
+
+    assert_eq!(
+        table.to_string(),
+        concat!("&str\n", "----\n", "  a \n", "abcd\n",)
+    );
+}
+
+#[test]
+fn layout_span_spread_proportional() {
+    let table = Table::new([("a", "a longer value", 3)])
+        .with(Panel("a panel which is much wider than the table", 0))
+        .with(Style::psql())
+        .with(LayoutOptions::new().span_spread(Spread::Proportional));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "a panel which is much wider than the table\n",
+            "---------+------------------------+-------\n",
+            "  &str   |          &str          |  i32  \n",
+            "    a    |     a longer value     |   3   \n",
+        )
+    );
+}
@@ -0,0 +1,83 @@
+use tabled::{Cell, Modify, Nested, Style, Table};
+
+#[test]
+fn nested_widens_column() {
+    let nested = Table::new([("a", "b")]).with(Style::psql());
+    let table = Table::new([1, 2])
+        .with(Modify::new(Cell(1, 0)).with(Nested(nested)))
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "     i32     \n",
+            "-------------\n",
+            " &str | &str \n",
+            "------+------\n",
+            "  a   |  b   \n",
+            "      2      \n",
+        )
+    );
+}
+
+#[test]
+fn nested_is_stretched() {
+    let nested = Table::new(["a"]).with(Style::psql());
+    let table =
+        Table::new(["a very long line", "x"]).with(Modify::new(Cell(2, 0)).with(Nested(nested)));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+------------------+\n",
+            "|       &str       |\n",
+            "+------------------+\n",
+            "| a very long line |\n",
+            "+------------------+\n",
+            "|       &str       |\n",
+            "|------------------|\n",
+            "|        a         |\n",
+            "+------------------+\n",
+        )
+    );
+}
+
+#[test]
+fn nested_in_nested() {
+    let inner = Table::new(["x"]).with(Style::psql());
+    let middle = Table::new(["y"])
+        .with(Modify::new(Cell(1, 0)).with(Nested(inner)))
+        .with(Style::psql());
+    let table = Table::new(["z"]).with(Modify::new(Cell(1, 0)).with(Nested(middle)));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+------+\n",
+            "| &str |\n",
+            "+------+\n",
+            "| &str |\n",
+            "|------|\n",
+            "| &str |\n",
+            "|------|\n",
+            "|  x   |\n",
+            "+------+\n",
+        )
+    );
+}
+
+#[test]
+fn nested_text_is_exported() {
+    let nested = Table::new([1]).with(Style::psql());
+    let table = Table::new(["x"]).with(Modify::new(Cell(1, 0)).with(Nested(nested)));
+
+    assert_eq!(
+        table.to_html(),
+        concat!(
+            "<table>\n",
+            "<tr><th>&amp;str</th></tr>\n",
+            "<tr><td> i32 <br>-----<br>  1  </td></tr>\n",
+            "</table>\n",
+        )
+    );
+}
@@ -0,0 +1,55 @@
+use tabled::{Column, Modify, Newlines, Raw, Style, Table};
+
+#[test]
+fn newlines_escape() {
+    let table = Table::new(["a\nb\r\nc", "d"])
+        .with(Newlines::Escape)
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!("  &str   \n", "---------\n", " a\\nb\\nc \n", "    d    \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn newlines_replace() {
+    let table = Table::new(["a\nb"])
+        .with(Newlines::Replace(String::from(" ⏎ ")))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str  \n", "-------\n", " a ⏎ b \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn newlines_cells() {
+    let table = Table::new([("a\nb", "c\nd")])
+        .with(Modify::new(Column(1..)).with(Newlines::Escape))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  a   | c\\nd \n",
+        "  b   |      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn newlines_skips_raw_cells() {
+    let table = Table::new(["a\nb"])
+        .with(Modify::new(Column(..)).with(Raw))
+        .with(Newlines::Escape)
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!("&str\n", "----\n", "a   \n", "b   \n",);
+
+    assert_eq!(table, expected);
+}
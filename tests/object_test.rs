@@ -0,0 +1,87 @@
+use tabled::{
+    Alignment, Columns, FirstColumn, FirstRow, Format, Indent, LastColumn, LastRow, Modify, Object,
+    Rows, Style, Table,
+};
+
+fn table() -> Table {
+    Table::new([(1, "a", true), (2, "b", false), (3, "c", true)])
+}
+
+#[test]
+fn first_row_and_last_column() {
+    let table = table()
+        .with(Style::psql())
+        .with(Modify::new(FirstRow).with(Format(|s| s.to_uppercase())))
+        .with(Modify::new(LastColumn.not(FirstRow)).with(Alignment::right()))
+        .to_string();
+
+    let expected = concat!(
+        " I32 | &STR | BOOL \n",
+        "-----+------+------\n",
+        "  1  |  a   |  true\n",
+        "  2  |  b   | false\n",
+        "  3  |  c   |  true\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn rows_and_columns_intersection() {
+    let table = table()
+        .with(Style::psql())
+        .with(
+            Modify::new(Rows::new(1..3).intersect(Columns::single(1)))
+                .with(Format(|s| format!("[{}]", s))),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " i32 | &str | bool  \n",
+        "-----+------+-------\n",
+        "  1  | [a]  | true  \n",
+        "  2  | [b]  | false \n",
+        "  3  |  c   | true  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn first_column_and_last_row_union() {
+    let table = table()
+        .with(Style::psql())
+        .with(Modify::new(FirstColumn.and(LastRow)).with(Indent::new(0, 0, 0, 0)))
+        .to_string();
+
+    let expected = concat!(
+        "i32| &str | bool  \n",
+        "---+------+-------\n",
+        "1  |  a   | true  \n",
+        "2  |  b   | false \n",
+        "3  |c     |true   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn rows_and_columns_out_of_grid_are_ignored() {
+    let table = table()
+        .with(Style::psql())
+        .with(
+            Modify::new(Rows::new(2..10).and(Columns::new(5..)))
+                .with(Format(|s| format!("*{}", s))),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " i32 | &str |  bool  \n",
+        "-----+------+--------\n",
+        "  1  |  a   |  true  \n",
+        " *2  |  *b  | *false \n",
+        " *3  |  *c  | *true  \n",
+    );
+
+    assert_eq!(table, expected);
+}
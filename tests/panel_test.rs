@@ -1,4 +1,7 @@
-use tabled::{Alignment, Footer, Full, Header, Modify, Object, Panel, Row, Style, Table, Tabled};
+use tabled::{
+    Alignment, Builder, EmptyState, Footer, Full, Header, Modify, Object, Panel, Row, Style, Table,
+    Tabled,
+};
 
 #[derive(Tabled)]
 struct Linux {
@@ -16,12 +19,12 @@ fn top_panel() {
         .to_string();
 
     let expected = concat!(
-        "            Linux Distributions             \n",
-        "--------------------------------------------\n",
-        "id |destribution |           link           \n",
-        " 0 |   Fedora    |  https://getfedora.org/  \n",
-        " 2 |  OpenSUSE   |https://www.opensuse.org/ \n",
-        " 3 | Endeavouros | https://endeavouros.com/ \n",
+        "Linux Distributions                      \n",
+        "--+------------+-------------------------\n",
+        "id|destribution|          link           \n",
+        "0 |   Fedora   | https://getfedora.org/  \n",
+        "2 |  OpenSUSE  |https://www.opensuse.org/\n",
+        "3 |Endeavouros |https://endeavouros.com/ \n",
     );
 
     assert_eq!(table, expected);
@@ -78,7 +81,7 @@ fn header() {
 
     let expected = concat!(
         "              Linux Distributions              \n",
-        "-----------------------------------------------\n",
+        "----+--------------+---------------------------\n",
         " id | destribution |           link            \n",
         " 0  |    Fedora    |  https://getfedora.org/   \n",
         " 2  |   OpenSUSE   | https://www.opensuse.org/ \n",
@@ -100,7 +103,7 @@ fn footer() {
 
     let expected = concat!(
         "             Linux Distributions             \n",
-        "---------------------------------------------\n",
+        "----+--------------+-------------------------\n",
         " id | destribution |          link           \n",
         " 0  |    Fedora    | https://getfedora.org/  \n",
         " 2  |   OpenSUSE   |https://www.opensuse.org/\n",
@@ -130,3 +133,49 @@ fn test_data() -> Vec<Linux> {
         },
     ]
 }
+
+#[test]
+fn panel_wider_than_table_expands_columns() {
+    let table = Table::new([(1, 2)])
+        .with(Panel("a panel wider than columns", 0))
+        .with(Style::default())
+        .to_string();
+
+    let expected = concat!(
+        "+--------------------------+\n",
+        "|a panel wider than columns|\n",
+        "+-------------+------------+\n",
+        "|     i32     |    i32     |\n",
+        "+-------------+------------+\n",
+        "|      1      |     2      |\n",
+        "+-------------+------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn empty_state_without_cells() {
+    let table = Builder::new()
+        .build()
+        .with(EmptyState("no data"))
+        .with(Style::default())
+        .to_string();
+
+    let expected = concat!("+---------+\n", "| no data |\n", "+---------+\n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn empty_state_with_data() {
+    let data = vec![Linux {
+        id: 0,
+        destribution: "Fedora",
+        link: "https://getfedora.org/",
+    }];
+
+    let table = Table::new(&data).with(EmptyState("no data")).to_string();
+
+    assert_eq!(table, Table::new(&data).to_string());
+}
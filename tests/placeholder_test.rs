@@ -0,0 +1,60 @@
+use tabled::{Cell, Full, Modify, Placeholder, Raw, Style, Table, Tabled};
+
+#[test]
+fn placeholder_test() {
+    let data = [["a", " "], ["", "b"]];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Placeholder::new("-")))
+        .to_string();
+
+    let expected = concat!(" 0 | 1 \n", "---+---\n", " a | - \n", " - | b \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn placeholder_skips_raw_cells_test() {
+    let data = [["", ""]];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Raw))
+        .with(Modify::new(Full).with(Placeholder::new("-")))
+        .to_string();
+
+    let expected = concat!(" 0 | 1 \n", "---+---\n", "   | - \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn placeholder_derive_test() {
+    #[derive(Tabled)]
+    #[tabled(placeholder = "N/A")]
+    struct Distribution {
+        name: &'static str,
+        based_on: Option<&'static str>,
+    }
+
+    let data = [
+        Distribution {
+            name: "Debian",
+            based_on: None,
+        },
+        Distribution {
+            name: "Ubuntu",
+            based_on: Some("Debian"),
+        },
+    ];
+
+    let table = Table::new(&data).with(Style::psql()).to_string();
+
+    let expected = concat!(
+        "  name  | based_on \n",
+        "--------+----------\n",
+        " Debian |   N/A    \n",
+        " Ubuntu |  Debian  \n",
+    );
+
+    assert_eq!(table, expected);
+}
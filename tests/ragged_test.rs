@@ -0,0 +1,89 @@
+use tabled::{Builder, Ragged, Style, Table};
+
+fn gallery() -> Table {
+    Builder::new()
+        .add_row(["1", "2", "3"])
+        .add_row(["4", "5", "6"])
+        .add_row(["7", "8"])
+        .build()
+}
+
+#[test]
+fn ragged_default_style() {
+    let table = gallery().with(Style::default()).with(Ragged).to_string();
+
+    let expected = concat!(
+        "+---+---+---+\n",
+        "| 1 | 2 | 3 |\n",
+        "+---+---+---+\n",
+        "| 4 | 5 | 6 |\n",
+        "+---+---+---+\n",
+        "| 7 | 8 |\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn ragged_pseudo_style() {
+    let table = gallery().with(Style::pseudo()).with(Ragged).to_string();
+
+    let expected = concat!(
+        "┌───┬───┬───┐\n",
+        "│ 1 │ 2 │ 3 │\n",
+        "├───┼───┼───┤\n",
+        "│ 4 │ 5 │ 6 │\n",
+        "├───┼───┼───┘\n",
+        "│ 7 │ 8 │\n",
+        "└───┴───┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn ragged_without_split_lines() {
+    let table = gallery()
+        .with(Style::pseudo_clean())
+        .with(Ragged)
+        .to_string();
+
+    let expected = concat!(
+        "┌───┬───┬───┐\n",
+        "│ 1 │ 2 │ 3 │\n",
+        "├───┼───┼───┤\n",
+        "│ 4 │ 5 │ 6 │\n",
+        "│   │   │───┘\n",
+        "│ 7 │ 8 │\n",
+        "└───┴───┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn ragged_single_row() {
+    let table = Builder::new()
+        .add_row(["1", "", ""])
+        .build()
+        .with(Style::pseudo())
+        .with(Ragged)
+        .to_string();
+
+    assert_eq!(table, concat!("┌───┐\n", "│ 1 │\n", "└───┘\n"));
+}
+
+#[test]
+fn ragged_psql_style() {
+    let table = gallery().with(Style::psql()).with(Ragged).to_string();
+
+    let expected = concat!(
+        " 1 | 2 | 3 \n",
+        "---+---+---\n",
+        " 4 | 5 | 6 \n",
+        " 7 | 8 \n",
+    );
+
+    assert_eq!(table, expected);
+}
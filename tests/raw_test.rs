@@ -0,0 +1,42 @@
+use tabled::{Alignment, Cell, Full, MaxWidth, Modify, Raw, Style, Table};
+
+#[test]
+fn raw_cell_is_not_aligned_nor_padded() {
+    let table = Table::new([("abcd", "x"), ("e", "y")])
+        .with(Style::psql().alignment(Alignment::right()))
+        .with(Modify::new(Cell(2, 0)).with(Raw));
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        " abcd |    x \n",
+        "e     |    y \n",
+    );
+
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn raw_cell_is_not_truncated() {
+    let table = Table::new(["abcdef", "ghijkl"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Raw))
+        .with(Modify::new(Full).with(MaxWidth(3, "")));
+
+    let expected = concat!(" &st  \n", "------\n", "abcdef\n", " ghi  \n",);
+
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+#[cfg(feature = "color")]
+fn raw_precolored_cell() {
+    let content = "\u{1b}[31mred\u{1b}[0m";
+    let table = Table::new([content])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Raw));
+
+    let expected = format!(" &str \n------\n{}   \n", content);
+
+    assert_eq!(table.to_string(), expected);
+}
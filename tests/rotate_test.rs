@@ -1,6 +1,6 @@
 // todo: add method for SPACING between cells.
 //       add MARGIN && PADDING instead of indent?
-use tabled::{Full, Indent, Modify, Rotate, Style, Table, Tabled};
+use tabled::{Alignment, Cell, Full, Indent, Modify, Rotate, Span, Style, Table, Tabled};
 
 #[derive(Tabled)]
 struct Linux {
@@ -44,3 +44,104 @@ fn rotate_left() {
         ),
     );
 }
+
+#[test]
+fn rotate_transpose() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let table = Table::new(&data)
+        .with(Rotate::Transpose)
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "      id      |           0            |             2             \n",
+            "--------------+------------------------+---------------------------\n",
+            " destribution |         Fedora         |         OpenSUSE          \n",
+            "     link     | https://getfedora.org/ | https://www.opensuse.org/ \n",
+        ),
+    );
+}
+
+#[test]
+fn rotate_cell_content() {
+    let table = Table::new(["ab\nc"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Rotate::Right))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str \n", "------\n", "  ca  \n", "  b   \n")
+    );
+
+    let table = Table::new(["ab\nc"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Rotate::Left))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str \n", "------\n", "  b   \n", "  ac  \n")
+    );
+
+    let table = Table::new(["ab\nc"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Rotate::Bottom))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str \n", "------\n", "  c   \n", "  ab  \n")
+    );
+}
+
+#[test]
+fn rotate_all_cells() {
+    let table = Table::new(["abc"])
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Rotate::Transpose))
+        .to_string();
+
+    let expected = concat!(" & \n", " s \n", " t \n", " r \n", "---\n", " a \n", " b \n", " c \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn rotate_transpose_moves_spans_and_settings() {
+    let table = Table::new([(1, 2, 3), (4444, 5, 6)])
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(
+            Modify::new(Cell(2, 1))
+                .with(Alignment::left())
+                .with(Indent::new(1, 1, 0, 0)),
+        )
+        .with(Rotate::Transpose)
+        .to_string();
+
+    let expected = concat!(
+        "+-----+---+------+\n",
+        "| i32 | 1 | 4444 |\n",
+        "+-----+   +------+\n",
+        "| i32 |   | 5    |\n",
+        "+-----+---+------+\n",
+        "| i32 | 3 |  6   |\n",
+        "+-----+---+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
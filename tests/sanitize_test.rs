@@ -0,0 +1,58 @@
+use tabled::{Column, Modify, Raw, Sanitize, Style, Table};
+
+#[test]
+fn sanitize_strip() {
+    let table = Table::new(["ab\u{8}c\u{7}", "d\r\ne"])
+        .with(Sanitize::Strip)
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", " abc  \n", "  d   \n", "  e   \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sanitize_replace() {
+    let table = Table::new(["a\u{0}\u{1b}\u{7f}\u{85}\tb"])
+        .with(Sanitize::Replace)
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!("   &str    \n", "-----------\n", " a␀␛␡�   b \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sanitize_escape() {
+    let table = Table::new(["a\rb\u{8}"])
+        .with(Sanitize::Escape)
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!("   &str    \n", "-----------\n", " a\\rb\\u{8} \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sanitize_cells() {
+    let table = Table::new([("a\rb", "c\rd")])
+        .with(Modify::new(Column(1..)).with(Sanitize::Strip))
+        .with(Style::psql())
+        .to_string();
+
+    assert!(table.contains("a\rb"));
+    assert!(table.contains("cd"));
+}
+
+#[test]
+fn sanitize_keeps_raw_cells() {
+    let table = Table::new(["a\rb"])
+        .with(Modify::new(Column(..)).with(Raw))
+        .with(Sanitize::Strip)
+        .to_string();
+
+    assert!(table.contains("a\rb"));
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "serde")]
+
+use tabled::{Alignment, Full, Indent, Modify, Style, Table, Theme};
+
+#[test]
+fn theme_from_json() {
+    let theme = serde_json::to_string(&Theme::new(Style::psql()).padding(2, 0)).unwrap();
+    let theme: Theme = serde_json::from_str(&theme).unwrap();
+
+    let table = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(theme)
+        .to_string();
+
+    let expected = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(Theme::new(Style::psql()).padding(2, 0))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_from_json() {
+    let style = serde_json::to_string(&Style::pseudo().padding(0, 1)).unwrap();
+    let style: Style = serde_json::from_str(&style).unwrap();
+
+    let table = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(style)
+        .to_string();
+
+    let expected = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(Style::pseudo().padding(0, 1))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn cell_options_from_json() {
+    let alignment: Alignment = serde_json::from_str(r#"{"Horizontal":"Right"}"#).unwrap();
+    let indent: Indent = serde_json::from_str("[0,2,0,0,null]").unwrap();
+
+    let table = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(Style::psql())
+        .with(Modify::new(Full).with(alignment).with(indent))
+        .to_string();
+
+    let expected = concat!(
+        "&str  | i32  \n",
+        "------+------\n",
+        "Rust  |2010  \n",
+        "  Go  |2009  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn from_serialize_maps_test() {
+    use std::collections::BTreeMap;
+
+    let mut a = BTreeMap::new();
+    a.insert("id", vec![1, 2]);
+    let mut b = BTreeMap::new();
+    b.insert("name", vec![3]);
+
+    let table = Table::from_serialize(&[a, b])
+        .unwrap()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   id   | name \n",
+        "--------+------\n",
+        " [1, 2] |      \n",
+        "        | [3]  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn from_serialize_tuples_and_values_test() {
+    let table = Table::from_serialize(&[(1, Some("a")), (2, None)])
+        .unwrap()
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " 0 | 1 \n---+---\n 1 | a \n 2 |   \n");
+
+    let table = Table::from_serialize(&[String::from("x")])
+        .unwrap()
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " String \n--------\n   x    \n");
+}
+
+#[test]
+fn from_serialize_error_test() {
+    struct Broken;
+
+    impl serde::Serialize for Broken {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("broken"))
+        }
+    }
+
+    let err = Table::from_serialize(&[Broken]).err().unwrap();
+    assert_eq!(err.to_string(), "broken");
+}
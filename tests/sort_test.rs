@@ -0,0 +1,161 @@
+use tabled::{Cell, Modify, Sort, Span, Style, Table};
+
+fn size(s: &str) -> u64 {
+    let (n, unit) = s.split_at(s.len() - 1);
+    let n: u64 = n.parse().unwrap();
+    match unit {
+        "K" => n * 1024,
+        "M" => n * 1024 * 1024,
+        _ => n,
+    }
+}
+
+#[test]
+fn sort_by_index() {
+    let table = Table::new([("b", 1), ("c", 2), ("a", 3)])
+        .with(Sort::new(0))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   |  3  \n",
+        "  b   |  1  \n",
+        "  c   |  2  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_descending_by_name() {
+    let table = Table::new([("b", 1), ("c", 2), ("a", 3)])
+        .with(Sort::new("i32").descending())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   |  3  \n",
+        "  c   |  2  \n",
+        "  b   |  1  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_with_comparator_is_reused_for_other_columns() {
+    let data = [("2M", "x"), ("10K", "z"), ("3K", "y")];
+
+    let mut sort = Sort::new(0).comparator(0, |a, b| size(a).cmp(&size(b)));
+
+    let table = Table::new(data).with(&mut sort).with(Style::psql());
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  3K  |  y   \n",
+        " 10K  |  z   \n",
+        "  2M  |  x   \n",
+    );
+    assert_eq!(table.to_string(), expected);
+
+    let mut sort = sort.column(1);
+    let table = table.with(&mut sort);
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  2M  |  x   \n",
+        "  3K  |  y   \n",
+        " 10K  |  z   \n",
+    );
+    assert_eq!(table.to_string(), expected);
+
+    let table = table.with(sort.column(0).descending());
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  2M  |  x   \n",
+        " 10K  |  z   \n",
+        "  3K  |  y   \n",
+    );
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn sort_unknown_column_does_nothing() {
+    let table = Table::new([("b", 1), ("a", 2)])
+        .with(Sort::new("unknown"))
+        .with(Sort::new(10))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  b   |  1  \n",
+        "  a   |  2  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_numeric() {
+    let table = Table::new(["10", "-1.5", "n/a", "9", "0.25"])
+        .with(Sort::new(0).numeric())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str \n", "------\n", " -1.5 \n", " 0.25 \n", "  9   \n", "  10  \n", " n/a  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_natural_descending() {
+    let table = Table::new(["file10", "file2", "file02b", "file", "img1"])
+        .with(Sort::new(0).natural().descending())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "  &str   \n",
+        "---------\n",
+        "  img1   \n",
+        " file10  \n",
+        " file02b \n",
+        "  file2  \n",
+        "  file   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_moves_row_spans_together() {
+    let table = Table::new([("c", 1), ("d", 2), ("a", 3), ("b", 4)])
+        .with(Modify::new(Cell(1, 1)).with(Span::row(2)))
+        .with(Sort::new(0))
+        .to_string();
+
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  a   |  3  |\n",
+        "+------+-----+\n",
+        "|  b   |  4  |\n",
+        "+------+-----+\n",
+        "|  c   |  1  |\n",
+        "+------+     |\n",
+        "|  d   |     |\n",
+        "+------+-----+\n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,125 @@
+use tabled::{Cell, Modify, Panel, Span, Style, Table, ValidationError};
+
+fn table() -> Table {
+    Table::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]])
+}
+
+#[test]
+fn span_column() {
+    let table = table().with(Modify::new(Cell(1, 0)).with(Span::column(2)));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+---+---+---+\n",
+            "| 0 | 1 | 2 |\n",
+            "+---+---+---+\n",
+            "|   1   | 3 |\n",
+            "+---+---+---+\n",
+            "| 4 | 5 | 6 |\n",
+            "+---+---+---+\n",
+            "| 7 | 8 | 9 |\n",
+            "+---+---+---+\n",
+        )
+    );
+}
+
+#[test]
+fn span_row() {
+    let table = table()
+        .with(Modify::new(Cell(1, 2)).with(Span::row(3)))
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " 0 | 1 | 2 \n",
+            "---+---+---\n",
+            " 1 | 2 | 3 \n",
+            " 4 | 5 |   \n",
+            " 7 | 8 |   \n",
+        )
+    );
+}
+
+#[test]
+fn span_region_with_multiline_content() {
+    let table = table()
+        .with(Modify::new(Cell(2, 0)).with(|_: &str| "a\nb\nc\nd".to_owned()))
+        .with(Modify::new(Cell(2, 0)).with(Span::new(2, 2)));
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+---+---+---+\n",
+            "| 0 | 1 | 2 |\n",
+            "+---+---+---+\n",
+            "| 1 | 2 | 3 |\n",
+            "+---+---+---+\n",
+            "|   a   | 6 |\n",
+            "|   b   |   |\n",
+            "|   c   +---+\n",
+            "|   d   | 9 |\n",
+            "+-------+---+\n",
+        )
+    );
+}
+
+#[test]
+fn span_row_keeps_column_span() {
+    let table = table()
+        .with(Modify::new(Cell(1, 1)).with(Span::column(2)))
+        .with(Modify::new(Cell(1, 1)).with(Span::row(2)))
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " 0 | 1 | 2 \n",
+            "---+---+---\n",
+            " 1 |   2   \n",
+            " 4 |       \n",
+            " 7 | 8 | 9 \n",
+        )
+    );
+}
+
+#[test]
+fn span_junctions() {
+    let table = table()
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(2, 1)).with(Span::new(2, 2)))
+        .with(Style::pseudo());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "┌───┬───┬───┐\n",
+            "│ 0 │ 1 │ 2 │\n",
+            "├───┴───┼───┤\n",
+            "│   1   │ 3 │\n",
+            "├───┬───┴───┤\n",
+            "│ 4 │   5   │\n",
+            "├───┤       │\n",
+            "│ 7 │       │\n",
+            "└───┴───────┘\n",
+        )
+    );
+}
+
+#[test]
+fn span_validate() {
+    assert_eq!(table().with(Panel("header", 0)).validate(), Ok(()));
+
+    let table = table()
+        .with(Modify::new(Cell(0, 1)).with(Span::column(2)))
+        .with(Modify::new(Cell(0, 2)).with(Span::row(2)));
+
+    assert_eq!(
+        table.validate(),
+        Err(vec![ValidationError::OverlappingSpans {
+            row: 0,
+            column: 2
+        }])
+    );
+}
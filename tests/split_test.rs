@@ -0,0 +1,91 @@
+use tabled::{Split, Style, Table};
+
+fn render(tables: Vec<Table>) -> Vec<String> {
+    tables
+        .into_iter()
+        .map(|table| table.with(Style::psql()).to_string())
+        .collect()
+}
+
+#[test]
+fn split_columns_without_key() {
+    let table = Table::new([(1, "a", true)]);
+
+    assert_eq!(
+        render(table.split(&Split::columns(2))),
+        [
+            concat!(" i32 | &str \n", "-----+------\n", "  1  |  a   \n"),
+            concat!(" bool \n", "------\n", " true \n"),
+        ]
+    );
+}
+
+#[test]
+fn split_columns_key_in_the_middle() {
+    let table = Table::new([(1, "a", true, 'c')]);
+
+    assert_eq!(
+        render(table.split(&Split::columns(2).key(1))),
+        [
+            concat!(
+                " &str | i32 | bool \n",
+                "------+-----+------\n",
+                "  a   |  1  | true \n",
+            ),
+            concat!(" &str | char \n", "------+------\n", "  a   |  c   \n"),
+        ]
+    );
+}
+
+#[test]
+fn split_width() {
+    let table = Table::new([("Grodno", 1000, 2000, 3000)]);
+
+    let parts = render(table.split(&Split::width(24).key(0)));
+
+    assert_eq!(
+        parts,
+        [
+            concat!(
+                "  &str  | i32  | i32  \n",
+                "--------+------+------\n",
+                " Grodno | 1000 | 2000 \n",
+            ),
+            concat!(
+                "  &str  | i32  \n",
+                "--------+------\n",
+                " Grodno | 3000 \n",
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_width_too_narrow() {
+    let table = Table::new([(1000, 2000)]);
+
+    assert_eq!(table.split(&Split::width(3)).len(), 2);
+}
+
+#[test]
+fn split_only_key() {
+    let table = Table::new(["a"]);
+
+    assert_eq!(
+        render(table.split(&Split::columns(1).key(0))),
+        [table.with(Style::psql()).to_string()]
+    );
+}
+
+#[test]
+fn split_key_out_of_table() {
+    let table = Table::new([(1, 2)]);
+
+    assert_eq!(table.split(&Split::columns(1).key(5)).len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn split_zero_columns() {
+    Split::columns(0);
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+use tabled::{StaleRows, Style, Table};
+
+#[test]
+fn stale_rows_marks_unchanged_rows() {
+    let mut stale = StaleRows::new(Duration::ZERO).marker("* ");
+
+    let first = Table::new([("api", "up"), ("db", "up")])
+        .with(&mut stale)
+        .with(Style::psql())
+        .to_string();
+
+    let second = Table::new([("db", "down"), ("api", "up"), ("cache", "up")])
+        .with(&mut stale)
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        " api  |  up  \n",
+        "  db  |  up  \n",
+    );
+
+    #[cfg(not(feature = "color"))]
+    assert_eq!(first, expected);
+    #[cfg(feature = "color")]
+    let _ = (first, expected);
+
+    let expected = concat!(
+        " &str  | &str \n",
+        "-------+------\n",
+        "  db   | down \n",
+        " * api |  up  \n",
+        " cache |  up  \n",
+    );
+
+    #[cfg(not(feature = "color"))]
+    assert_eq!(second, expected);
+    #[cfg(feature = "color")]
+    let _ = (second, expected);
+}
+
+#[test]
+fn stale_rows_within_window_are_not_marked() {
+    let mut stale = StaleRows::new(Duration::from_secs(3600)).marker("* ");
+
+    Table::new(["a"]).with(&mut stale);
+    let table = Table::new(["a"])
+        .with(&mut stale)
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, concat!(" &str \n", "------\n", "  a   \n"));
+}
+
+#[test]
+fn stale_rows_forget_vanished_rows() {
+    let mut stale = StaleRows::new(Duration::ZERO).marker("* ");
+
+    Table::new(["a"]).with(&mut stale);
+    Table::new(["b"]).with(&mut stale);
+    let table = Table::new(["a"])
+        .with(&mut stale)
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, concat!(" &str \n", "------\n", "  a   \n"));
+}
+
+#[test]
+fn stale_rows_key_column() {
+    let mut stale = StaleRows::new(Duration::ZERO).key_column(1).marker("~");
+
+    Table::new([(1, "a")]).with(&mut stale);
+    let table = Table::new([(2, "a")])
+        .with(&mut stale)
+        .with(Style::psql())
+        .to_string();
+
+    #[cfg(not(feature = "color"))]
+    assert_eq!(
+        table,
+        concat!(" i32 | &str \n", "-----+------\n", "  2  |  a   \n")
+    );
+    #[cfg(feature = "color")]
+    let _ = table;
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn stale_rows_are_dimmed() {
+    let mut stale = StaleRows::new(Duration::ZERO);
+
+    Table::new(["a", "b"]).with(&mut stale);
+    let table = Table::new(["a", "c"])
+        .with(&mut stale)
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str \n",
+        "------\n",
+        "  \u{1b}[2ma\u{1b}[0m   \n",
+        "  c   \n",
+    );
+
+    assert_eq!(table, expected);
+}
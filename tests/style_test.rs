@@ -1,5 +1,5 @@
 use tabled::style::Line;
-use tabled::{Style, Table, Tabled};
+use tabled::{Alignment, Cell, Column, Disable, Format, Full, Modify, Span, Style, Table, Tabled};
 
 #[derive(Tabled)]
 struct Linux {
@@ -330,3 +330,491 @@ fn custom_style() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn preview_all_styles() {
+    let previews = Style::preview_all();
+
+    let names = previews.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "default",
+            "noborder",
+            "psql",
+            "github_markdown",
+            "pseudo",
+            "pseudo_clean",
+            "columns_only",
+            "sharp",
+            "rounded",
+            "extended",
+            "double_frame",
+            "dots",
+            "frame"
+        ]
+    );
+
+    assert_eq!(
+        previews[0].1,
+        concat!(
+            "+------+------+\n",
+            "| name | year |\n",
+            "+------+------+\n",
+            "| Rust | 2010 |\n",
+            "+------+------+\n",
+            "|  Go  | 2009 |\n",
+            "+------+------+\n",
+        )
+    );
+}
+
+#[test]
+fn style_padding_and_alignment_defaults() {
+    let table = Table::new([("a", 1), ("bcd", 23)])
+        .with(
+            Style::github_markdown()
+                .padding(2, 1)
+                .alignment(Alignment::right()),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "|  &str |  i32 |\n",
+        "|-------+------|\n",
+        "|     a |    1 |\n",
+        "|   bcd |   23 |\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_defaults_are_overridden_by_later_options() {
+    let table = Table::new([("a", 1), ("bcd", 23)])
+        .with(Style::psql().padding(0, 0).alignment(Alignment::left()))
+        .with(Modify::new(Column(1..)).with(Alignment::right()))
+        .to_string();
+
+    let expected = concat!("&str|i32\n", "----+---\n", "a   |  1\n", "bcd | 23\n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn preset_defaults_replace_defaults_of_a_previous_style() {
+    let table = Table::new(["a", "bcd"])
+        .with(Style::psql().padding(0, 2).alignment(Alignment::left()))
+        .with(Style::github_markdown())
+        .to_string();
+
+    let expected = concat!("| &str |\n", "|------|\n", "|  a   |\n", "| bcd  |\n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_defaults_keep_settings_of_cells() {
+    let table = Table::new(["a"])
+        .with(Modify::new(Full).with(Alignment::left()))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, concat!("&str\n", "----\n", "a   \n"));
+}
+
+#[test]
+fn resolve_intersection_is_unique_for_each_junction() {
+    let mut chars = std::collections::HashSet::new();
+    for mask in 0..16 {
+        let c = tabled::style::resolve_intersection(
+            mask & 1 != 0,
+            mask & 2 != 0,
+            mask & 4 != 0,
+            mask & 8 != 0,
+        );
+        chars.insert(c);
+    }
+
+    assert_eq!(chars.len(), 16);
+}
+
+#[test]
+fn extended_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "╔════╦══════════════╦═══════════════════════════╗\n",
+        "║ id ║ destribution ║           link            ║\n",
+        "╠════╬══════════════╬═══════════════════════════╣\n",
+        "║ 0  ║    Fedora    ║  https://getfedora.org/   ║\n",
+        "╠════╬══════════════╬═══════════════════════════╣\n",
+        "║ 2  ║   OpenSUSE   ║ https://www.opensuse.org/ ║\n",
+        "╚════╩══════════════╩═══════════════════════════╝\n",
+    );
+
+    let table = Table::new(&data).with(Style::extended()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn double_frame_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "╔════╤══════════════╤═══════════════════════════╗\n",
+        "║ id │ destribution │           link            ║\n",
+        "╟────┼──────────────┼───────────────────────────╢\n",
+        "║ 0  │    Fedora    │  https://getfedora.org/   ║\n",
+        "║ 2  │   OpenSUSE   │ https://www.opensuse.org/ ║\n",
+        "╚════╧══════════════╧═══════════════════════════╝\n",
+    );
+
+    let table = Table::new(&data).with(Style::double_frame()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn resolve_stroke_intersection_of_a_double_frame() {
+    use tabled::style::{resolve_stroke_intersection as resolve, Stroke::*};
+
+    // top line
+    assert_eq!(resolve(Empty, Double, Empty, Double), '╔');
+    assert_eq!(resolve(Empty, Single, Double, Double), '╤');
+    assert_eq!(resolve(Empty, Double, Double, Empty), '╗');
+    // header line
+    assert_eq!(resolve(Double, Double, Empty, Single), '╟');
+    assert_eq!(resolve(Single, Single, Single, Single), '┼');
+    assert_eq!(resolve(Double, Double, Single, Empty), '╢');
+    // bottom line
+    assert_eq!(resolve(Double, Empty, Empty, Double), '╚');
+    assert_eq!(resolve(Single, Empty, Double, Double), '╧');
+    assert_eq!(resolve(Double, Empty, Double, Empty), '╝');
+}
+
+#[test]
+fn resolve_stroke_intersection_prefers_a_double_line() {
+    use tabled::style::{resolve_stroke_intersection as resolve, Stroke::*};
+
+    assert_eq!(resolve(Single, Double, Double, Double), '╬');
+    assert_eq!(resolve(Double, Single, Single, Empty), '╢');
+    assert_eq!(resolve(Single, Single, Empty, Empty), '│');
+    assert_eq!(resolve(Empty, Empty, Double, Double), '═');
+    assert_eq!(resolve(Double, Empty, Empty, Empty), '╵');
+    assert_eq!(resolve(Empty, Empty, Empty, Empty), ' ');
+}
+
+#[test]
+fn resolve_stroke_intersection_matches_single_lines() {
+    use tabled::style::{resolve_intersection, resolve_stroke_intersection, Stroke};
+
+    let stroke = |b: bool| if b { Stroke::Single } else { Stroke::Empty };
+    for mask in 0..16 {
+        let (up, down, left, right) = (mask & 1 != 0, mask & 2 != 0, mask & 4 != 0, mask & 8 != 0);
+
+        assert_eq!(
+            resolve_stroke_intersection(stroke(up), stroke(down), stroke(left), stroke(right)),
+            resolve_intersection(up, down, left, right),
+        );
+    }
+}
+
+#[test]
+fn dots_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·\n",
+        "┆ id ┆ destribution ┆           link            ┆\n",
+        "·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·\n",
+        "┆ 0  ┆    Fedora    ┆  https://getfedora.org/   ┆\n",
+        "·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·\n",
+        "┆ 2  ┆   OpenSUSE   ┆ https://www.opensuse.org/ ┆\n",
+        "·┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄·┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄┄·\n",
+    );
+
+    let table = Table::new(&data).with(Style::dots()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn dots_style_with_custom_lines() {
+    let table = Table::new(["a", "b"])
+        .with(Style::dots().horizontal('-').vertical(':'))
+        .to_string();
+
+    let expected = concat!(
+        "·------·\n",
+        ": &str :\n",
+        "·------·\n",
+        ":  a   :\n",
+        "·------·\n",
+        ":  b   :\n",
+        "·------·\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn horizontal_and_vertical_keep_missing_lines() {
+    let table = Table::new([("a", "b")])
+        .with(Style::psql().horizontal('=').vertical('!'))
+        .to_string();
+
+    let expected = concat!(" &str ! &str \n", "======+======\n", "  a   !  b   \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn frame_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        "┌───────────────────────────────────────────────┐\n",
+        "│ id   destribution             link            │\n",
+        "│ 0       Fedora       https://getfedora.org/   │\n",
+        "│ 2      OpenSUSE     https://www.opensuse.org/ │\n",
+        "│ 3    Endeavouros    https://endeavouros.com/  │\n",
+        "└───────────────────────────────────────────────┘\n",
+    );
+
+    let table = Table::new(&data).with(Style::frame()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn frame_style_with_a_span() {
+    let table = Table::new([("a", "b")])
+        .with(Style::frame())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(1, 0)).with(Format(|_| String::from("a long text"))))
+        .to_string();
+
+    let expected = concat!(
+        "┌─────────────┐\n",
+        "│ &str   &str │\n",
+        "│ a long text │\n",
+        "└─────────────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn rounded_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        "╭────┬──────────────┬───────────────────────────╮\n",
+        "│ id │ destribution │           link            │\n",
+        "├────┼──────────────┼───────────────────────────┤\n",
+        "│ 0  │    Fedora    │  https://getfedora.org/   │\n",
+        "│ 2  │   OpenSUSE   │ https://www.opensuse.org/ │\n",
+        "│ 3  │ Endeavouros  │ https://endeavouros.com/  │\n",
+        "╰────┴──────────────┴───────────────────────────╯\n",
+    );
+
+    let table = Table::new(&data).with(Style::rounded()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn rounded_style_with_a_single_row() {
+    let table = Table::new(["a"])
+        .with(Disable::row(..1))
+        .with(Style::rounded())
+        .to_string();
+
+    assert_eq!(table, "╭───╮\n│ a │\n╰───╯\n");
+}
+
+#[test]
+fn sharp_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        " id │ destribution │           link            \n",
+        "────┼──────────────┼───────────────────────────\n",
+        " 0  │    Fedora    │  https://getfedora.org/   \n",
+        " 2  │   OpenSUSE   │ https://www.opensuse.org/ \n",
+        " 3  │ Endeavouros  │ https://endeavouros.com/  \n",
+    );
+
+    let table = Table::new(&data).with(Style::sharp()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sharp_style_with_a_header_only() {
+    let data: Vec<Linux> = Vec::new();
+
+    let table = Table::new(&data).with(Style::sharp()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " id │ destribution │ link \n",
+            "────┼──────────────┼──────\n",
+        )
+    );
+}
+
+#[test]
+fn columns_only_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        " id │ destribution │           link\n",
+        " 0  │    Fedora    │  https://getfedora.org/\n",
+        " 2  │   OpenSUSE   │ https://www.opensuse.org/\n",
+    );
+
+    let table = Table::new(&data)
+        .with(Style::columns_only(" │ "))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn columns_only_style_with_spaces() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "id  destribution  link\n",
+        "0   Fedora        https://getfedora.org/\n",
+        "2   OpenSUSE      https://www.opensuse.org/\n",
+    );
+
+    let table = Table::new(&data)
+        .with(Style::columns_only("  ").alignment(Alignment::left()))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn columns_only_style_with_a_single_space() {
+    let table = Table::new([("a", 1), ("bcd", 22)])
+        .with(Style::columns_only(" ").alignment(Alignment::left()))
+        .to_string();
+
+    assert_eq!(table, "&str i32\na    1\nbcd  22\n");
+}
+
+#[test]
+#[should_panic]
+fn columns_only_style_with_several_visible_characters() {
+    Style::columns_only(" || ");
+}
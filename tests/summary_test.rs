@@ -0,0 +1,85 @@
+use tabled::{Style, Summary, Table};
+
+#[test]
+fn summary_numbers() {
+    let data = [("a", "1.5", 3), ("b", "n/a", -2), ("c", "2", 10)];
+
+    let table = Table::new(data)
+        .with(Summary::new("mean").mean(1).min(2))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str | i32 \n",
+        "------+------+-----\n",
+        "  a   | 1.5  |  3  \n",
+        "  b   | n/a  | -2  \n",
+        "  c   |  2   | 10  \n",
+        " mean | 1.75 | -2  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn summary_mean_keeps_significant_digits_only() {
+    let table = Table::new([1, 2, 4])
+        .with(Summary::new("").mean(0))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" i32  \n", "------\n", "  1   \n", "  2   \n", "  4   \n", " 2.33 \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn summary_count_with_label_column() {
+    let data = [("x", ""), ("y", "z")];
+
+    let table = Table::new(data)
+        .with(Summary::new("count").label_column(1).count(0))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str  \n",
+        "------+-------\n",
+        "  x   |       \n",
+        "  y   |   z   \n",
+        "  2   | count \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn summary_max() {
+    let table = Table::new([("a", "1.5"), ("b", "-2")])
+        .with(Summary::new("max").max(1))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  a   | 1.5  \n",
+        "  b   |  -2  \n",
+        " max  | 1.5  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn summary_of_empty_table() {
+    let table = Table::new(Vec::<(u8, u8)>::new())
+        .with(Summary::new("sum").sum(1))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" u8  | u8 \n", "-----+----\n", " sum | 0  \n")
+    );
+}
@@ -0,0 +1,52 @@
+use tabled::{Style, TabWidth, Table};
+
+#[test]
+fn tab_default_width() {
+    let table = Table::new(["a\tb", "ab\tc"])
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str  \n", "-------\n", " a   b \n", " ab  c \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn tab_stops_of_each_line() {
+    let table = Table::new(["12345\tx\ny\tz"])
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   &str    \n",
+        "-----------\n",
+        " 12345   x \n",
+        "   y   z   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn tab_after_wide_char() {
+    let table = Table::new(["你\tb"])
+        .with(Style::psql())
+        .with(TabWidth(3))
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", " 你 b \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn tab_width_zero_removes_tabs() {
+    let table = Table::new(["a\t\tb"])
+        .with(Style::psql())
+        .with(TabWidth(0))
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  ab  \n");
+
+    assert_eq!(table, expected);
+}
@@ -1,5 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet};
-use tabled::{Style, Table, Tabled};
+use tabled::{Alignment, Column, Modify, Style, Table, Tabled, Width};
 
 mod default_types {
     use super::*;
@@ -378,7 +378,7 @@ fn table_tuple() {
                          | we are in | 2020 |\n\
                          +-----------+------+\n";
 
-    let table = Table::new(&[t]).to_string();
+    let table = Table::new([t]).to_string();
 
     assert_eq!(table, expected);
 }
@@ -392,7 +392,7 @@ fn table_single_tuple() {
                          | 2020 |\n\
                          +------+\n";
 
-    let table = Table::new(&[t]).to_string();
+    let table = Table::new([t]).to_string();
 
     assert_eq!(table, expected);
 }
@@ -408,7 +408,7 @@ fn table_tuple_vec() {
                          |  1  | Thursday |\n\
                          +-----+----------+\n";
 
-    let table = Table::new(&map).to_string();
+    let table = Table::new(map).to_string();
 
     assert_eq!(table, expected);
 }
@@ -735,3 +735,229 @@ fn tuple_combination() {
         )
     );
 }
+
+#[test]
+fn table_measure_and_draw() {
+    let data = [("Rust", 2010), ("Go", 2009)];
+    let table = Table::new(data).with(Style::pseudo());
+
+    let measurement = table.measure();
+
+    assert_eq!(measurement.width(), 15);
+    assert_eq!(measurement.height(), 7);
+    assert_eq!(measurement.column_widths(), &[6, 6]);
+    assert_eq!(table.draw(&measurement), table.to_string());
+}
+
+#[test]
+fn table_from_map() {
+    let mut map = BTreeMap::new();
+    map.insert(1, String::from("a,b"));
+    map.insert(0, String::new());
+
+    let table = Table::from_map(map).with(Style::psql()).to_string();
+
+    let expected = concat!(
+        " key | value \n",
+        "-----+-------\n",
+        "  0  |       \n",
+        "  1  |  a,b  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_from_map_of_tuples() {
+    let table = Table::from_map(vec![("a", 1.5)])
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" key | value \n", "-----+-------\n", "  a  |  1.5  \n")
+    );
+}
+
+#[test]
+fn table_long_tuple_and_array_vec() {
+    let data = vec![(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 'c')];
+    let table = Table::new(data).with(Style::github_markdown()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | char |\n",
+            "|-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+------|\n",
+            "|  1  |  2  |  3  |  4  |  5  |  6  |  7  |  8  |  9  | 10  | 11  |  c   |\n",
+        )
+    );
+
+    let data = vec![[1, 2], [3, 4]];
+    let table = Table::new(data).with(Style::psql()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(" 0 | 1 \n", "---+---\n", " 1 | 2 \n", " 3 | 4 \n")
+    );
+}
+
+#[test]
+fn table_measure_cell_positions() {
+    let table = Table::new([("Rust", 2010), ("Go", 2009)]).with(Style::psql());
+
+    let measurement = table.measure();
+    let rendered = table.to_string();
+    let lines = rendered.lines().collect::<Vec<_>>();
+
+    assert_eq!(measurement.column_offsets(), &[0, 7]);
+    assert_eq!(measurement.row_offsets(), &[0, 2, 3]);
+
+    let (x, y) = measurement.cell_position(2, 1).unwrap();
+    assert_eq!(&lines[y][x..], " 2009 ");
+    assert_eq!(measurement.cell_position(3, 0), None);
+}
+
+#[test]
+fn table_measure_cell_positions_in_display_columns() {
+    let table = Table::new([("Rust", 2010), ("Go", 2009)]).with(Style::pseudo());
+
+    let measurement = table.measure();
+    let rendered = table.to_string();
+    let lines = rendered.lines().collect::<Vec<_>>();
+
+    let (x, y) = measurement.cell_position(2, 1).unwrap();
+    assert_eq!((x, y), (8, 5));
+    assert_eq!(lines[y].chars().skip(x).collect::<String>(), " 2009 │");
+}
+
+#[test]
+fn table_render_to_file() {
+    let table = Table::new((0..100).map(|i| (i, i * i))).with(Style::psql());
+    let path = std::env::temp_dir().join(format!("tabled-{}.txt", std::process::id()));
+
+    let mut last = (0, 0);
+    table
+        .render_to_file(&path, |row, total| last = (row, total))
+        .unwrap();
+    let rendered = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(rendered, table.to_string());
+    assert_eq!(last, (101, 101));
+}
+
+#[test]
+fn table_paginate() {
+    let table = Table::new(["a", "bb", "ccc", "dddd", "e"]);
+    let pages = table.paginate(2).collect::<Vec<_>>();
+
+    assert_eq!(
+        pages,
+        [
+            "+------+\n\
+             | &str |\n\
+             +------+\n\
+             |  a   |\n\
+             +------+\n\
+             |  bb  |\n\
+             +------+\n",
+            "+------+\n\
+             | &str |\n\
+             +------+\n\
+             | ccc  |\n\
+             +------+\n\
+             | dddd |\n\
+             +------+\n",
+            "+------+\n\
+             | &str |\n\
+             +------+\n\
+             |  e   |\n\
+             +------+\n",
+        ]
+    );
+}
+
+#[test]
+fn table_paginate_keeps_frame() {
+    let table = Table::new(["a", "bb", "ccc"]).with(Style::pseudo());
+    let pages = table.paginate(2).collect::<Vec<_>>();
+
+    assert_eq!(
+        pages[1],
+        "┌──────┐\n\
+         │ &str │\n\
+         ├──────┤\n\
+         │ ccc  │\n\
+         └──────┘\n"
+    );
+}
+
+#[test]
+fn table_paginate_header_only() {
+    let table = Table::new(Vec::<&str>::new());
+    let pages = table.paginate(3).collect::<Vec<_>>();
+
+    assert_eq!(pages, [table.to_string()]);
+}
+
+#[test]
+#[should_panic]
+fn table_paginate_zero_rows_per_page() {
+    let _ = Table::new(["a"]).paginate(0);
+}
+
+#[test]
+fn table_render_deterministic() {
+    let table = Table::new([("Rust", "a\r\nb"), ("Go", "c")]).with(Style::psql());
+
+    assert_eq!(
+        table.render_deterministic(),
+        " &str | &str\n\
+         ------+------\n\
+         \x20Rust |  a\n\
+         \x20     |  b\n\
+         \x20 Go  |  c\n"
+    );
+}
+
+#[test]
+fn table_render_deterministic_is_to_string_without_trailing_spaces() {
+    let table = Table::new([("Rust", 2010), ("Go", 2009)]).with(Width::exact(15));
+    let expected = table
+        .to_string()
+        .lines()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect::<String>();
+
+    assert_eq!(table.render_deterministic(), expected);
+}
+
+#[test]
+fn with_records_keeps_settings_test() {
+    let template = Table::new([(1, "a"), (2, "b"), (3, "c")])
+        .with(Style::default())
+        .with(Modify::new(Column(1..)).with(Alignment::left()));
+
+    let expected = concat!(
+        "+-----+----+\n",
+        "| i32 |&str|\n",
+        "+-----+----+\n",
+        "| 10  |x   |\n",
+        "+-----+----+\n",
+        "| 20  |y   |\n",
+        "+-----+----+\n",
+        "| 30  |z   |\n",
+        "+-----+----+\n",
+        "| 40  |w   |\n",
+        "+-----+----+\n",
+    );
+
+    let table = template.with_records([(10, "x"), (20, "y"), (30, "z"), (40, "w")]);
+    assert_eq!(table.to_string(), expected);
+
+    let expected = concat!("+-----+----+\n", "| i32 |&str|\n", "+-----+----+\n",);
+
+    let table = template.with_records(Vec::<(i32, &str)>::new());
+    assert_eq!(table.to_string(), expected);
+}
@@ -0,0 +1,79 @@
+use tabled::{Alignment, Full, Modify, Style, Table, Theme};
+
+#[test]
+fn theme_minimal() {
+    let table = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(Theme::minimal())
+        .to_string();
+
+    #[cfg(not(feature = "color"))]
+    let expected = concat!(
+        " &str   i32  \n",
+        "─────────────\n",
+        " Rust   2010 \n",
+        "  Go    2009 \n",
+    );
+    #[cfg(feature = "color")]
+    let expected = concat!(
+        " \u{1b}[1m&str\u{1b}[0m   \u{1b}[1mi32\u{1b}[0m  \n",
+        "─────────────\n",
+        " Rust   2010 \n",
+        "  Go    2009 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn theme_padding_keeps_alignment() {
+    let table = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(Theme::new(Style::psql()).padding(2, 0))
+        .to_string();
+
+    let expected = concat!(
+        "  &str|  i32 \n",
+        "------+------\n",
+        "  Rust|  2010\n",
+        "   Go |  2009\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn theme_can_be_overridden() {
+    let table = Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(Theme::dark().style(Style::psql()))
+        .with(Modify::new(Full).with(Alignment::left()))
+        .to_string();
+
+    #[cfg(not(feature = "color"))]
+    let expected = concat!("&str|i32 \n", "----+----\n", "Rust|2010\n", "Go  |2009\n",);
+    #[cfg(feature = "color")]
+    let expected = concat!(
+        "\u{1b}[1m&str\u{1b}[0m\u{1b}[2m|\u{1b}[0m\u{1b}[1mi32\u{1b}[0m \n",
+        "\u{1b}[2m----\u{1b}[0m\u{1b}[2m+\u{1b}[0m\u{1b}[2m----\u{1b}[0m\n",
+        "Rust\u{1b}[2m|\u{1b}[0m2010\n",
+        "\u{1b}[2mGo\u{1b}[0m  \u{1b}[2m|\u{1b}[0m\u{1b}[2m2009\u{1b}[0m\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn theme_dark_colors() {
+    let table = Table::new([("Rust", 2010), ("Go", 2009), ("C", 1972)])
+        .with(Theme::dark().style(Style::psql()))
+        .to_string();
+
+    let expected = concat!(
+        " \u{1b}[1m&str\u{1b}[0m \u{1b}[2m|\u{1b}[0m \u{1b}[1mi32\u{1b}[0m  \n",
+        "\u{1b}[2m------\u{1b}[0m\u{1b}[2m+\u{1b}[0m\u{1b}[2m------\u{1b}[0m\n",
+        " Rust \u{1b}[2m|\u{1b}[0m 2010 \n",
+        "  \u{1b}[2mGo\u{1b}[0m  \u{1b}[2m|\u{1b}[0m \u{1b}[2m2009\u{1b}[0m \n",
+        "  C   \u{1b}[2m|\u{1b}[0m 1972 \n",
+    );
+
+    assert_eq!(table, expected);
+}
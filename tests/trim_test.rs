@@ -0,0 +1,58 @@
+use tabled::{Full, Modify, Style, Table, TrimTrailingWhitespace, Wrap};
+
+#[test]
+fn trim_psql_style() {
+    let table = Table::new([("Rust", "a"), ("Go", "bcd")])
+        .with(Style::psql())
+        .with(TrimTrailingWhitespace)
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str\n",
+        "------+------\n",
+        " Rust |  a\n",
+        "  Go  | bcd\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn trim_keeps_right_border() {
+    let table = Table::new(["a", "bb"])
+        .with(Style::default())
+        .with(TrimTrailingWhitespace)
+        .to_string();
+
+    let expected = concat!(
+        "+------+\n",
+        "| &str |\n",
+        "+------+\n",
+        "|  a   |\n",
+        "+------+\n",
+        "|  bb  |\n",
+        "+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn trim_wrapped_lines() {
+    let table = Table::new(["Hello World"])
+        .with(Style::noborder())
+        .with(Modify::new(Full).with(Wrap(5)))
+        .with(TrimTrailingWhitespace)
+        .to_string();
+
+    assert_eq!(table, " &str\n Hello\n Worl\n   d\n");
+}
+
+#[test]
+fn trim_measure_and_draw() {
+    let table = Table::new(["a", "bb"])
+        .with(Style::noborder())
+        .with(TrimTrailingWhitespace);
+
+    assert_eq!(table.draw(&table.measure()), table.to_string());
+}
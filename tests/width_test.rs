@@ -1,4 +1,8 @@
-use tabled::{Column, Full, MaxWidth, Modify, Object, Row, Style, Table, Tabled};
+use tabled::{
+    Cell, Column, Full, Head, MaxWidth, Modify, Object, PercentileWidth, Row, Span, Style, Table,
+    Tabled, Truncate, Width, Wrap,
+};
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Tabled)]
 struct Linux {
@@ -83,12 +87,13 @@ fn dont_change_content_if_width_is_less_then_max_width() {
 fn max_width_with_emoji() {
     let data = &["🤠", "😳🥵🥶😱😨", "🚴🏻‍♀️🚴🏻🚴🏻‍♂️🚵🏻‍♀️🚵🏻🚵🏻‍♂️"];
 
+    // an emoji sequence is kept whole, its width is a sum of widths of its chars
     let _expected = concat!(
-        "|  &st...   |\n",
-        "|-----------|\n",
-        "|    🤠     |\n",
-        "| 😳🥵🥶... |\n",
-        "|  🚴🏻\u{200d}...  |\n",
+        "|      &st...       |\n",
+        "|-------------------|\n",
+        "|        🤠         |\n",
+        "|     😳🥵🥶...     |\n",
+        "| 🚴🏻\u{200d}♀\u{fe0f}🚴🏻🚴🏻\u{200d}♂\u{fe0f}... |\n",
     );
 
     let table = Table::new(data)
@@ -127,3 +132,285 @@ fn color_chars_are_stripped() {
 
     assert_eq!(expected, table);
 }
+
+#[test]
+fn truncate_position_per_column() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "| id | destribution |    link    |\n",
+        "|----+--------------+------------|\n",
+        "| 0  |    Fe..ra    | ...ra.org/ |\n",
+        "| 2  |    Op..SE    | ...se.org/ |\n",
+    );
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Column(1..2).not(Row(..1))).with(Truncate::middle(4, "..")))
+        .with(Modify::new(Column(2..).not(Row(..1))).with(Truncate::left(7, "...")))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn truncate_right_is_max_width() {
+    let data = ["123456789", "qwertyuiop[]"];
+
+    let table1 = Table::new(data)
+        .with(Modify::new(Full).with(MaxWidth(5, "...")))
+        .to_string();
+    let table2 = Table::new(data)
+        .with(Modify::new(Full).with(Truncate::right(5, "...")))
+        .to_string();
+
+    assert_eq!(table1, table2);
+}
+
+#[test]
+fn wrap_header_and_truncate_body() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+    ];
+
+    let expected = concat!(
+        "| id | destri |  link  |\n",
+        "|    | bution |        |\n",
+        "|----+--------+--------|\n",
+        "| 0  | Fedora | htt... |\n",
+        "| 2  | OpenSU | htt... |\n",
+    );
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Head).with(Wrap(6)))
+        .with(Modify::new(Column(1..2).not(Row(..1))).with(MaxWidth(6, "")))
+        .with(Modify::new(Column(2..).not(Row(..1))).with(MaxWidth(3, "...")))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn wrap_multiline() {
+    let table = Table::new(["123456\n12"])
+        .with(Modify::new(Full).with(Wrap(4)))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", " 1234 \n", "  56  \n", "  12  \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn truncate_keeps_grapheme_clusters() {
+    let table = Table::new(["ne\u{301}e\u{301}", "👨\u{200d}👩\u{200d}👧ab"])
+        .with(Modify::new(Full.not(Row(..1))).with(Truncate::middle(2, "~")))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "   &str   \n",
+        "----------\n",
+        "   n~e\u{301}    \n",
+        " 👨\u{200d}👩\u{200d}👧~b \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn wrap_keeps_grapheme_clusters() {
+    let table = Table::new(["e\u{301}e\u{301}e\u{301}"])
+        .with(Modify::new(Full.not(Row(..1))).with(Wrap(2)))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " &str \n",
+        "------\n",
+        "  e\u{301}e\u{301}  \n",
+        "  e\u{301}   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn percentile_width_wraps_outliers() {
+    let data = [
+        ("1", "ok"),
+        ("2", "ok"),
+        ("3", "failed"),
+        ("4", "a connection was refused"),
+    ];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(PercentileWidth::wrap(Column(1..), 75))
+        .to_string();
+
+    let expected = concat!(
+        " &str |  &str  \n",
+        "------+--------\n",
+        "  1   |   ok   \n",
+        "  2   |   ok   \n",
+        "  3   | failed \n",
+        "  4   | a conn \n",
+        "      | ection \n",
+        "      | was r  \n",
+        "      | efused \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn percentile_width_100_keeps_contents() {
+    let data = ["a", "a long value"];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(PercentileWidth::truncate(Full, 100, "..."))
+        .to_string();
+
+    let expected = concat!(
+        "     &str     \n",
+        "--------------\n",
+        "      a       \n",
+        " a long value \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn percentile_width_is_computed_over_a_whole_column() {
+    let data = ["a", "bb", "a long value", "another long value"];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(PercentileWidth::truncate(Row(4..), 60, "~"))
+        .to_string();
+
+    let expected = concat!(
+        "     &str     \n",
+        "--------------\n",
+        "      a       \n",
+        "      bb      \n",
+        " a long value \n",
+        "    anot~     \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn exact_width_expands_columns() {
+    let data = [("a", "bbb")];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Width::exact(20))
+        .to_string();
+
+    let expected = concat!(
+        "   &str   |  &str   \n",
+        "----------+---------\n",
+        "    a     |   bbb   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn exact_width_is_kept_for_any_width() {
+    let data = [
+        ("Fedora", "https://getfedora.org/"),
+        ("OpenSUSE", "https://www.opensuse.org/"),
+    ];
+
+    for width in 10..60 {
+        let table = Table::new(data)
+            .with(Style::pseudo())
+            .with(Width::exact(width))
+            .to_string();
+
+        for line in table.lines() {
+            assert_eq!(line.chars().count(), width, "{}", table);
+        }
+    }
+}
+
+#[test]
+fn exact_width_keeps_indent() {
+    let table = Table::new(["abc"])
+        .with(Style::psql())
+        .with(Width::exact(1))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" & \n", " s \n", " t \n", " r \n", "---\n", " a \n", " b \n", " c \n")
+    );
+}
+
+#[test]
+fn exact_width_wraps_by_display_width() {
+    let table = Table::new(["日本語日本語日本語"])
+        .with(Style::psql())
+        .with(Width::exact(10))
+        .to_string();
+
+    let expected = concat!(
+        "   &str   \n",
+        "----------\n",
+        " 日本語日 \n",
+        " 本語日本 \n",
+        "    語    \n",
+    );
+
+    assert_eq!(table, expected);
+    for line in table.lines() {
+        assert_eq!(UnicodeWidthStr::width(line), 10);
+    }
+}
+
+#[test]
+fn exact_width_wraps_spanned_cells() {
+    let table = Table::new([("a long spanned text", "covered text")])
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Style::psql())
+        .with(Width::exact(15))
+        .to_string();
+
+    let expected = concat!(
+        " &str  | &str  \n",
+        "-------+-------\n",
+        " a long spanne \n",
+        "    d text     \n",
+    );
+
+    assert_eq!(table, expected);
+}